@@ -1,53 +1,59 @@
-//! # 20xx day xx - 
+//! # 20xx day xx - Title
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("2").part2("0")];
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
-    data: Vec<String>
+pub struct InputData {
+    data: Vec<String>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self { data: s.lines().map(|line| line.to_string()).collect() })
+        Ok(Self {
+            data: parse::lines(s, |line| Ok(line.to_string()))?,
+        })
     }
 }
 
 impl InputData {
     fn solve_part1(&self) -> usize {
-        for line in &self.data {
-            println!("{}", line);
-        }
-        1
+        self.data.len()
     }
 
     fn solve_part2(&self) -> usize {
-        2
+        0
     }
 }
 
+const EXAMPLE: &str = "1
+2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let testdata = "1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let testdata = "2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2);
+    fn examples() {
+        check::<InputData>();
     }
 }
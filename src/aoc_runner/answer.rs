@@ -0,0 +1,128 @@
+//! # Answer values
+//!
+//! The answer of each part is returned as an `Answer`, keeping the kind of value - an integer, a
+//! text, a rendered screen or a coordinate - so that the runner can format, compare and serialize
+//! all answers the same way. Most days simply return a number or a string, which are converted
+//! with `Into<Answer>`.
+//!
+//! Answers are stored as text in the answer store, and verified by parsing the stored text as the
+//! same kind of value, so e.g. a screen matches regardless of surrounding line breaks.
+use crate::aoc_util::point::Point;
+use std::fmt;
+
+/// The answer of one part of a puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    /// A number, which covers most answers.
+    Int(i128),
+    /// A string, e.g. a password.
+    Text(String),
+    /// A rendered screen, as rows of characters. The letters on it are the actual answer.
+    Screen(Vec<String>),
+    /// A coordinate, written as `x,y`.
+    Point(i64, i64),
+    /// The puzzle has no such part, i.e. part 2 of day 25, or the input has no answer to it, e.g.
+    /// no path through a maze.
+    NotApplicable,
+}
+
+impl Answer {
+    /// Creates a screen answer from `s`, with one row per line. Leading and trailing line breaks
+    /// are ignored.
+    pub fn screen(s: &str) -> Self {
+        Answer::Screen(s.trim_matches('\n').lines().map(String::from).collect())
+    }
+
+    /// Returns true if the answer equals `expected`, the stored text of an answer, when read as
+    /// the same kind of value.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(n) => expected.trim().parse() == Ok(*n),
+            Answer::Text(s) => s == expected,
+            Answer::Screen(rows) => {
+                let expected: Vec<_> = expected.trim_matches('\n').lines().collect();
+                rows.len() == expected.len()
+                    && rows
+                        .iter()
+                        .zip(expected)
+                        .all(|(row, e)| row.trim_end() == e.trim_end())
+            }
+            Answer::Point(x, y) => expected
+                .split_once(',')
+                .is_some_and(|(ex, ey)| ex.trim().parse() == Ok(*x) && ey.trim().parse() == Ok(*y)),
+            Answer::NotApplicable => expected == "-",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Screen(rows) => write!(f, "\n{}\n", rows.join("\n")),
+            Answer::Point(x, y) => write!(f, "{x},{y}"),
+            Answer::NotApplicable => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(p: Point) -> Self {
+        Answer::Point(p.x.into(), p.y.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(-42_i32).to_string(), "-42");
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::screen("\n.#\n#.\n").to_string(), "\n.#\n#.\n");
+        assert_eq!(Answer::from(Point::new(6, 1)).to_string(), "6,1");
+        assert_eq!(Answer::NotApplicable.to_string(), "-");
+    }
+
+    #[test]
+    fn matches_values() {
+        assert!(Answer::Int(74).matches("74"));
+        assert!(Answer::Int(74).matches(" 74"));
+        assert!(!Answer::Int(74).matches("074x"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(!Answer::from("abc").matches("abc "));
+        assert!(Answer::screen("\n.#\n#.").matches("\n.#\n#.\n"));
+        assert!(Answer::screen(".# \n#.").matches(".#\n#."));
+        assert!(!Answer::screen(".#\n#.").matches(".#\n##"));
+        assert!(!Answer::screen(".#\n#.").matches(".#"));
+        assert!(Answer::Point(6, 1).matches("6, 1"));
+        assert!(!Answer::Point(6, 1).matches("1,6"));
+        assert!(Answer::NotApplicable.matches("-"));
+    }
+}
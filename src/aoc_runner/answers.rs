@@ -0,0 +1,192 @@
+//! # Expected answers
+//!
+//! Stores confirmed puzzle answers in the file `AdventOfCode-Answers.tsv`, next to the
+//! `AdventOfCode-Input` folder. Each line holds one puzzle, as tab-separated year, day, part 1 and
+//! part 2 answers. Backslashes, tabs and newlines in the answers (e.g. in rendered screens) are
+//! escaped as `\\`, `\t` and `\n`.
+use crate::aoc_runner::answer::Answer;
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// The result of comparing a solver's output to the stored answers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// Stored answers for part 1 and 2, keyed by year and day.
+type Answers = BTreeMap<(u16, u8), (String, String)>;
+
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: Answers,
+}
+
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(c) => out.push(c),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+impl AnswerStore {
+    /// Loads the answer file from the current directory. A missing file gives an empty store.
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        Self::load_from(env::current_dir()?.join("AdventOfCode-Answers.tsv"))
+    }
+
+    /// Loads the answer file at `path`. A missing file gives an empty store.
+    pub fn load_from(path: PathBuf) -> Result<Self, Box<dyn Error>> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let answers = Self::parse(&contents)
+            .map_err(|line| format!("{}: line {line}: invalid answer entry", path.display()))?;
+        Ok(Self { path, answers })
+    }
+
+    /// Parses the file contents, returning the (1-based) line number of the first invalid line
+    /// on failure.
+    fn parse(contents: &str) -> Result<Answers, usize> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.split('\t').collect();
+            let [year, day, p1, p2] = fields[..] else {
+                return Err(i + 1);
+            };
+            let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                return Err(i + 1);
+            };
+            answers.insert((year, day), (unescape(p1), unescape(p2)));
+        }
+        Ok(answers)
+    }
+
+    /// Writes the store back to the file it was loaded from.
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        fs::write(&self.path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the stored answers for `year` and `day`, if any.
+    pub fn get(&self, year: u16, day: u8) -> Option<&(String, String)> {
+        self.answers.get(&(year, day))
+    }
+
+    /// Stores the answers for `year` and `day`, replacing any previous entry.
+    pub fn insert(&mut self, year: u16, day: u8, p1: &str, p2: &str) {
+        self.answers
+            .insert((year, day), (p1.to_string(), p2.to_string()));
+    }
+
+    /// Compares the answers `p1` and `p2` to the stored answers for `year` and `day`, as values of
+    /// the same kind. A part which wasn't solved, i.e. `None`, isn't compared.
+    pub fn verify(&self, year: u16, day: u8, p1: Option<&Answer>, p2: Option<&Answer>) -> Status {
+        match self.get(year, day) {
+            Some((e1, e2))
+                if p1.is_none_or(|p1| p1.matches(e1)) && p2.is_none_or(|p2| p2.matches(e2)) =>
+            {
+                Status::Pass
+            }
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        }
+    }
+}
+
+impl fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year\tday\tpart 1\tpart 2")?;
+        for ((year, day), (p1, p2)) in &self.answers {
+            writeln!(f, "{year}\t{day}\t{}\t{}", escape(p1), escape(p2))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_roundtrip() {
+        for answer in ["123", "\n.#.\n#.#\n", "a\tb\\n"] {
+            assert_eq!(unescape(&escape(answer)), answer);
+            assert!(!escape(answer).contains(['\n', '\t']));
+        }
+    }
+
+    #[test]
+    fn parse_and_verify() {
+        let answers = AnswerStore {
+            path: PathBuf::new(),
+            answers: AnswerStore::parse("# comment\n2015\t1\t74\t1795\n2016\t8\t6\t\\n#.\\n")
+                .unwrap(),
+        };
+        let verify =
+            |year, day, p1: Answer, p2: Answer| answers.verify(year, day, Some(&p1), Some(&p2));
+        assert_eq!(verify(2015, 1, 74.into(), 1795.into()), Status::Pass);
+        assert_eq!(verify(2015, 1, 74.into(), 1796.into()), Status::Fail);
+        assert_eq!(verify(2015, 2, 1.into(), 2.into()), Status::Missing);
+        assert_eq!(
+            verify(2016, 8, 6.into(), Answer::screen("#.")),
+            Status::Pass
+        );
+        assert_eq!(verify(2016, 8, 6.into(), "\n#.\n".into()), Status::Pass);
+        assert_eq!(verify(2016, 8, 6.into(), "#.".into()), Status::Fail);
+        assert_eq!(
+            answers.verify(2015, 1, Some(&74.into()), None),
+            Status::Pass
+        );
+        assert_eq!(answers.verify(2015, 1, None, Some(&1.into())), Status::Fail);
+        assert_eq!(
+            AnswerStore::parse(&answers.to_string()),
+            Ok(answers.answers)
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(AnswerStore::parse("2015\t1\t74\n"), Err(1));
+        assert_eq!(AnswerStore::parse("\n2015\tx\t74\t1\n"), Err(2));
+    }
+}
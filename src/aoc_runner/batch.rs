@@ -0,0 +1,563 @@
+//! # Batch runner
+//!
+//! Runs a set of solvers and prints a summary table of the answers and timings. A failing puzzle
+//! doesn't stop the run; all failures are instead reported after the table.
+//!
+//! The puzzles are independent of each other, so by default they are spread over all cores with
+//! `spawn_jobs`, and the results are put back in year and day order before printing. As the
+//! puzzles then compete for the cores and memory bandwidth, the timings are less accurate than when
+//! running them one at a time with `--sequential`.
+//!
+//! Depending on the mode, the answers are also verified against, or recorded to, the answer store.
+//! When only one part is run, the answer of the other part is left out.
+//!
+//! Each solver runs on a worker thread, so that a panicking solver is reported as a failure rather
+//! than ending the run, and a solver which doesn't finish within the time limit is abandoned. The
+//! abandoned thread can't be stopped, and keeps running in the background until the process exits.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::answers::{AnswerStore, Status};
+use crate::aoc_runner::format::{self, Format};
+use crate::aoc_runner::input::InputSource;
+use crate::aoc_runner::memory::{self, AllocStats, PhaseAllocs};
+use crate::aoc_runner::params::Params;
+use crate::aoc_runner::shape;
+use crate::aoc_runner::solver::{self, Outcome, Parts, PhaseTimes, Solver};
+use crate::aoc_util::thread::spawn_jobs;
+use crate::{Config, Mode, Selection};
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Why a puzzle failed to produce its answers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Failure {
+    /// The input couldn't be read or parsed, or the solver returned an error.
+    Error(String),
+    /// The solver panicked, with the panic message.
+    Panic(String),
+    /// The solver didn't finish within the time limit.
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// A short label for the summary table.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Failure::Error(_) => "FAILED",
+            Failure::Panic(_) => "PANIC",
+            Failure::Timeout(_) => "TIMEOUT",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "{e}"),
+            Failure::Panic(message) => write!(f, "PANIC: {message}"),
+            Failure::Timeout(limit) => {
+                write!(f, "TIMEOUT: no answer within {} s", limit.as_secs_f64())
+            }
+        }
+    }
+}
+
+/// The outcome of running one solver. The answer of a part which wasn't run is `None`.
+pub struct PuzzleResult {
+    pub solver: &'static Solver,
+    pub answers: Result<(Option<Answer>, Option<Answer>), Failure>,
+    pub times: PhaseTimes,
+    /// The allocations of each phase, with the `alloc-stats` feature.
+    pub allocs: Option<PhaseAllocs>,
+    pub status: Option<Status>,
+}
+
+/// Reads the input from `source`, checks its shape, and runs the selected `parts` of a single
+/// puzzle with the parameter overrides `params` on a worker thread, giving up after `timeout` if
+/// set.
+pub fn run_puzzle(
+    solver: &'static Solver,
+    source: &InputSource,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> PuzzleResult {
+    let (answers, times, allocs) = match source
+        .read(solver.year, solver.day)
+        .map_err(|e| Failure::Error(e.to_string()))
+        .and_then(|aoc_input| {
+            shape::check(solver, &aoc_input).map_err(Failure::Error)?;
+            Ok(aoc_input)
+        })
+        .and_then(|aoc_input| run_isolated(solver, aoc_input, parts, params, timeout))
+    {
+        Ok(outcome) => (
+            Ok((outcome.part1, outcome.part2)),
+            outcome.times,
+            outcome.allocs,
+        ),
+        Err(e) => (Err(e), PhaseTimes::default(), None),
+    };
+    PuzzleResult {
+        solver,
+        answers,
+        times,
+        allocs,
+        status: None,
+    }
+}
+
+/// Runs all `solvers` spread over the available cores, and returns the results in the same order
+/// as `solvers`.
+fn run_parallel(
+    solvers: Vec<&'static Solver>,
+    source: &InputSource,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Vec<PuzzleResult> {
+    let results = Mutex::new(Vec::with_capacity(solvers.len()));
+    let jobs: Vec<_> = solvers.into_iter().enumerate().collect();
+    spawn_jobs(jobs, |jobs| {
+        for (i, solver) in jobs {
+            let result = run_puzzle(solver, source, parts, params, timeout);
+            results.lock().unwrap().push((i, result));
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_unstable_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Runs `solver` on a worker thread named after the puzzle, catching any panic, and waits at most
+/// `timeout` for the result.
+pub fn run_isolated(
+    solver: &'static Solver,
+    input: String,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<Outcome, Failure> {
+    let params = params.clone();
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("{} day {}", solver.year, solver.day))
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                solver::solve_with(solver, &input, parts, &params).map_err(|e| e.to_string())
+            }));
+            // The receiver is gone if the puzzle has timed out, so there's no one to tell.
+            let _ = sender.send(result);
+        })
+        .map_err(|e| Failure::Error(format!("Can't start worker thread: {e}")))?;
+
+    let result = match timeout {
+        Some(limit) => receiver.recv_timeout(limit).map_err(|e| match e {
+            RecvTimeoutError::Timeout => Failure::Timeout(limit),
+            RecvTimeoutError::Disconnected => Failure::Panic("worker thread died".to_string()),
+        })?,
+        None => receiver
+            .recv()
+            .map_err(|_| Failure::Panic("worker thread died".to_string()))?,
+    };
+    match result {
+        Ok(outcome) => outcome.map_err(Failure::Error),
+        Err(payload) => Err(Failure::Panic(panic_message(payload.as_ref()))),
+    }
+}
+
+/// Returns the message of a panic, which is a `&str` or a `String` unless the solver panicked
+/// with some other value.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Prints `rows` as a table with left-aligned columns under `header`.
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<_> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let print_row = |row: &[String]| {
+        let line: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+    print_row(&header.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    print_row(&widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>());
+    for row in rows {
+        print_row(row);
+    }
+}
+
+/// Runs all `solvers`, in parallel unless `config` says otherwise, and reports the results in the
+/// format set in `config`. Text is printed in the single puzzle layout if only one day is
+/// selected, and otherwise as a summary table. Returns an error if any puzzle failed to solve or,
+/// when verifying, didn't match the stored answers.
+pub fn run(
+    solvers: impl Iterator<Item = &'static Solver>,
+    config: &Config,
+) -> Result<(), Box<dyn Error>> {
+    let mode = &config.mode;
+    let source = &config.input;
+    let mut store = match mode {
+        Mode::Verify | Mode::Record => Some(AnswerStore::load()?),
+        _ => None,
+    };
+
+    // Allocations are counted process wide, so puzzles running side by side would mix them up.
+    let mut results: Vec<_> = if config.sequential || memory::ENABLED {
+        solvers
+            .map(|s| run_puzzle(s, source, config.parts, &config.params, config.timeout))
+            .collect()
+    } else {
+        run_parallel(
+            solvers.collect(),
+            source,
+            config.parts,
+            &config.params,
+            config.timeout,
+        )
+    };
+    if results.is_empty() {
+        return Err("No implemented puzzles match the selection".into());
+    }
+    if let (Mode::Verify, Some(store)) = (mode, &store) {
+        for r in results.iter_mut() {
+            if let Ok((p1, p2)) = &r.answers {
+                r.status =
+                    Some(store.verify(r.solver.year, r.solver.day, p1.as_ref(), p2.as_ref()));
+            }
+        }
+    }
+
+    match config.format {
+        Format::Json => print!("{}", format::to_json(&results)),
+        Format::Csv => print!("{}", format::to_csv(&results)),
+        Format::Text if matches!(config.selection, Selection::Day(..)) => {
+            let r = &results[0];
+            if let Err(e) = &r.answers {
+                return Err(format!("{} day {}: {e}", r.solver.year, r.solver.day).into());
+            }
+            print_single(&results[0], store.as_ref());
+        }
+        Format::Text => print_summary(&results, store.as_ref()),
+        Format::Markdown => unreachable!("markdown is only accepted with --coverage"),
+    }
+
+    if let (Mode::Record, Some(store)) = (mode, &mut store) {
+        let mut recorded = 0;
+        for r in &results {
+            if let Ok((Some(p1), Some(p2))) = &r.answers {
+                store.insert(
+                    r.solver.year,
+                    r.solver.day,
+                    &p1.to_string(),
+                    &p2.to_string(),
+                );
+                recorded += 1;
+            }
+        }
+        store.save()?;
+        eprintln!("Recorded answers for {recorded} puzzle(s)");
+    }
+
+    let failed = results.iter().filter(|r| r.answers.is_err()).count();
+    let mismatched = results
+        .iter()
+        .filter(|r| r.status == Some(Status::Fail))
+        .count();
+    match (failed, mismatched) {
+        (0, 0) => Ok(()),
+        (_, 0) => Err(format!("{failed} of {} puzzles failed", results.len()).into()),
+        (0, _) => Err(format!(
+            "{mismatched} of {} puzzles didn't match the stored answers",
+            results.len()
+        )
+        .into()),
+        _ => Err(format!(
+            "{failed} of {} puzzles failed, {mismatched} didn't match the stored answers",
+            results.len()
+        )
+        .into()),
+    }
+}
+
+fn print_single(r: &PuzzleResult, store: Option<&AnswerStore>) {
+    if let Ok((p1, p2)) = &r.answers {
+        for (part, answer) in [(1, p1), (2, p2)] {
+            if let Some(answer) = answer {
+                println!("Part {part}: {answer}");
+            }
+        }
+        if let Some(status) = r.status {
+            println!("Verification: {status}");
+            if status == Status::Fail {
+                print_mismatch(r, store);
+            }
+        }
+        let mut phases = vec![format!("parse: {} μs", r.times.parse.as_micros())];
+        for (part, answer, time) in [(1, p1, r.times.part1), (2, p2, r.times.part2)] {
+            if answer.is_some() {
+                phases.push(format!("part {part}: {} μs", time.as_micros()));
+            }
+        }
+        println!(
+            "Completed in: {} μs ({})",
+            r.times.total().as_micros(),
+            phases.join(", ")
+        );
+        if let Some(allocs) = &r.allocs {
+            let mut phases = vec![format!("parse: {}", alloc_summary(&allocs.parse))];
+            for (part, answer, stats) in [(1, p1, &allocs.part1), (2, p2, &allocs.part2)] {
+                if answer.is_some() {
+                    phases.push(format!("part {part}: {}", alloc_summary(stats)));
+                }
+            }
+            println!("Allocations: {}", phases.join(", "));
+        }
+    }
+}
+
+/// Formats allocation stats as e.g. `12 (3.5 KiB, peak 2.0 KiB)`.
+fn alloc_summary(stats: &AllocStats) -> String {
+    format!(
+        "{} ({}, peak {})",
+        stats.count,
+        memory::format_bytes(stats.bytes),
+        memory::format_bytes(stats.peak)
+    )
+}
+
+/// Prints the expected answers of a puzzle which failed verification.
+fn print_mismatch(r: &PuzzleResult, store: Option<&AnswerStore>) {
+    if let (Ok((p1, p2)), Some((e1, e2))) = (
+        &r.answers,
+        store.and_then(|s| s.get(r.solver.year, r.solver.day)),
+    ) {
+        for (part, answer, expected) in [(1, p1, e1), (2, p2, e2)] {
+            if let Some(answer) = answer
+                && !answer.matches(expected)
+            {
+                println!(
+                    "  {} day {} part {part}: expected {expected:?}, got {:?}",
+                    r.solver.year,
+                    r.solver.day,
+                    answer.to_string()
+                );
+            }
+        }
+    }
+}
+
+/// Formats an answer for a table cell. Screens don't fit in the table and are printed separately
+/// after it, and parts which weren't run are shown as `-`.
+pub fn cell(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Screen(_)) => "(see below)".to_string(),
+        Some(answer) => answer.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn print_summary(results: &[PuzzleResult], store: Option<&AnswerStore>) {
+    let verifying = results.iter().any(|r| r.status.is_some());
+    let counting = results.iter().any(|r| r.allocs.is_some());
+    let rows: Vec<_> = results
+        .iter()
+        .map(|r| {
+            let (p1, p2) = match &r.answers {
+                Ok((p1, p2)) => (cell(p1.as_ref()), cell(p2.as_ref())),
+                Err(e) => (e.label().to_string(), String::new()),
+            };
+            let mut row = vec![
+                r.solver.year.to_string(),
+                r.solver.day.to_string(),
+                r.solver.title.to_string(),
+                p1,
+                p2,
+                format!("{} μs", r.times.total().as_micros()),
+            ];
+            if counting {
+                match r.allocs.map(|a| a.total()) {
+                    Some(total) => row.extend([
+                        total.count.to_string(),
+                        memory::format_bytes(total.bytes),
+                        memory::format_bytes(total.peak),
+                    ]),
+                    None => row.extend([String::new(), String::new(), String::new()]),
+                }
+            }
+            if verifying {
+                row.push(r.status.map(|s| s.to_string()).unwrap_or_default());
+            }
+            row
+        })
+        .collect();
+    let mut header = vec!["Year", "Day", "Title", "Part 1", "Part 2", "Time"];
+    if counting {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+    if verifying {
+        header.push("Check");
+    }
+    print_table(&header, &rows);
+    let total: Duration = results.iter().map(|r| r.times.total()).sum();
+    println!(
+        "Total: {} puzzles in {} ms",
+        results.len(),
+        total.as_millis()
+    );
+    if verifying {
+        let count = |status| results.iter().filter(|r| r.status == Some(status)).count();
+        println!(
+            "Verification: {} passed, {} failed, {} missing",
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Missing)
+        );
+    }
+
+    for r in results {
+        if let Ok((p1, p2)) = &r.answers {
+            for (part, answer) in [(1, p1), (2, p2)] {
+                if let Some(Answer::Screen(rows)) = answer {
+                    println!(
+                        "\n{} day {} part {part}:\n{}",
+                        r.solver.year,
+                        r.solver.day,
+                        rows.join("\n")
+                    );
+                }
+            }
+        }
+    }
+
+    if results.iter().any(|r| r.status == Some(Status::Fail)) {
+        println!("\nMismatches:");
+        for r in results.iter().filter(|r| r.status == Some(Status::Fail)) {
+            print_mismatch(r, store);
+        }
+    }
+
+    let failures: Vec<_> = results
+        .iter()
+        .filter_map(|r| r.answers.as_ref().err().map(|e| (r.solver, e)))
+        .collect();
+    if !failures.is_empty() {
+        println!("\nFailures:");
+        for (solver, e) in failures {
+            println!("  {} day {}: {e}", solver.year, solver.day);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::shape::Shape;
+    use crate::aoc_runner::solver;
+
+    static PANICS: Solver = Solver {
+        year: 2000,
+        day: 1,
+        title: "Panics",
+        params: &[],
+        examples: &[],
+        shape: Shape::Any,
+        run: |input, _, _| panic!("bad input {input:?}"),
+    };
+
+    static HANGS: Solver = Solver {
+        year: 2000,
+        day: 2,
+        title: "Hangs",
+        params: &[],
+        examples: &[],
+        shape: Shape::Any,
+        run: |_, _, _| loop {
+            thread::park();
+        },
+    };
+
+    #[test]
+    fn parallel_order() {
+        let solvers: Vec<_> = solver::all().take(20).collect();
+        let source = InputSource::Directory(Some("/nonexistent".into()));
+        let results = run_parallel(
+            solvers.clone(),
+            &source,
+            Parts::Both,
+            &Params::default(),
+            None,
+        );
+        assert_eq!(results.len(), solvers.len());
+        for (r, s) in results.iter().zip(solvers) {
+            assert_eq!((r.solver.year, r.solver.day), (s.year, s.day));
+            assert!(r.answers.is_err());
+        }
+    }
+
+    #[test]
+    fn isolated_ok() {
+        let outcome = run_isolated(
+            solver::find(2015, 1).unwrap(),
+            "(()(".to_string(),
+            Parts::Both,
+            &Params::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(outcome.part1, Some(Answer::Int(2)));
+    }
+
+    #[test]
+    fn isolated_panic() {
+        let result = run_isolated(
+            &PANICS,
+            "x".to_string(),
+            Parts::Both,
+            &Params::default(),
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(Failure::Panic("bad input \"x\"".to_string()))
+        );
+    }
+
+    #[test]
+    fn isolated_timeout() {
+        let limit = Duration::from_millis(50);
+        let result = run_isolated(
+            &HANGS,
+            String::new(),
+            Parts::Both,
+            &Params::default(),
+            Some(limit),
+        );
+        let failure = result.err().unwrap();
+        assert_eq!(failure, Failure::Timeout(limit));
+        assert_eq!(failure.label(), "TIMEOUT");
+        assert_eq!(failure.to_string(), "TIMEOUT: no answer within 0.05 s");
+    }
+}
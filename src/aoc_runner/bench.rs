@@ -0,0 +1,301 @@
+//! # Benchmarking
+//!
+//! Runs each selected solver repeatedly on its input, and reports min, median, mean and 95th
+//! percentile timings for the parsing and for each part separately. Every solver gets one
+//! untimed warm-up run before the measured iterations. Like a normal run, each run is isolated on
+//! a worker thread, so a panic or timeout fails only that puzzle.
+//!
+//! The medians are stored in the benchmark history under the current git revision, and can be
+//! compared to those of a baseline revision to detect regressions. When benchmarking a single
+//! part, only that part is timed. Results are only stored for both parts of the default inputs
+//! with the default parameters.
+use crate::aoc_runner::batch::{self, print_table};
+use crate::aoc_runner::history::{self, BenchHistory, Comparison, Medians};
+use crate::aoc_runner::input::InputSource;
+use crate::aoc_runner::memory::{self, PhaseAllocs};
+use crate::aoc_runner::params::Params;
+use crate::aoc_runner::shape;
+use crate::aoc_runner::solver::{Parts, PhaseTimes, Solver};
+use std::error::Error;
+use std::time::Duration;
+
+/// Summary statistics of a set of timing samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Calculates the statistics of `samples`.
+    ///
+    /// # Panic
+    ///
+    /// Will panic if `samples` is empty.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            p95,
+        }
+    }
+}
+
+/// Settings for a benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchOptions {
+    /// The number of timed runs per puzzle.
+    pub iterations: usize,
+    /// The revision to compare the results to, if any.
+    pub baseline: Option<String>,
+    /// How many percent slower than the baseline a puzzle may get before it's flagged.
+    pub threshold: f64,
+}
+
+/// The timing statistics of each phase of one solver.
+pub struct BenchResult {
+    pub solver: &'static Solver,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+    /// The allocations of each phase in the last run, with the `alloc-stats` feature.
+    pub allocs: Option<PhaseAllocs>,
+}
+
+/// Runs the selected `parts` of `solver` with the parameter overrides `params` on its input from
+/// `source` `iterations` times, after one warm-up run. Each run is isolated on a worker thread,
+/// and fails if it takes longer than `timeout`.
+pub fn bench_puzzle(
+    solver: &'static Solver,
+    source: &InputSource,
+    iterations: usize,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<BenchResult, Box<dyn Error>> {
+    let aoc_input = source.read(solver.year, solver.day)?;
+    shape::check(solver, &aoc_input)?;
+    let run = || {
+        batch::run_isolated(solver, aoc_input.clone(), parts, params, timeout)
+            .map_err(|failure| failure.to_string())
+    };
+    run()?;
+    let outcomes = (0..iterations)
+        .map(|_| run())
+        .collect::<Result<Vec<_>, _>>()?;
+    let samples: Vec<_> = outcomes.iter().map(|outcome| outcome.times).collect();
+    let stats = |phase: fn(&PhaseTimes) -> Duration| {
+        Stats::new(&samples.iter().map(phase).collect::<Vec<_>>())
+    };
+    Ok(BenchResult {
+        solver,
+        parse: stats(|t| t.parse),
+        part1: stats(|t| t.part1),
+        part2: stats(|t| t.part2),
+        total: stats(PhaseTimes::total),
+        allocs: outcomes.last().and_then(|outcome| outcome.allocs),
+    })
+}
+
+impl BenchResult {
+    fn medians(&self) -> Medians {
+        Medians {
+            parse: self.parse.median,
+            part1: self.part1.median,
+            part2: self.part2.median,
+            total: self.total.median,
+        }
+    }
+}
+
+fn micros(d: Duration) -> String {
+    format!("{:.1} μs", d.as_secs_f64() * 1_000_000.0)
+}
+
+/// Compares the median total times of `results` to those of the `baseline` revision, and prints
+/// the comparison. Puzzles without a stored baseline are listed, but don't count as slower.
+/// Returns the number of puzzles slower than the threshold.
+fn print_comparison(
+    results: &[BenchResult],
+    history: &BenchHistory,
+    baseline: &str,
+    threshold: f64,
+) -> usize {
+    let mut regressions = 0;
+    let rows: Vec<_> = results
+        .iter()
+        .map(|r| {
+            let base = history
+                .get(r.solver.year, r.solver.day, baseline)
+                .map(|m| m.total);
+            let (change, status) = match history::compare(r.total.median, base, threshold) {
+                Comparison::Ok(c) => (format!("{c:+.1} %"), "OK"),
+                Comparison::Slower(c) => {
+                    regressions += 1;
+                    (format!("{c:+.1} %"), "SLOWER")
+                }
+                Comparison::NoBaseline => (String::new(), "NO BASELINE"),
+            };
+            vec![
+                r.solver.year.to_string(),
+                r.solver.day.to_string(),
+                base.map(micros).unwrap_or_default(),
+                micros(r.total.median),
+                change,
+                status.to_string(),
+            ]
+        })
+        .collect();
+    println!("\nComparison of median total time to {baseline}, threshold {threshold} %");
+    print_table(
+        &["Year", "Day", "Baseline", "Current", "Change", "Status"],
+        &rows,
+    );
+    regressions
+}
+
+/// Benchmarks the selected `parts` of all `solvers` as set in `options`, with each run limited to
+/// `timeout`, prints the statistics and stores the medians in the history if both parts were run
+/// on the default inputs with the default parameters. Returns an error if any puzzle failed, or
+/// was slower than the baseline.
+pub fn run(
+    solvers: impl Iterator<Item = &'static Solver>,
+    options: &BenchOptions,
+    source: &InputSource,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<(), Box<dyn Error>> {
+    let iterations = options.iterations;
+    let mut history = BenchHistory::load()?;
+    let baseline = options.baseline.as_deref().map(history::resolve_revision);
+    if let Some(baseline) = &baseline
+        && !history.has_revision(baseline)
+    {
+        return Err(format!("No benchmark results stored for revision {baseline}").into());
+    }
+    let mut results = Vec::new();
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    for solver in solvers {
+        match bench_puzzle(solver, source, iterations, parts, params, timeout) {
+            Ok(result) => {
+                let allocs = result.allocs.unwrap_or_default();
+                for (phase, stats, allocs) in [
+                    ("parse", Some(result.parse), allocs.parse),
+                    (
+                        "part 1",
+                        parts.part1().then_some(result.part1),
+                        allocs.part1,
+                    ),
+                    (
+                        "part 2",
+                        parts.part2().then_some(result.part2),
+                        allocs.part2,
+                    ),
+                    ("total", Some(result.total), allocs.total()),
+                ]
+                .into_iter()
+                .filter_map(|(phase, stats, allocs)| Some((phase, stats?, allocs)))
+                {
+                    let mut row = vec![
+                        solver.year.to_string(),
+                        solver.day.to_string(),
+                        phase.to_string(),
+                        micros(stats.min),
+                        micros(stats.median),
+                        micros(stats.mean),
+                        micros(stats.p95),
+                    ];
+                    if memory::ENABLED {
+                        row.push(allocs.count.to_string());
+                        row.push(memory::format_bytes(allocs.bytes));
+                        row.push(memory::format_bytes(allocs.peak));
+                    }
+                    rows.push(row);
+                }
+                results.push(result);
+            }
+            Err(e) => failures.push((solver, e)),
+        }
+    }
+    if rows.is_empty() && failures.is_empty() {
+        return Err("No implemented puzzles match the selection".into());
+    }
+
+    let revision = history::current_revision();
+    println!("{iterations} iterations per puzzle, revision {revision}");
+    let mut header = vec!["Year", "Day", "Phase", "Min", "Median", "Mean", "P95"];
+    if memory::ENABLED {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+    print_table(&header, &rows);
+    let regressions = match &baseline {
+        Some(baseline) => print_comparison(&results, &history, baseline, options.threshold),
+        None => 0,
+    };
+
+    if !results.is_empty()
+        && parts == Parts::Both
+        && params.is_empty()
+        && *source == InputSource::default()
+    {
+        for r in &results {
+            history.insert(r.solver.year, r.solver.day, &revision, r.medians());
+        }
+        history.save()?;
+    }
+
+    if !failures.is_empty() {
+        println!("\nFailures:");
+        for (solver, e) in &failures {
+            println!("  {} day {}: {e}", solver.year, solver.day);
+        }
+    }
+    match (failures.len(), regressions) {
+        (0, 0) => Ok(()),
+        (f, 0) => Err(format!("{f} puzzles failed").into()),
+        (0, r) => Err(format!("{r} puzzles slower than the baseline").into()),
+        (f, r) => Err(format!("{f} puzzles failed, {r} slower than the baseline").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_odd() {
+        let samples: Vec<_> = [5, 1, 3].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+    }
+
+    #[test]
+    fn stats_even() {
+        let samples: Vec<_> = (1..=20).map(Duration::from_micros).collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(10_500));
+        assert_eq!(stats.mean, Duration::from_nanos(10_500));
+        assert_eq!(stats.p95, Duration::from_micros(19));
+    }
+}
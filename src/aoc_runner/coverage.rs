@@ -0,0 +1,183 @@
+//! # Coverage matrix
+//!
+//! Summarises the progress over all years, `--coverage`, as a table with a row per day and a
+//! column per year. Each implemented puzzle is marked as such, as verified if the answer store has
+//! its answers, and as having examples if its solver lists any, together with the median total
+//! time of its latest benchmark on the checked out history.
+//!
+//! The table is printed as text, or with `--format markdown` as a Markdown table to paste into
+//! the README.
+use crate::aoc_runner::answers::AnswerStore;
+use crate::aoc_runner::batch::print_table;
+use crate::aoc_runner::format::Format;
+use crate::aoc_runner::history::{self, BenchHistory};
+use crate::aoc_runner::solver::Solver;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::time::Duration;
+
+/// Explains the marks used in the cells.
+const LEGEND: &str = "✓ implemented, V verified answers, E examples, time is the latest median";
+
+/// Formats `d` with a unit suited to its size, e.g. `850 μs`, `12.3 ms` or `1.5 s`.
+fn short_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1_000 {
+        format!("{micros} μs")
+    } else if micros < 1_000_000 {
+        format!("{:.1} ms", d.as_secs_f64() * 1_000.0)
+    } else {
+        format!("{:.1} s", d.as_secs_f64())
+    }
+}
+
+/// The coverage of one implemented puzzle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cell {
+    verified: bool,
+    examples: bool,
+    median: Option<Duration>,
+}
+
+impl Cell {
+    fn to_text(self) -> String {
+        let mut text = "✓".to_string();
+        if self.verified {
+            text.push('V');
+        }
+        if self.examples {
+            text.push('E');
+        }
+        if let Some(median) = self.median {
+            text = format!("{text} {}", short_duration(median));
+        }
+        text
+    }
+}
+
+/// The header and rows of the coverage table of `solvers`, with a row for each day and a final
+/// row of totals. `verified` and `median` look up the state of a puzzle by year and day.
+fn table(
+    solvers: impl Iterator<Item = &'static Solver>,
+    verified: impl Fn(u16, u8) -> bool,
+    median: impl Fn(u16, u8) -> Option<Duration>,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let cells: BTreeMap<_, _> = solvers
+        .map(|s| {
+            let cell = Cell {
+                verified: verified(s.year, s.day),
+                examples: !s.examples.is_empty(),
+                median: median(s.year, s.day),
+            };
+            ((s.year, s.day), cell)
+        })
+        .collect();
+    let mut years: Vec<_> = cells.keys().map(|(year, _)| *year).collect();
+    years.dedup();
+
+    let header = ["Day".to_string()]
+        .into_iter()
+        .chain(years.iter().map(|year| year.to_string()))
+        .collect();
+    let mut rows: Vec<Vec<String>> = (1..=25)
+        .map(|day| {
+            [day.to_string()]
+                .into_iter()
+                .chain(years.iter().map(|year| {
+                    cells
+                        .get(&(*year, day))
+                        .map_or_else(String::new, |cell| cell.to_text())
+                }))
+                .collect()
+        })
+        .collect();
+    rows.push(
+        ["Total".to_string()]
+            .into_iter()
+            .chain(years.iter().map(|year| {
+                let year_cells: Vec<_> = cells.range((*year, 0)..=(*year, u8::MAX)).collect();
+                let count = |f: fn(&Cell) -> bool| year_cells.iter().filter(|(_, c)| f(c)).count();
+                format!(
+                    "{}✓ {}V {}E",
+                    year_cells.len(),
+                    count(|c| c.verified),
+                    count(|c| c.examples)
+                )
+            }))
+            .collect(),
+    );
+    (header, rows)
+}
+
+/// Returns the table as Markdown, with the day column right aligned.
+fn to_markdown(header: &[String], rows: &[Vec<String>]) -> String {
+    let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+    let mut out = line(header);
+    let separator: Vec<_> = (0..header.len())
+        .map(|i| if i == 0 { "--:" } else { "---" }.to_string())
+        .collect();
+    out += &line(&separator);
+    for row in rows {
+        out += &line(row);
+    }
+    out
+}
+
+/// Prints the coverage table of `solvers` in `format`, based on the answer store and the benchmark
+/// history in the current directory.
+pub fn run(
+    solvers: impl Iterator<Item = &'static Solver>,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let store = AnswerStore::load()?;
+    let bench_history = BenchHistory::load()?;
+    let revisions = history::recent_revisions();
+    let (header, rows) = table(
+        solvers,
+        |year, day| store.get(year, day).is_some(),
+        |year, day| bench_history.latest(year, day, &revisions).map(|m| m.total),
+    );
+    if format == Format::Markdown {
+        print!("{}", to_markdown(&header, &rows));
+    } else {
+        let header: Vec<_> = header.iter().map(String::as_str).collect();
+        print_table(&header, &rows);
+    }
+    println!("\n{LEGEND}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::solver;
+
+    #[test]
+    fn durations() {
+        assert_eq!(short_duration(Duration::from_micros(850)), "850 μs");
+        assert_eq!(short_duration(Duration::from_micros(12_345)), "12.3 ms");
+        assert_eq!(short_duration(Duration::from_millis(1_500)), "1.5 s");
+    }
+
+    #[test]
+    fn coverage_table() {
+        let solvers = solver::all().filter(|s| s.year == 2024 && s.day <= 2);
+        let (header, rows) = table(
+            solvers,
+            |_, day| day == 1,
+            |_, day| (day == 2).then(|| Duration::from_micros(120)),
+        );
+        assert_eq!(header, ["Day", "2024"]);
+        assert_eq!(rows.len(), 26);
+        assert_eq!(rows[0], ["1", "✓VE"]);
+        assert_eq!(rows[1], ["2", "✓E 120 μs"]);
+        assert_eq!(rows[2], ["3", ""]);
+        assert_eq!(rows[25], ["Total", "2✓ 1V 2E"]);
+
+        let markdown = to_markdown(&header, &rows[..2]);
+        assert_eq!(
+            markdown,
+            "| Day | 2024 |\n| --: | --- |\n| 1 | ✓VE |\n| 2 | ✓E 120 μs |\n"
+        );
+    }
+}
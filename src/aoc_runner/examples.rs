@@ -0,0 +1,313 @@
+//! # Puzzle examples
+//!
+//! The puzzle descriptions come with small example inputs and their answers. Each day lists these
+//! in `Solution::EXAMPLES`, with the expected answer of each part they apply to and any parameters
+//! they need, e.g. a smaller grid size. The same examples are used by the unit tests of the day,
+//! through `check`, and by the `--example [N]` mode, which solves them without the private input.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::answers::Status;
+use crate::aoc_runner::batch::{self, Failure, print_table};
+use crate::aoc_runner::params::Params;
+use crate::aoc_runner::solver::{Outcome, Parts, Solver};
+use crate::{Config, Selection};
+use std::error::Error;
+
+/// An example input from the puzzle description, with its expected answers.
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    /// Parameter values for the example, as comma separated `name=value` pairs.
+    pub params: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// Creates an example with the input `input`, which applies to neither part until given an
+    /// expected answer with `part1` or `part2`.
+    pub const fn new(input: &'static str) -> Self {
+        Self {
+            input,
+            params: "",
+            part1: None,
+            part2: None,
+        }
+    }
+
+    /// Sets the expected answer of part 1, written as in the answer store.
+    pub const fn part1(mut self, answer: &'static str) -> Self {
+        self.part1 = Some(answer);
+        self
+    }
+
+    /// Sets the expected answer of part 2, written as in the answer store.
+    pub const fn part2(mut self, answer: &'static str) -> Self {
+        self.part2 = Some(answer);
+        self
+    }
+
+    /// Sets the parameter values of the example, e.g. `"dim=7,bytes=12"`.
+    pub const fn params(mut self, params: &'static str) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Returns the parts which have an expected answer, limited to `selected`, or `None` if there
+    /// are no such parts.
+    pub fn parts(&self, selected: Parts) -> Option<Parts> {
+        match (
+            self.part1.is_some() && selected.part1(),
+            self.part2.is_some() && selected.part2(),
+        ) {
+            (true, true) => Some(Parts::Both),
+            (true, false) => Some(Parts::Part1),
+            (false, true) => Some(Parts::Part2),
+            (false, false) => None,
+        }
+    }
+
+    /// Returns the parameter values of the example.
+    pub fn overrides(&self) -> Result<Params, &'static str> {
+        if self.params.is_empty() {
+            Ok(Params::default())
+        } else {
+            self.params.parse()
+        }
+    }
+
+    /// Compares the answers in `outcome` to the expected ones, and returns a description of each
+    /// mismatch.
+    pub fn mismatches(&self, outcome: &Outcome) -> Vec<String> {
+        [
+            (1, outcome.part1.as_ref(), self.part1),
+            (2, outcome.part2.as_ref(), self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(part, answer, expected)| match (answer, expected?) {
+            (Some(answer), expected) if answer.matches(expected) => None,
+            (Some(answer), expected) => Some(format!(
+                "part {part}: expected {expected:?}, got {:?}",
+                answer.to_string()
+            )),
+            (None, _) => None,
+        })
+        .collect()
+    }
+}
+
+/// Solves all examples of the day `S`, and panics on any error or wrong answer.
+#[cfg(test)]
+pub fn check<S: crate::aoc_runner::solver::Solution<'static>>() {
+    assert!(!S::EXAMPLES.is_empty(), "no examples");
+    for (i, example) in S::EXAMPLES.iter().enumerate() {
+        let parts = example.parts(Parts::Both).expect("no expected answers");
+        let params = example.overrides().unwrap();
+        let outcome = crate::aoc_runner::solver::run::<S>(example.input, parts, &params)
+            .unwrap_or_else(|e| panic!("example {}: {e}", i + 1));
+        let mismatches = example.mismatches(&outcome);
+        assert!(mismatches.is_empty(), "example {}: {mismatches:?}", i + 1);
+    }
+}
+
+/// The result of solving one example.
+struct ExampleResult {
+    solver: &'static Solver,
+    /// The 1-based number of the example within the day.
+    number: usize,
+    answers: Result<Outcome, Failure>,
+    mismatches: Vec<String>,
+}
+
+/// Solves the examples of `solver`, all or only example `number`, with the parts and parameter
+/// overrides in `config`. Examples without an expected answer for the selected parts are skipped.
+fn run_examples(
+    solver: &'static Solver,
+    number: Option<usize>,
+    config: &Config,
+) -> Vec<ExampleResult> {
+    solver
+        .examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| (i + 1, example))
+        .filter(|(n, _)| number.is_none_or(|number| number == *n))
+        .filter_map(|(n, example)| {
+            let parts = example.parts(config.parts)?;
+            let answers = example
+                .overrides()
+                .map_err(|e| Failure::Error(e.to_string()))
+                .and_then(|mut params| {
+                    params.extend(config.params.clone());
+                    batch::run_isolated(
+                        solver,
+                        example.input.to_string(),
+                        parts,
+                        &params,
+                        config.timeout,
+                    )
+                });
+            let mismatches = match &answers {
+                Ok(outcome) => example.mismatches(outcome),
+                Err(_) => Vec::new(),
+            };
+            Some(ExampleResult {
+                solver,
+                number: n,
+                answers,
+                mismatches,
+            })
+        })
+        .collect()
+}
+
+/// Solves the examples of all `solvers`, all or only example `number` of each, prints a table of
+/// the answers and whether they match the expected ones, and lists any mismatches and failures.
+/// Returns an error if any example failed or didn't match.
+pub fn run(
+    solvers: impl Iterator<Item = &'static Solver>,
+    number: Option<usize>,
+    config: &Config,
+) -> Result<(), Box<dyn Error>> {
+    let results: Vec<_> = solvers
+        .flat_map(|solver| run_examples(solver, number, config))
+        .collect();
+    if results.is_empty() {
+        return Err(match (&config.selection, number) {
+            (Selection::Day(year, day), Some(n)) => format!("{year} day {day} has no example {n}"),
+            (Selection::Day(year, day), None) => format!("{year} day {day} has no examples"),
+            _ => "No examples for the selected puzzles".to_string(),
+        }
+        .into());
+    }
+
+    let status = |r: &ExampleResult| match (&r.answers, r.mismatches.is_empty()) {
+        (Err(e), _) => e.label().to_string(),
+        (Ok(_), true) => Status::Pass.to_string(),
+        (Ok(_), false) => Status::Fail.to_string(),
+    };
+    let answer = |r: &ExampleResult, part: fn(&Outcome) -> Option<&Answer>| {
+        batch::cell(r.answers.as_ref().ok().and_then(part))
+    };
+    let rows: Vec<_> = results
+        .iter()
+        .map(|r| {
+            vec![
+                r.solver.year.to_string(),
+                r.solver.day.to_string(),
+                r.number.to_string(),
+                answer(r, |o| o.part1.as_ref()),
+                answer(r, |o| o.part2.as_ref()),
+                status(r),
+            ]
+        })
+        .collect();
+    print_table(
+        &["Year", "Day", "Example", "Part 1", "Part 2", "Check"],
+        &rows,
+    );
+
+    for r in &results {
+        if let Ok(outcome) = &r.answers {
+            for (part, answer) in [(1, &outcome.part1), (2, &outcome.part2)] {
+                if let Some(Answer::Screen(rows)) = answer {
+                    println!(
+                        "\n{} day {} example {} part {part}:\n{}",
+                        r.solver.year,
+                        r.solver.day,
+                        r.number,
+                        rows.join("\n")
+                    );
+                }
+            }
+        }
+    }
+
+    let mismatched: Vec<_> = results
+        .iter()
+        .filter(|r| !r.mismatches.is_empty())
+        .collect();
+    if !mismatched.is_empty() {
+        println!("\nMismatches:");
+        for r in &mismatched {
+            for m in &r.mismatches {
+                let (year, day) = (r.solver.year, r.solver.day);
+                println!("  {year} day {day} example {} {m}", r.number);
+            }
+        }
+    }
+    let failed: Vec<_> = results.iter().filter(|r| r.answers.is_err()).collect();
+    if !failed.is_empty() {
+        println!("\nFailures:");
+        for r in &failed {
+            if let Err(e) = &r.answers {
+                let (year, day) = (r.solver.year, r.solver.day);
+                println!("  {year} day {day} example {}: {e}", r.number);
+            }
+        }
+    }
+
+    match failed.len() + mismatched.len() {
+        0 => Ok(()),
+        n => Err(format!("{n} of {} examples failed", results.len()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_parts() {
+        let example = Example::new("x").part2("2");
+        assert_eq!(example.parts(Parts::Both), Some(Parts::Part2));
+        assert_eq!(example.parts(Parts::Part1), None);
+        let example = example.part1("1");
+        assert_eq!(example.parts(Parts::Both), Some(Parts::Both));
+        assert_eq!(example.parts(Parts::Part1), Some(Parts::Part1));
+        assert_eq!(Example::new("x").parts(Parts::Both), None);
+    }
+
+    #[test]
+    fn example_mismatches() {
+        let example = Example::new("x").part1("22").part2("6,1").params("dim=7");
+        assert_eq!(example.overrides(), "dim=7".parse());
+        assert_eq!(Example::new("x").overrides(), Ok(Params::default()));
+        let outcome = |p1, p2| Outcome {
+            part1: Some(Answer::Int(p1)),
+            part2: p2,
+            times: Default::default(),
+            allocs: None,
+        };
+        assert!(example.mismatches(&outcome(22, None)).is_empty());
+        assert_eq!(
+            example.mismatches(&outcome(21, Some(Answer::Point(1, 6)))),
+            [
+                "part 1: expected \"22\", got \"21\"",
+                "part 2: expected \"6,1\", got \"1,6\""
+            ]
+        );
+    }
+
+    #[test]
+    fn run_selected() {
+        let solver = crate::aoc_runner::solver::find(2024, 18).unwrap();
+        let config = |args: &str| Config::build(args.split_whitespace().map(String::from)).unwrap();
+
+        let results = run_examples(solver, Some(1), &config("aoc 2024 18 --example"));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].number, 1);
+        assert!(results[0].answers.is_ok() && results[0].mismatches.is_empty());
+
+        let results = run_examples(
+            solver,
+            None,
+            &config("aoc 2024 18 --part 1 --param bytes=20"),
+        );
+        assert!(results.iter().all(|r| r.answers.is_ok()));
+        assert_eq!(
+            results[0].mismatches,
+            ["part 1: expected \"22\", got \"24\""]
+        );
+        assert!(run_examples(solver, Some(9), &config("aoc 2024 18")).is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+//! # Machine-readable output
+//!
+//! Formats the results of a run as JSON or CSV, with one record per puzzle holding the year, day,
+//! title, both answers, the phase timings in nanoseconds, the verification status if verifying,
+//! and the error message if the puzzle failed. Integer answers are written as JSON numbers, and
+//! all other answers as strings. A part which wasn't run gets `null` answer and time fields in
+//! JSON, and empty ones in CSV. Multi-line answers, such as rendered screens, are escaped as JSON
+//! strings or quoted CSV fields.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::batch::PuzzleResult;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// The output format of the runner, `--format text|json|csv|markdown`. Markdown is only supported
+/// by the coverage matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" => Ok(Format::Markdown),
+            _ => Err("Invalid format, expected text, json, csv or markdown."),
+        }
+    }
+}
+
+/// Returns `s` as a quoted JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Returns `s` as a CSV field, quoted if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn is_int(answer: Option<&Answer>) -> bool {
+    matches!(answer, Some(Answer::Int(_)))
+}
+
+/// The fields of one record, as (name, value) where the value is `None` if not applicable, and
+/// a flag telling if the value is a string rather than a number.
+fn fields(r: &PuzzleResult) -> Vec<(&'static str, Option<String>, bool)> {
+    let (p1, p2, error) = match &r.answers {
+        Ok((p1, p2)) => (p1.clone(), p2.clone(), None),
+        Err(e) => (None, None, Some(e.to_string())),
+    };
+    let ok = r.answers.is_ok();
+    let nanos = |d: std::time::Duration| ok.then(|| d.as_nanos().to_string());
+    let part1_ns = nanos(r.times.part1).filter(|_| p1.is_some());
+    let part2_ns = nanos(r.times.part2).filter(|_| p2.is_some());
+    vec![
+        ("year", Some(r.solver.year.to_string()), false),
+        ("day", Some(r.solver.day.to_string()), false),
+        ("title", Some(r.solver.title.to_string()), true),
+        (
+            "part1",
+            p1.as_ref().map(Answer::to_string),
+            !is_int(p1.as_ref()),
+        ),
+        (
+            "part2",
+            p2.as_ref().map(Answer::to_string),
+            !is_int(p2.as_ref()),
+        ),
+        ("parse_ns", nanos(r.times.parse), false),
+        ("part1_ns", part1_ns, false),
+        ("part2_ns", part2_ns, false),
+        ("total_ns", nanos(r.times.total()), false),
+        ("status", r.status.map(|s| s.to_string()), true),
+        ("error", error, true),
+    ]
+}
+
+/// Formats `results` as a JSON array with one object per puzzle.
+pub fn to_json(results: &[PuzzleResult]) -> String {
+    let records: Vec<_> = results
+        .iter()
+        .map(|r| {
+            let members: Vec<_> = fields(r)
+                .into_iter()
+                .map(|(name, value, is_string)| {
+                    let value = match value {
+                        None => "null".to_string(),
+                        Some(v) if is_string => json_string(&v),
+                        Some(v) => v,
+                    };
+                    format!("\"{name}\":{value}")
+                })
+                .collect();
+            format!("  {{{}}}", members.join(","))
+        })
+        .collect();
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+/// Formats `results` as CSV with a header row and one row per puzzle.
+pub fn to_csv(results: &[PuzzleResult]) -> String {
+    let mut out = String::new();
+    if let Some(first) = results.first() {
+        let header: Vec<_> = fields(first).iter().map(|(name, _, _)| *name).collect();
+        out.push_str(&header.join(","));
+        out.push('\n');
+    }
+    for r in results {
+        let row: Vec<_> = fields(r)
+            .into_iter()
+            .map(|(_, value, _)| csv_field(&value.unwrap_or_default()))
+            .collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::batch::Failure;
+    use crate::aoc_runner::solver::{self, PhaseTimes};
+    use std::time::Duration;
+
+    fn results() -> Vec<PuzzleResult> {
+        vec![
+            PuzzleResult {
+                solver: solver::find(2016, 8).unwrap(),
+                answers: Ok((Some(Answer::Int(6)), Some(Answer::screen(".#\n#.")))),
+                times: PhaseTimes {
+                    parse: Duration::from_nanos(1),
+                    part1: Duration::from_nanos(2),
+                    part2: Duration::from_nanos(3),
+                },
+                allocs: None,
+                status: None,
+            },
+            PuzzleResult {
+                solver: solver::find(2015, 1).unwrap(),
+                answers: Err(Failure::Error("Can't read \"x\", y".to_string())),
+                times: PhaseTimes::default(),
+                allocs: None,
+                status: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(json_string("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field("a,\"b\"\nc"), "\"a,\"\"b\"\"\nc\"");
+    }
+
+    #[test]
+    fn json_records() {
+        assert_eq!(
+            to_json(&results()),
+            r#"[
+  {"year":2016,"day":8,"title":"Two-Factor Authentication","part1":6,"part2":"\n.#\n#.\n","parse_ns":1,"part1_ns":2,"part2_ns":3,"total_ns":6,"status":null,"error":null},
+  {"year":2015,"day":1,"title":"Not Quite Lisp","part1":null,"part2":null,"parse_ns":null,"part1_ns":null,"part2_ns":null,"total_ns":null,"status":null,"error":"Can't read \"x\", y"}
+]
+"#
+        );
+    }
+
+    #[test]
+    fn csv_records() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,title,part1,part2,parse_ns,part1_ns,part2_ns,total_ns,status,error
+2016,8,Two-Factor Authentication,6,\"
+.#
+#.
+\",1,2,3,6,,
+2015,1,Not Quite Lisp,,,,,,,,\"Can't read \"\"x\"\", y\"
+"
+        );
+    }
+
+    #[test]
+    fn skipped_part() {
+        let result = PuzzleResult {
+            solver: solver::find(2015, 1).unwrap(),
+            answers: Ok((None, Some(Answer::Int(5)))),
+            times: PhaseTimes {
+                parse: Duration::from_nanos(1),
+                part1: Duration::ZERO,
+                part2: Duration::from_nanos(3),
+            },
+            allocs: None,
+            status: None,
+        };
+        assert_eq!(
+            to_csv(&[result]).lines().nth(1),
+            Some("2015,1,Not Quite Lisp,,5,1,,3,4,,")
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! # Benchmark history
+//!
+//! Keeps the median timings of benchmark runs in the file `AdventOfCode-Bench.tsv`, next to the
+//! `AdventOfCode-Input` folder, keyed by year, day and the git revision the run was made on. A
+//! revision with uncommitted changes gets a `-dirty` suffix. Each line holds tab-separated year,
+//! day, revision, and the median parse, part 1, part 2 and total times in nanoseconds.
+//!
+//! Results of a new run can then be compared to those stored for a baseline revision, flagging
+//! any puzzle which got slower by more than a given percentage.
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+/// The median timings of one benchmarked puzzle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Medians {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    pub total: Duration,
+}
+
+/// Benchmark medians keyed by year, day and revision.
+type Entries = BTreeMap<(u16, u8, String), Medians>;
+
+pub struct BenchHistory {
+    path: PathBuf,
+    entries: Entries,
+}
+
+/// Runs git with `args` in the current directory and returns the trimmed output, or `None` if git
+/// isn't available or the command fails.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns the short hash of the checked out revision, with a `-dirty` suffix if tracked files have
+/// uncommitted changes, or `unknown` outside of a git repository.
+pub fn current_revision() -> String {
+    let Some(rev) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => rev + "-dirty",
+        _ => rev,
+    }
+}
+
+/// Returns the revisions of the checked out history, newest first: the current revision if it has
+/// uncommitted changes, followed by the short hashes of `HEAD` and its ancestors. Empty outside of
+/// a git repository.
+pub fn recent_revisions() -> Vec<String> {
+    let mut revisions = Vec::new();
+    let current = current_revision();
+    if current.ends_with("-dirty") {
+        revisions.push(current);
+    }
+    if let Some(log) = git(&["log", "--format=%h"]) {
+        revisions.extend(log.lines().map(String::from));
+    }
+    revisions
+}
+
+/// Resolves `rev`, e.g. a branch name, to a short hash. Revisions unknown to git, such as
+/// `abc123-dirty`, are used as given.
+pub fn resolve_revision(rev: &str) -> String {
+    git(&["rev-parse", "--short", rev]).unwrap_or_else(|| rev.to_string())
+}
+
+impl BenchHistory {
+    /// Loads the history file from the current directory. A missing file gives an empty history.
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        Self::load_from(env::current_dir()?.join("AdventOfCode-Bench.tsv"))
+    }
+
+    /// Loads the history file at `path`. A missing file gives an empty history.
+    pub fn load_from(path: PathBuf) -> Result<Self, Box<dyn Error>> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let entries = Self::parse(&contents)
+            .map_err(|line| format!("{}: line {line}: invalid history entry", path.display()))?;
+        Ok(Self { path, entries })
+    }
+
+    /// Parses the file contents, returning the (1-based) line number of the first invalid line
+    /// on failure.
+    fn parse(contents: &str) -> Result<Entries, usize> {
+        let mut entries = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.split('\t').collect();
+            let [year, day, rev, times @ ..] = &fields[..] else {
+                return Err(i + 1);
+            };
+            let times: Vec<Duration> = times
+                .iter()
+                .map(|t| t.parse().map(Duration::from_nanos))
+                .collect::<Result<_, _>>()
+                .map_err(|_| i + 1)?;
+            let (Ok(year), Ok(day), &[parse, part1, part2, total]) =
+                (year.parse(), day.parse(), &times[..])
+            else {
+                return Err(i + 1);
+            };
+            entries.insert(
+                (year, day, rev.to_string()),
+                Medians {
+                    parse,
+                    part1,
+                    part2,
+                    total,
+                },
+            );
+        }
+        Ok(entries)
+    }
+
+    /// Writes the history back to the file it was loaded from.
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        fs::write(&self.path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the stored medians for `year` and `day` at revision `rev`, if any.
+    pub fn get(&self, year: u16, day: u8, rev: &str) -> Option<&Medians> {
+        self.entries.get(&(year, day, rev.to_string()))
+    }
+
+    /// Returns whether any medians are stored at revision `rev`.
+    pub fn has_revision(&self, rev: &str) -> bool {
+        self.entries.keys().any(|(_, _, r)| r == rev)
+    }
+
+    /// Returns the medians for `year` and `day` at the first of `revisions` which has an entry,
+    /// i.e. the latest one if the revisions are given newest first.
+    pub fn latest(&self, year: u16, day: u8, revisions: &[String]) -> Option<&Medians> {
+        revisions.iter().find_map(|rev| self.get(year, day, rev))
+    }
+
+    /// Stores the medians for `year` and `day` at revision `rev`, replacing any previous entry.
+    pub fn insert(&mut self, year: u16, day: u8, rev: &str, medians: Medians) {
+        self.entries.insert((year, day, rev.to_string()), medians);
+    }
+}
+
+impl fmt::Display for BenchHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# year\tday\trevision\tparse\tpart 1\tpart 2\ttotal (ns)"
+        )?;
+        for ((year, day, rev), m) in &self.entries {
+            writeln!(
+                f,
+                "{year}\t{day}\t{rev}\t{}\t{}\t{}\t{}",
+                m.parse.as_nanos(),
+                m.part1.as_nanos(),
+                m.part2.as_nanos(),
+                m.total.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of comparing a new median to a baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    /// Within the threshold, or faster. Holds the change in percent.
+    Ok(f64),
+    /// Slower than the baseline by more than the threshold. Holds the change in percent.
+    Slower(f64),
+    /// No baseline stored for the puzzle, though the baseline revision has other puzzles.
+    NoBaseline,
+}
+
+/// Compares the `current` median to the `baseline`, flagging it as slower if the increase is
+/// larger than `threshold` percent.
+pub fn compare(current: Duration, baseline: Option<Duration>, threshold: f64) -> Comparison {
+    let Some(baseline) = baseline else {
+        return Comparison::NoBaseline;
+    };
+    let change = if baseline.is_zero() {
+        0.0
+    } else {
+        (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+    };
+    if change > threshold {
+        Comparison::Slower(change)
+    } else {
+        Comparison::Ok(change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_roundtrip() {
+        let history = BenchHistory {
+            path: PathBuf::new(),
+            entries: BenchHistory::parse("# comment\n2023\t17\tabc123\t10\t20\t30\t60\n").unwrap(),
+        };
+        assert_eq!(
+            history.get(2023, 17, "abc123"),
+            Some(&Medians {
+                parse: Duration::from_nanos(10),
+                part1: Duration::from_nanos(20),
+                part2: Duration::from_nanos(30),
+                total: Duration::from_nanos(60),
+            })
+        );
+        assert_eq!(history.get(2023, 17, "def456"), None);
+        assert_eq!(
+            BenchHistory::parse(&history.to_string()),
+            Ok(history.entries)
+        );
+    }
+
+    #[test]
+    fn latest_revision() {
+        let history = BenchHistory {
+            path: PathBuf::new(),
+            entries: BenchHistory::parse("2023\t1\taaa\t1\t1\t1\t3\n2023\t1\tccc\t2\t2\t2\t6\n")
+                .unwrap(),
+        };
+        let revisions = |revs: &[&str]| revs.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+        let total = |revs| history.latest(2023, 1, &revisions(revs)).map(|m| m.total);
+        assert_eq!(total(&["bbb", "ccc", "aaa"]), Some(Duration::from_nanos(6)));
+        assert_eq!(total(&["bbb", "aaa"]), Some(Duration::from_nanos(3)));
+        assert_eq!(total(&["bbb"]), None);
+        assert_eq!(history.latest(2023, 2, &revisions(&["aaa"])), None);
+        assert!(history.has_revision("ccc"));
+        assert!(!history.has_revision("bbb"));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(BenchHistory::parse("2023\t17\tabc\t1\t2\t3\n"), Err(1));
+        assert_eq!(BenchHistory::parse("\n2023\t17\tabc\t1\tx\t3\t4\n"), Err(2));
+    }
+
+    #[test]
+    fn compare_threshold() {
+        let ms = Duration::from_millis;
+        assert!(matches!(
+            compare(ms(105), Some(ms(100)), 10.0),
+            Comparison::Ok(c) if (c - 5.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            compare(ms(120), Some(ms(100)), 10.0),
+            Comparison::Slower(_)
+        ));
+        assert!(matches!(compare(ms(50), Some(ms(100)), 10.0), Comparison::Ok(c) if c < 0.0));
+        assert_eq!(compare(ms(50), None, 10.0), Comparison::NoBaseline);
+    }
+}
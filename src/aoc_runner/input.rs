@@ -0,0 +1,108 @@
+//! # Puzzle input
+//!
+//! Locates and reads the input of a puzzle. By default, inputs are read from
+//! `AdventOfCode-Input/<year>/dayNN.txt` in the current directory. The root folder can be moved
+//! with the `AOC_INPUT_DIR` environment variable or the `--input-dir` option, and a single puzzle
+//! can be given an explicit input file, or `-` for stdin, with `--input`.
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable overriding the input root folder.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read puzzle inputs from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// `<root>/<year>/dayNN.txt`, with the root given by `--input-dir`, or if not set by
+    /// `AOC_INPUT_DIR`, or otherwise `AdventOfCode-Input` in the current directory.
+    Directory(Option<PathBuf>),
+    /// The same file for every puzzle, `--input <file>`.
+    File(PathBuf),
+    /// Standard input, `--input -`.
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(None)
+    }
+}
+
+impl InputSource {
+    /// Creates the source for an `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Returns the path of the input file for `year` and `day`, or `None` for stdin.
+    pub fn path(&self, year: u16, day: u8) -> Result<Option<PathBuf>, Box<dyn Error>> {
+        let root = match self {
+            InputSource::Stdin => return Ok(None),
+            InputSource::File(path) => return Ok(Some(path.clone())),
+            InputSource::Directory(Some(root)) => root.clone(),
+            InputSource::Directory(None) => match env::var_os(INPUT_DIR_VAR) {
+                Some(root) => PathBuf::from(root),
+                None => env::current_dir()
+                    .map_err(|e| format!("Can't get the current directory: {e}"))?
+                    .join("AdventOfCode-Input"),
+            },
+        };
+        Ok(Some(
+            root.join(year.to_string()).join(format!("day{day:02}.txt")),
+        ))
+    }
+
+    /// Reads the input for `year` and `day`, with any trailing newlines removed.
+    pub fn read(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let contents = match self.path(year, day)? {
+            Some(path) => read_file(&path)?,
+            None => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|e| format!("Can't read input from stdin: {e}"))?;
+                contents
+            }
+        };
+        Ok(contents.trim_end_matches('\n').to_string())
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, Box<dyn Error>> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("Can't read input file {}: {e}", path.display()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_paths() {
+        let dir = InputSource::Directory(Some(PathBuf::from("inputs")));
+        assert_eq!(
+            dir.path(2015, 1).unwrap(),
+            Some(PathBuf::from("inputs/2015/day01.txt"))
+        );
+        let file = InputSource::from_arg("example.txt");
+        assert_eq!(
+            file.path(2015, 1).unwrap(),
+            Some(PathBuf::from("example.txt"))
+        );
+        assert_eq!(InputSource::from_arg("-").path(2015, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn missing_file_names_path() {
+        let dir = InputSource::Directory(Some(PathBuf::from("no-such-dir")));
+        let e = dir.read(2015, 1).unwrap_err().to_string();
+        assert!(e.contains("no-such-dir/2015/day01.txt"), "{e}");
+    }
+}
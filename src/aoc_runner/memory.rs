@@ -0,0 +1,173 @@
+//! # Allocation accounting
+//!
+//! With the `alloc-stats` cargo feature, a counting global allocator wraps the system allocator,
+//! and each solve phase records how many allocations it made, how many bytes they requested in
+//! total, and the peak of live heap bytes above what was live when the phase started. This shows
+//! which solutions allocate heavily, e.g. by cloning strings or rebuilding sets in a loop.
+//!
+//! The counters are process wide, so they include allocations on any threads a solver spawns. The
+//! puzzles are therefore run one at a time when the feature is enabled. Without the feature the
+//! system allocator is used as is, and no numbers are recorded.
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "alloc-stats")]
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+/// True if allocations are counted, i.e. the `alloc-stats` feature is enabled.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// The allocations made during one phase of a solver run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    /// The number of allocations, including reallocations.
+    pub count: usize,
+    /// The total number of bytes requested by the allocations.
+    pub bytes: usize,
+    /// The peak number of live bytes, above those live when the phase started.
+    pub peak: usize,
+}
+
+/// The allocations of each phase of a solver run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PhaseAllocs {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl PhaseAllocs {
+    /// Returns the allocations of all phases together, with the highest peak of any phase.
+    pub fn total(&self) -> AllocStats {
+        let phases = [self.parse, self.part1, self.part2];
+        AllocStats {
+            count: phases.iter().map(|p| p.count).sum(),
+            bytes: phases.iter().map(|p| p.bytes).sum(),
+            peak: phases.iter().map(|p| p.peak).max().unwrap_or_default(),
+        }
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "alloc-stats")]
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-stats")]
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-stats")]
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-stats")]
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations and live bytes.
+#[cfg(feature = "alloc-stats")]
+struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+impl Counting {
+    fn record(grown: usize) {
+        COUNT.fetch_add(1, Relaxed);
+        BYTES.fetch_add(grown, Relaxed);
+        let live = LIVE.fetch_add(grown, Relaxed) + grown;
+        PEAK.fetch_max(live, Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Counting::record(layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Counting::record(layout.size());
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        LIVE.fetch_sub(layout.size(), Relaxed);
+        Counting::record(new_size);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f` and returns its result together with the allocations it made. Without the
+/// `alloc-stats` feature, the allocations are always zero.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (count, bytes, live) = (COUNT.load(Relaxed), BYTES.load(Relaxed), LIVE.load(Relaxed));
+        PEAK.store(live, Relaxed);
+        let result = f();
+        let stats = AllocStats {
+            count: COUNT.load(Relaxed) - count,
+            bytes: BYTES.load(Relaxed) - bytes,
+            peak: PEAK.load(Relaxed).saturating_sub(live),
+        };
+        (result, stats)
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), AllocStats::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_with_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+
+    #[test]
+    fn total_of_phases() {
+        let stats = |count, bytes, peak| AllocStats { count, bytes, peak };
+        let allocs = PhaseAllocs {
+            parse: stats(2, 100, 80),
+            part1: stats(1, 50, 50),
+            part2: stats(3, 300, 120),
+        };
+        assert_eq!(allocs.total(), stats(6, 450, 120));
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counts_allocations() {
+        let (v, stats) = measure(|| {
+            let v = vec![0_u8; 4096];
+            let w = vec![0_u8; 1024];
+            drop(w);
+            v
+        });
+        assert_eq!(v.len(), 4096);
+        // Other test threads may allocate at the same time, so only lower bounds are exact.
+        assert!(stats.count >= 2);
+        assert!(stats.bytes >= 5120);
+        assert!(stats.peak >= 5120);
+    }
+}
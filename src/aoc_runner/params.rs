@@ -0,0 +1,185 @@
+//! # Solver parameters
+//!
+//! Some puzzles have constants which differ between the examples and the actual input, e.g. the
+//! size of a grid or the number of steps to simulate. A day declares these as named parameters
+//! with default values in `Solution::PARAMS`, and reads the values when parsing the input. The
+//! defaults can then be overridden on the command line with `--param name=value`, to run the
+//! examples or what-if variants without editing the code.
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+/// A named parameter of a solver, with its default value for the actual puzzle input.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: &'static str) -> Self {
+        Self { name, default }
+    }
+}
+
+/// A missing parameter, or a value which can't be read as the type of the parameter or is out of
+/// its range.
+#[derive(Debug, PartialEq)]
+pub struct ParamError(String);
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ParamError {}
+
+/// Parameter values by name, either overrides from the command line or the resolved values of a
+/// solver's parameters.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+
+impl Params {
+    /// Returns the default values of the `declared` parameters.
+    pub fn defaults(declared: &[Param]) -> Self {
+        Self::resolve(declared, &Params::default())
+    }
+
+    /// Returns the values of the `declared` parameters, taken from `overrides` where given and
+    /// otherwise the defaults. Overrides of other parameters are left out.
+    pub fn resolve(declared: &[Param], overrides: &Params) -> Self {
+        Self {
+            values: declared
+                .iter()
+                .map(|p| {
+                    let value = overrides.value(p.name).unwrap_or(p.default);
+                    (p.name.to_string(), value.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    /// Sets the value of `name`, replacing any earlier value.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.retain(|(n, _)| n != name);
+        self.values.push((name.to_string(), value.to_string()));
+    }
+
+    /// Sets all values of `other`, replacing any earlier values of the same names.
+    pub fn extend(&mut self, other: Params) {
+        for (name, value) in other.values {
+            self.set(&name, &value);
+        }
+    }
+
+    /// Returns true if no values are set.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over the names of the set values.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.iter().map(|(name, _)| name.as_str())
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of `name` parsed as `T`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParamError> {
+        let value = self
+            .value(name)
+            .ok_or_else(|| ParamError(format!("Missing parameter `{name}`")))?;
+        value
+            .parse()
+            .map_err(|_| ParamError(format!("Invalid value `{value}` for parameter `{name}`")))
+    }
+
+    /// Returns the value of `name` parsed as `T`, which must be within `range`.
+    pub fn get_in<T, R>(&self, name: &str, range: R) -> Result<T, ParamError>
+    where
+        T: FromStr + PartialOrd + Display,
+        R: RangeBounds<T>,
+    {
+        let value = self.get(name)?;
+        if range.contains(&value) {
+            return Ok(value);
+        }
+        let expected = match (range.start_bound(), range.end_bound()) {
+            (Bound::Included(low), Bound::Unbounded) => format!("at least {low}"),
+            (Bound::Unbounded, Bound::Included(high)) => format!("at most {high}"),
+            (Bound::Included(low), Bound::Included(high)) => format!("{low} to {high}"),
+            (Bound::Included(low), Bound::Excluded(high)) => format!("{low} to below {high}"),
+            _ => "a value in range".to_string(),
+        };
+        Err(ParamError(format!(
+            "Invalid value `{value}` for parameter `{name}`, expected {expected}"
+        )))
+    }
+}
+
+impl FromStr for Params {
+    type Err = &'static str;
+
+    /// Parses one or more comma separated values on the form `name=value`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in s.split(',') {
+            match pair.split_once('=') {
+                Some((name, value)) if !name.is_empty() => params.set(name, value),
+                _ => return Err("Invalid parameter, expected name=value."),
+            }
+        }
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[Param::new("dim", "71"), Param::new("bytes", "1024")];
+
+    #[test]
+    fn resolve_values() {
+        let defaults = Params::defaults(DECLARED);
+        assert_eq!(defaults.get::<usize>("dim").unwrap(), 71);
+        assert_eq!(defaults.get::<usize>("bytes").unwrap(), 1024);
+
+        let mut overrides: Params = "dim=7".parse().unwrap();
+        overrides.extend("bytes=12,other=1".parse().unwrap());
+        let params = Params::resolve(DECLARED, &overrides);
+        assert_eq!(params.get::<usize>("dim").unwrap(), 7);
+        assert_eq!(params.get::<usize>("bytes").unwrap(), 12);
+        assert_eq!(params.names().collect::<Vec<_>>(), ["dim", "bytes"]);
+    }
+
+    #[test]
+    fn errors() {
+        let params: Params = "dim=x".parse().unwrap();
+        assert_eq!(
+            params.get::<usize>("dim").unwrap_err().to_string(),
+            "Invalid value `x` for parameter `dim`"
+        );
+        assert!(params.get::<usize>("bytes").is_err());
+        let params: Params = "dim=0".parse().unwrap();
+        assert_eq!(
+            params
+                .get_in::<usize, _>("dim", 1..)
+                .unwrap_err()
+                .to_string(),
+            "Invalid value `0` for parameter `dim`, expected at least 1"
+        );
+        assert_eq!(params.get_in::<usize, _>("dim", ..=5), Ok(0));
+        assert!("dim".parse::<Params>().is_err());
+        assert!("=7".parse::<Params>().is_err());
+    }
+}
@@ -0,0 +1,240 @@
+//! # Day scaffolding
+//!
+//! Creates the file of a new day, `src/yearYYYY/dayDD.rs`, from the template in
+//! `src/.day_template.rs`, and declares and registers it in the `mod.rs` of the year. A new year
+//! also gets its `mod.rs`, and is added to the modules and the `YEARS` table in `lib.rs`. Existing
+//! day files are never overwritten.
+//!
+//! The files are edited in the source tree the program was built from, and all edits are prepared
+//! before anything is written, so a failure leaves the tree untouched.
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("../.day_template.rs");
+
+/// Returns the module name of `day`, e.g. `day07`.
+fn module_name(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// Returns the source of a new day from the template, with the header filled in.
+fn day_source(year: u16, day: u8, title: &str) -> String {
+    let (_, body) = TEMPLATE.split_once('\n').unwrap_or_default();
+    format!("//! # {year} day {day} - {title}\n{body}")
+}
+
+/// Returns the `mod.rs` of a year with no registered days.
+fn year_source(year: u16) -> String {
+    format!("use crate::aoc_runner::solver::register_days;\n\nregister_days! {{ {year}:\n}}\n")
+}
+
+/// Adds the `pub mod` declaration of `day` to `lines`, keeping the days in order. The first
+/// declaration of a year goes at the top, followed by an empty line.
+fn declare_module(lines: &mut Vec<String>, day: u8) {
+    let module = module_name(day);
+    let declared: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, l.strip_prefix("pub mod ")?.strip_suffix(';')?)))
+        .collect();
+    let at = match declared.iter().find(|(_, m)| **m > *module) {
+        Some((i, _)) => *i,
+        None => declared.last().map_or(0, |(i, _)| i + 1),
+    };
+    if declared.is_empty() {
+        lines.insert(0, String::new());
+    }
+    lines.insert(at, format!("pub mod {module};"));
+}
+
+/// Adds `day` with `title` to the `register_days!` list in `mod_rs`, keeping the days in order,
+/// and declares its module.
+fn register_day(mod_rs: &str, day: u8, title: &str) -> Result<String, Box<dyn Error>> {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    let entry = format!("    {day} => {}: \"{title}\",", module_name(day));
+    let mut lines: Vec<_> = mod_rs.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("register_days!"))
+        .ok_or("No register_days! list in mod.rs")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "}")
+            .ok_or("Unterminated register_days! list in mod.rs")?;
+    let mut at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let n = line
+            .trim()
+            .split_once(" =>")
+            .and_then(|(n, _)| n.parse::<u8>().ok());
+        match n {
+            Some(n) if n == day => return Err(format!("Day {day} is already registered").into()),
+            Some(n) if n > day => {
+                at = i;
+                break;
+            }
+            _ => (),
+        }
+    }
+    lines.insert(at, entry);
+    declare_module(&mut lines, day);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` into `lines` among the consecutive lines holding a year number, as extracted by
+/// `year_of`, keeping the years in order.
+fn insert_year_line(
+    lines: &mut Vec<String>,
+    line: String,
+    year: u16,
+    year_of: impl Fn(&str) -> Option<u16>,
+) -> Result<(), Box<dyn Error>> {
+    let years: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, year_of(l)?)))
+        .collect();
+    let last = years.last().ok_or("No years found in lib.rs")?.0;
+    if years.iter().any(|(_, y)| *y == year) {
+        return Err(format!("Year {year} is already registered").into());
+    }
+    let at = years
+        .iter()
+        .find(|(_, y)| *y > year)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(at, line);
+    Ok(())
+}
+
+/// Adds the module of `year` and its solvers to `lib_rs`.
+fn register_year(lib_rs: &str, year: u16) -> Result<String, Box<dyn Error>> {
+    let mut lines: Vec<_> = lib_rs.lines().map(String::from).collect();
+    insert_year_line(&mut lines, format!("pub mod year{year};"), year, |l| {
+        l.strip_prefix("pub mod year")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })?;
+    insert_year_line(&mut lines, format!("    year{year}::SOLVERS,"), year, |l| {
+        l.strip_prefix("    year")?
+            .strip_suffix("::SOLVERS,")?
+            .parse()
+            .ok()
+    })?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates day `day` of `year` with `title` in the source tree at `src`, registers it, and
+/// returns the paths of the created and changed files.
+pub fn new_day(src: &Path, year: u16, day: u8, title: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err("Invalid day, expected 1 to 25".into());
+    }
+    let year_dir = src.join(format!("year{year}"));
+    let day_path = year_dir.join(format!("{}.rs", module_name(day)));
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()).into());
+    }
+    let mod_path = year_dir.join("mod.rs");
+    let lib_path = src.join("lib.rs");
+
+    let new_year = !mod_path.exists();
+    let mod_rs = if new_year {
+        year_source(year)
+    } else {
+        fs::read_to_string(&mod_path)?
+    };
+    let mod_rs = register_day(&mod_rs, day, title)?;
+    let lib_rs = if new_year {
+        Some(register_year(&fs::read_to_string(&lib_path)?, year)?)
+    } else {
+        None
+    };
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&day_path, day_source(year, day, title))?;
+    fs::write(&mod_path, mod_rs)?;
+    let mut changed = vec![
+        day_path.display().to_string(),
+        mod_path.display().to_string(),
+    ];
+    if let Some(lib_rs) = lib_rs {
+        fs::write(&lib_path, lib_rs)?;
+        changed.push(lib_path.display().to_string());
+    }
+    Ok(changed)
+}
+
+/// Creates day `day` of `year` with `title` in the source tree of this crate, and lists the
+/// created and changed files.
+pub fn run(year: u16, day: u8, title: &str) -> Result<(), Box<dyn Error>> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for path in new_day(&src, year, day, title)? {
+        println!("{path}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_from_template() {
+        let source = day_source(2026, 3, "Some Title");
+        assert!(source.starts_with("//! # 2026 day 3 - Some Title\nuse crate::"));
+        assert_eq!(source.lines().count(), TEMPLATE.lines().count());
+    }
+
+    #[test]
+    fn register_days_in_order() {
+        let mod_rs = register_day(&year_source(2026), 5, "Five").unwrap();
+        let mod_rs = register_day(&mod_rs, 12, "Twelve").unwrap();
+        let mod_rs = register_day(&mod_rs, 1, "Say \"One\"").unwrap();
+        assert_eq!(
+            mod_rs,
+            "pub mod day01;
+pub mod day05;
+pub mod day12;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2026:
+    1 => day01: \"Say \\\"One\\\"\",
+    5 => day05: \"Five\",
+    12 => day12: \"Twelve\",
+}
+"
+        );
+        assert!(register_day(&mod_rs, 5, "Again").is_err());
+    }
+
+    #[test]
+    fn register_years_in_order() {
+        let lib_rs = "pub mod year2015;
+pub mod year2017;
+
+const YEARS: &[&[Solver]] = &[
+    year2015::SOLVERS,
+    year2017::SOLVERS,
+];
+";
+        assert_eq!(
+            register_year(lib_rs, 2016).unwrap(),
+            "pub mod year2015;
+pub mod year2016;
+pub mod year2017;
+
+const YEARS: &[&[Solver]] = &[
+    year2015::SOLVERS,
+    year2016::SOLVERS,
+    year2017::SOLVERS,
+];
+"
+        );
+        assert!(register_year(&register_year(lib_rs, 2018).unwrap(), 2018).is_err());
+        assert!(register_year(lib_rs, 2015).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! # Input shapes
+//!
+//! Each day can declare the rough layout of its input in `Solution::SHAPE`, e.g. lines matching
+//! some simple patterns, a grid of certain characters, or sections separated by blank lines. The
+//! runner checks the input against it before solving, so that the input of another day is reported
+//! as such rather than failing somewhere in the parser, and `identify FILE` lists the puzzles whose
+//! shape an input file matches.
+//!
+//! Line patterns are matched literally, except for these placeholders:
+//!
+//! * `{int}` - an integer, optionally negative
+//! * `{ints}` - one or more integers separated by spaces or commas
+//! * `{word}` - one or more ASCII letters or digits
+//! * `{any}` - any text, possibly empty
+//! * `[chars]` - one or more of the characters between the brackets
+//!
+//! A space in a pattern matches one or more spaces, since inputs often align their columns.
+use crate::aoc_runner::solver::{self, Solver};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// The layout of a puzzle input.
+#[derive(Clone, Copy, Debug)]
+pub enum Shape {
+    /// Any input, i.e. no check. The default for days which don't declare a shape.
+    Any,
+    /// One or more lines, each matching one of the patterns.
+    Lines(&'static [&'static str]),
+    /// A rectangular grid of the given characters.
+    Grid(&'static str),
+    /// Sections separated by blank lines, matching the given shapes in order. A `Blocks` shape
+    /// matches all the remaining sections.
+    Sections(&'static [Shape]),
+    /// One or more sections separated by blank lines, all of the given shape.
+    Blocks(&'static Shape),
+}
+
+/// The placeholders of line patterns.
+#[derive(Clone, Copy)]
+enum Token {
+    Int,
+    Ints,
+    Word,
+    Any,
+}
+
+impl Token {
+    const ALL: [(&'static str, Token); 4] = [
+        ("{int}", Token::Int),
+        ("{ints}", Token::Ints),
+        ("{word}", Token::Word),
+        ("{any}", Token::Any),
+    ];
+
+    /// Returns the lengths of the prefixes of `text` this token can match, longest first.
+    fn prefixes(self, text: &str) -> Vec<usize> {
+        let int_len = |s: &str| {
+            let sign = usize::from(s.starts_with('-'));
+            let digits = s[sign..].bytes().take_while(u8::is_ascii_digit).count();
+            (digits > 0).then_some(sign + digits)
+        };
+        match self {
+            Token::Int => int_len(text).into_iter().collect(),
+            Token::Ints => {
+                let mut ends = Vec::new();
+                let mut at = 0;
+                while let Some(len) = int_len(&text[at..]) {
+                    at += len;
+                    ends.push(at);
+                    at += text[at..]
+                        .bytes()
+                        .take_while(|b| *b == b' ' || *b == b',')
+                        .count();
+                }
+                ends.reverse();
+                ends
+            }
+            Token::Word => {
+                let len = text.bytes().take_while(u8::is_ascii_alphanumeric).count();
+                (1..=len).rev().collect()
+            }
+            Token::Any => text
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([text.len()])
+                .rev()
+                .collect(),
+        }
+    }
+}
+
+/// Returns true if all of `text` matches `pattern`.
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    if let Some((placeholder, token)) = Token::ALL.iter().find(|(p, _)| pattern.starts_with(p)) {
+        let rest = &pattern[placeholder.len()..];
+        return token
+            .prefixes(text)
+            .into_iter()
+            .any(|len| matches_pattern(rest, &text[len..]));
+    }
+    if let Some((class, rest)) = pattern
+        .strip_prefix('[')
+        .and_then(|class| class.split_once(']'))
+    {
+        let len: usize = text
+            .chars()
+            .take_while(|c| class.contains(*c))
+            .map(char::len_utf8)
+            .sum();
+        return (1..=len)
+            .rev()
+            .filter(|len| text.is_char_boundary(*len))
+            .any(|len| matches_pattern(rest, &text[len..]));
+    }
+    match (pattern.chars().next(), text.chars().next()) {
+        (None, None) => true,
+        (Some(' '), Some(' ')) => {
+            let spaces = text.bytes().take_while(|b| *b == b' ').count();
+            matches_pattern(&pattern[1..], &text[spaces..])
+        }
+        (Some(p), Some(t)) if p == t => {
+            matches_pattern(&pattern[p.len_utf8()..], &text[t.len_utf8()..])
+        }
+        _ => false,
+    }
+}
+
+/// Splits `lines` into the sections between blank lines.
+fn sections<'a>(lines: &'a [&'a str]) -> impl Iterator<Item = &'a [&'a str]> {
+    lines.split(|line| line.is_empty())
+}
+
+impl Shape {
+    /// Returns true if `input` has this shape. Trailing blank lines are ignored.
+    pub fn matches(&self, input: &str) -> bool {
+        let mut lines: Vec<_> = input.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        self.matches_lines(&lines)
+    }
+
+    fn matches_lines(&self, lines: &[&str]) -> bool {
+        match self {
+            Shape::Any => true,
+            Shape::Lines(patterns) => {
+                !lines.is_empty()
+                    && lines
+                        .iter()
+                        .all(|line| patterns.iter().any(|p| matches_pattern(p, line)))
+            }
+            Shape::Grid(chars) => lines.first().is_some_and(|first| {
+                let width = first.chars().count();
+                width > 0
+                    && lines.iter().all(|line| {
+                        line.chars().count() == width && line.chars().all(|c| chars.contains(c))
+                    })
+            }),
+            Shape::Sections(shapes) => {
+                let mut sections = sections(lines);
+                for shape in *shapes {
+                    if let Shape::Blocks(block) = shape {
+                        let rest: Vec<_> = sections.by_ref().collect();
+                        if rest.is_empty() || !rest.iter().all(|s| block.matches_lines(s)) {
+                            return false;
+                        }
+                    } else if !sections.next().is_some_and(|s| shape.matches_lines(s)) {
+                        return false;
+                    }
+                }
+                sections.next().is_none()
+            }
+            Shape::Blocks(block) => sections(lines).all(|s| block.matches_lines(s)),
+        }
+    }
+}
+
+/// Returns the puzzles which declare a shape that `input` matches, in year and day order.
+pub fn identify(input: &str) -> Vec<&'static Solver> {
+    solver::all()
+        .filter(|s| !matches!(s.shape, Shape::Any) && s.shape.matches(input))
+        .collect()
+}
+
+/// Names the puzzles in `solvers`, e.g. `2023 day 5 or 2023 day 6`.
+fn names(solvers: &[&Solver]) -> String {
+    let names: Vec<_> = solvers
+        .iter()
+        .map(|s| format!("{} day {}", s.year, s.day))
+        .collect();
+    names.join(" or ")
+}
+
+/// Checks that `input` has the shape declared by `solver`. If not, the error tells which puzzles'
+/// input it looks like instead, if any.
+pub fn check(solver: &Solver, input: &str) -> Result<(), String> {
+    if solver.shape.matches(input) {
+        return Ok(());
+    }
+    let message = "Input doesn't match the expected format".to_string();
+    match identify(input) {
+        others if others.is_empty() => Err(message),
+        others => Err(format!(
+            "{message}, this looks like {} input",
+            names(&others)
+        )),
+    }
+}
+
+/// Reads the file at `path` and lists the puzzles whose input it looks like.
+pub fn run(path: &Path) -> Result<(), Box<dyn Error>> {
+    let input =
+        fs::read_to_string(path).map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let solvers = identify(&input);
+    if solvers.is_empty() {
+        return Err(format!(
+            "{} doesn't look like any known puzzle input",
+            path.display()
+        )
+        .into());
+    }
+    for s in solvers {
+        println!("{} day {}: {}", s.year, s.day, s.title);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        assert!(matches_pattern("Game {int}: {any}", "Game 12: 3 blue"));
+        assert!(matches_pattern(
+            "Card {int}: {ints} | {ints}",
+            "Card   1: 41 48 | 83 86 6"
+        ));
+        assert!(matches_pattern(
+            "{word} = ({word}, {word})",
+            "AAA = (BBB, CCC)"
+        ));
+        assert!(matches_pattern("{any} {ints}", "???.### 1,1,3"));
+        assert!(matches_pattern("{ints}", "-4 0 12"));
+        assert!(matches_pattern("{word}{{any}}", "px{a<2006:qkq,rfg}"));
+        assert!(matches_pattern("[.#?] {ints}", ".??..??...?##. 1,1,3"));
+        assert!(!matches_pattern("[.#?] {ints}", "0 3 6 9"));
+        assert!(!matches_pattern("{int}", "12a"));
+        assert!(!matches_pattern("{int}", "-"));
+        assert!(!matches_pattern("{ints}", "1 2 "));
+        assert!(!matches_pattern("{word}", ""));
+        assert!(!matches_pattern("Game {int}:", "Games 1:"));
+    }
+
+    #[test]
+    fn shapes() {
+        const GRID: Shape = Shape::Grid(".#");
+        assert!(GRID.matches(".#.\n##.\n...\n\n"));
+        assert!(!GRID.matches(".#.\n##\n"));
+        assert!(!GRID.matches(".#.\n#O.\n"));
+        assert!(!GRID.matches(""));
+
+        let lines = Shape::Lines(&["{int}", "x {int}"]);
+        assert!(lines.matches("1\nx 2\n3\n"));
+        assert!(!lines.matches("1\n\n3\n"));
+        assert!(!lines.matches(""));
+
+        let sections = Shape::Sections(&[Shape::Lines(&["{word}"]), Shape::Blocks(&GRID)]);
+        assert!(sections.matches("abc\n\n.#\n#.\n\n#\n"));
+        assert!(!sections.matches("abc\n"));
+        assert!(!sections.matches("abc\n\n.#\n\nabc\n"));
+        let sections = Shape::Sections(&[Shape::Lines(&["{word}"]), GRID]);
+        assert!(sections.matches("abc\n\n.#\n"));
+        assert!(!sections.matches("abc\n\n.#\n\n#.\n"));
+
+        assert!(Shape::Blocks(&GRID).matches(".#\n\n#..\n"));
+        assert!(Shape::Any.matches(""));
+    }
+
+    #[test]
+    fn wrong_input() {
+        let solver = |year, day| solver::find(year, day).unwrap();
+        let races = "Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(check(solver(2023, 6), races), Ok(()));
+        assert_eq!(
+            check(solver(2023, 5), races),
+            Err(
+                "Input doesn't match the expected format, this looks like 2023 day 6 input"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            check(solver(2023, 6), "no such input!"),
+            Err("Input doesn't match the expected format".to_string())
+        );
+        assert_eq!(
+            identify(races)
+                .iter()
+                .map(|s| (s.year, s.day))
+                .collect::<Vec<_>>(),
+            [(2023, 6)]
+        );
+    }
+
+    #[test]
+    fn examples_match_shapes() {
+        for solver in solver::all() {
+            for (i, example) in solver.examples.iter().enumerate() {
+                assert!(
+                    solver.shape.matches(example.input),
+                    "{} day {} example {}",
+                    solver.year,
+                    solver.day,
+                    i + 1
+                );
+            }
+        }
+    }
+}
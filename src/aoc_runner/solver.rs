@@ -0,0 +1,352 @@
+//! # Solver registry
+//!
+//! Every implemented puzzle is described by a `Solver` entry, registered in the `mod.rs` of its
+//! year with the `register_days!` macro, next to the plain `mod` declaration of the day. The
+//! entries of all years can then be looked up or iterated through the functions in this module,
+//! e.g. for listing or batch-running puzzles.
+//!
+//! Each day implements the `Solution` trait on its `InputData`, splitting the solving into three
+//! phases - parsing the input, and solving part 1 and 2 - which are run and timed separately.
+//! Either part can also be run on its own, skipping the other one. Days with constants that differ
+//! between the examples and the actual input declare them as parameters, see `params`.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::memory::{self, AllocStats, PhaseAllocs};
+use crate::aoc_runner::params::{Param, ParamError, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_util::parse::ParseError;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// The interface implemented by each day. The lifetime allows the parsed data to borrow from the
+/// input string.
+pub trait Solution<'a>: Sized {
+    /// The named parameters of the solver, with their defaults. Most days have none.
+    const PARAMS: &'static [Param] = &[];
+
+    /// The examples of the puzzle description, with their expected answers.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// The layout of the puzzle input, checked before solving to catch the input of another day.
+    const SHAPE: Shape = Shape::Any;
+
+    /// Parses the puzzle input.
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>>;
+
+    /// Parses the puzzle input using the values of `PARAMS` in `params`. Days declaring parameters
+    /// implement this, and `parse` as calling it with the default values.
+    fn parse_with(input: &'a str, _params: &Params) -> Result<Self, Box<dyn Error>> {
+        Self::parse(input)
+    }
+
+    /// Solves part 1, independently of part 2.
+    fn part1(&self) -> impl Into<Answer>;
+
+    /// Solves part 2, independently of part 1.
+    fn part2(&self) -> impl Into<Answer>;
+}
+
+/// Which parts of a puzzle to solve, `--part 1|2`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    /// Returns true if part 1 is to be solved.
+    pub fn part1(self) -> bool {
+        self != Parts::Part2
+    }
+
+    /// Returns true if part 2 is to be solved.
+    pub fn part2(self) -> bool {
+        self != Parts::Part1
+    }
+}
+
+impl FromStr for Parts {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Parts::Part1),
+            "2" => Ok(Parts::Part2),
+            _ => Err("Invalid part, expected 1 or 2."),
+        }
+    }
+}
+
+/// The time spent in each phase of a solver run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl PhaseTimes {
+    /// Returns the combined time of all phases.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// The answers and phase timings of one solver run. The answer of a part which wasn't run is
+/// `None`, and its time zero. The allocations of each phase are only recorded with the
+/// `alloc-stats` feature.
+#[derive(Debug)]
+pub struct Outcome {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub times: PhaseTimes,
+    pub allocs: Option<PhaseAllocs>,
+}
+
+/// Runs `f` and returns its result together with the time it took and the allocations it made.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration, AllocStats) {
+    let ((result, time), allocs) = memory::measure(|| {
+        let timer = Instant::now();
+        let result = f();
+        (result, timer.elapsed())
+    });
+    (result, time, allocs)
+}
+
+/// Parses `input` into the solution type `S` and solves the selected `parts`, timing each phase.
+/// The parameters of `S` get the values in `overrides`, or else their defaults. Parse errors are
+/// located by line and column in `input`.
+pub fn run<'a, S: Solution<'a>>(
+    input: &'a str,
+    parts: Parts,
+    overrides: &Params,
+) -> Result<Outcome, Box<dyn Error>> {
+    let params = Params::resolve(S::PARAMS, overrides);
+    let (solution, parse, parse_allocs) = timed(|| S::parse_with(input, &params));
+    let solution = solution.map_err(|e| match e.downcast::<ParseError>() {
+        Ok(e) => e.locate(input).into(),
+        Err(e) => e,
+    })?;
+    let (part1, part1_time, part1_allocs) = if parts.part1() {
+        timed(|| Some(solution.part1().into()))
+    } else {
+        (None, Duration::ZERO, AllocStats::default())
+    };
+    let (part2, part2_time, part2_allocs) = if parts.part2() {
+        timed(|| Some(solution.part2().into()))
+    } else {
+        (None, Duration::ZERO, AllocStats::default())
+    };
+
+    Ok(Outcome {
+        part1,
+        part2,
+        times: PhaseTimes {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+        allocs: memory::ENABLED.then_some(PhaseAllocs {
+            parse: parse_allocs,
+            part1: part1_allocs,
+            part2: part2_allocs,
+        }),
+    })
+}
+
+/// The type-erased `run` function of a day.
+pub type RunFn = fn(&str, Parts, &Params) -> Result<Outcome, Box<dyn Error>>;
+
+/// Describes one implemented puzzle.
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    pub examples: &'static [Example],
+    pub shape: Shape,
+    pub run: RunFn,
+}
+
+/// Generates the `SOLVERS` table of a year with one entry per day. Each day is registered with one
+/// line containing the day number, the module name and the puzzle title, e.g. as below. The day
+/// module must be declared separately, so it stays visible to rustfmt, and provide a public
+/// `InputData` type implementing `Solution`.
+///
+/// ```ignore
+/// pub mod day01;
+///
+/// register_days! { 2015:
+///     1 => day01: "Not Quite Lisp",
+/// }
+/// ```
+macro_rules! register_days {
+    ($year:literal: $($day:literal => $module:ident: $title:literal,)*) => {
+        /// All implemented days of the year, in order.
+        pub const SOLVERS: &[$crate::aoc_runner::solver::Solver] = &[
+            $($crate::aoc_runner::solver::Solver {
+                year: $year,
+                day: $day,
+                title: $title,
+                params: <$module::InputData as $crate::aoc_runner::solver::Solution>::PARAMS,
+                examples: <$module::InputData as $crate::aoc_runner::solver::Solution>::EXAMPLES,
+                shape: <$module::InputData as $crate::aoc_runner::solver::Solution>::SHAPE,
+                run: |input, parts, params| {
+                    $crate::aoc_runner::solver::run::<$module::InputData>(input, parts, params)
+                },
+            },)*
+        ];
+    };
+}
+pub(crate) use register_days;
+
+/// Returns an iterator over all registered solvers, ordered by year and day.
+pub fn all() -> impl Iterator<Item = &'static Solver> {
+    crate::YEARS.iter().flat_map(|year| year.iter())
+}
+
+/// Returns the solver for the given `year` and `day`, or `None` if the day is not implemented.
+pub fn find(year: u16, day: u8) -> Option<&'static Solver> {
+    all().find(|s| s.year == year && s.day == day)
+}
+
+/// Returns true if at least one day is implemented for the given `year`.
+pub fn has_year(year: u16) -> bool {
+    all().any(|s| s.year == year)
+}
+
+/// Why a puzzle couldn't be solved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolveError {
+    /// No day of the year is implemented.
+    YearNotImplemented(u16),
+    /// The year is implemented, but not the day.
+    DayNotImplemented(u16, u8),
+    /// A parameter the puzzle doesn't have, or an invalid parameter value.
+    Param(String),
+    /// The input couldn't be parsed. Malformed lines are located by line and column.
+    Parse(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::YearNotImplemented(year) => write!(f, "Year {year} not implemented"),
+            SolveError::DayNotImplemented(year, day) => {
+                write!(f, "{year} day {day} not implemented")
+            }
+            SolveError::Param(e) | SolveError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Returns the solver for the given `year` and `day`, or an error telling whether the year or
+/// only the day isn't implemented.
+pub fn lookup(year: u16, day: u8) -> Result<&'static Solver, SolveError> {
+    match find(year, day) {
+        Some(solver) => Ok(solver),
+        None if has_year(year) => Err(SolveError::DayNotImplemented(year, day)),
+        None => Err(SolveError::YearNotImplemented(year)),
+    }
+}
+
+/// Solves the selected `parts` of `solver` for `input`. Its parameters get the values in
+/// `overrides`, or else their defaults, and overrides of other parameters are ignored.
+pub fn solve_with(
+    solver: &Solver,
+    input: &str,
+    parts: Parts,
+    overrides: &Params,
+) -> Result<Outcome, SolveError> {
+    (solver.run)(input, parts, overrides).map_err(|e| match e.downcast::<ParamError>() {
+        Ok(e) => SolveError::Param(e.to_string()),
+        Err(e) => SolveError::Parse(e.to_string()),
+    })
+}
+
+/// Solves both parts of the puzzle of `year` and `day` for `input`, without any I/O, and returns
+/// the answers and phase timings. The puzzle's parameters get the values in `params`, or else
+/// their defaults, so `Params::default()` solves the actual puzzle input.
+pub fn solve(year: u16, day: u8, input: &str, params: &Params) -> Result<Outcome, SolveError> {
+    let solver = lookup(year, day)?;
+    if let Some(name) = params
+        .names()
+        .find(|name| !solver.params.iter().any(|p| p.name == *name))
+    {
+        return Err(SolveError::Param(format!(
+            "Unknown parameter `{name}` for {year} day {day}"
+        )));
+    }
+    solve_with(solver, input, Parts::Both, params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        let keys: Vec<_> = all().map(|s| (s.year, s.day)).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn find_solver() {
+        let solver = find(2015, 1).unwrap();
+        assert_eq!(solver.title, "Not Quite Lisp");
+        assert!(find(2015, 26).is_none());
+        assert!(has_year(2015));
+        assert!(!has_year(2014));
+    }
+
+    #[test]
+    fn run_single_part() {
+        let solver = find(2015, 1).unwrap();
+        let outcome = (solver.run)("(()(", Parts::Part1, &Params::default()).unwrap();
+        assert_eq!(outcome.part1, Some(Answer::Int(2)));
+        assert_eq!(outcome.part2, None);
+        assert_eq!(outcome.times.part2, Duration::ZERO);
+        let outcome = (solver.run)("())", Parts::Part2, &Params::default()).unwrap();
+        assert_eq!(outcome.part1, None);
+        assert_eq!(outcome.part2, Some(Answer::Int(3)));
+        assert_eq!("2".parse(), Ok(Parts::Part2));
+        assert!("3".parse::<Parts>().is_err());
+    }
+
+    #[test]
+    fn solve_values() {
+        let outcome = solve(2015, 1, "())", &Params::default()).unwrap();
+        assert_eq!(outcome.part1, Some(Answer::Int(-1)));
+        assert_eq!(outcome.part2, Some(Answer::Int(3)));
+    }
+
+    #[test]
+    fn solve_errors() {
+        let params = Params::default();
+        assert_eq!(
+            solve(2014, 1, "", &params).unwrap_err(),
+            SolveError::YearNotImplemented(2014)
+        );
+        assert_eq!(
+            solve(2015, 26, "", &params).unwrap_err(),
+            SolveError::DayNotImplemented(2015, 26)
+        );
+        let SolveError::Parse(e) = solve(2024, 18, "1,2\n3;4", &params).unwrap_err() else {
+            panic!("expected a parse error");
+        };
+        assert!(e.starts_with("line 2, column 1:"), "{e}");
+        assert_eq!(
+            solve(2024, 18, "1,2", &"size=7".parse().unwrap()).unwrap_err(),
+            SolveError::Param("Unknown parameter `size` for 2024 day 18".to_string())
+        );
+        assert_eq!(
+            solve(2024, 18, "1,2", &"dim=x".parse().unwrap()).unwrap_err(),
+            SolveError::Param("Invalid value `x` for parameter `dim`".to_string())
+        );
+    }
+}
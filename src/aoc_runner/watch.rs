@@ -0,0 +1,157 @@
+//! # Watch mode
+//!
+//! Solves a single puzzle again whenever its input file changes, `--watch`, to get quick feedback
+//! while working on a solution, e.g. on an example input given with `--input`. Changes are found
+//! by polling the modification time and size of the file, which works on any platform without
+//! extra services.
+//!
+//! Each run prints the answers, marked as unchanged or with the answer of the previous run, and
+//! the timing together with that of the previous run. A failing run doesn't stop the watching.
+//!
+//! Only the puzzle input is watched. The examples are compiled into the solvers, so changing them
+//! needs a rebuild anyway, and `--watch` can't be combined with `--example`.
+use crate::Config;
+use crate::aoc_runner::batch::{self, PuzzleResult};
+use crate::aoc_runner::solver::Solver;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the input file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Returns the modification time and size of the file at `path`, or `None` if it can't be read.
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Describes the `current` result of a puzzle compared to the `previous` one, one line at a time.
+fn report(previous: Option<&PuzzleResult>, current: &PuzzleResult) -> Vec<String> {
+    let (p1, p2) = match &current.answers {
+        Ok(answers) => answers,
+        Err(e) => return vec![e.to_string()],
+    };
+    let previous = previous.and_then(|r| Some((r.answers.as_ref().ok()?, r.times.total())));
+    let mut lines = Vec::new();
+    for (part, answer, before) in [
+        (1, p1, previous.and_then(|((p1, _), _)| p1.as_ref())),
+        (2, p2, previous.and_then(|((_, p2), _)| p2.as_ref())),
+    ] {
+        if let Some(answer) = answer {
+            lines.push(match before {
+                Some(before) if before == answer => format!("Part {part}: {answer} (unchanged)"),
+                Some(before) => format!("Part {part}: {answer} (was {before})"),
+                None => format!("Part {part}: {answer}"),
+            });
+        }
+    }
+    let time = current.times.total().as_micros();
+    lines.push(match previous {
+        Some((_, before)) => format!("Completed in: {time} μs (was {} μs)", before.as_micros()),
+        None => format!("Completed in: {time} μs"),
+    });
+    lines
+}
+
+/// Solves `solver` with the settings in `config`, and again each time the input file changes.
+/// Only returns if the input file can't be located.
+pub fn run(solver: &'static Solver, config: &Config) -> Result<(), Box<dyn Error>> {
+    let path = config
+        .input
+        .path(solver.year, solver.day)?
+        .ok_or("--watch needs an input file, not stdin.")?;
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop.",
+        path.display()
+    );
+    let mut last_stamp = stamp(&path);
+    let mut previous = None;
+    loop {
+        let result = batch::run_puzzle(
+            solver,
+            &config.input,
+            config.parts,
+            &config.params,
+            config.timeout,
+        );
+        println!();
+        for line in report(previous.as_ref(), &result) {
+            println!("{line}");
+        }
+        previous = Some(result);
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = stamp(&path);
+            if current != last_stamp {
+                last_stamp = current;
+                break;
+            }
+        }
+        println!("\n{} changed, solving again.", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::answer::Answer;
+    use crate::aoc_runner::batch::Failure;
+    use crate::aoc_runner::solver::{self, PhaseTimes};
+
+    fn result(
+        answers: Result<(Option<Answer>, Option<Answer>), Failure>,
+        micros: u64,
+    ) -> PuzzleResult {
+        PuzzleResult {
+            solver: solver::find(2015, 1).unwrap(),
+            answers,
+            times: PhaseTimes {
+                parse: Duration::from_micros(micros),
+                ..Default::default()
+            },
+            allocs: None,
+            status: None,
+        }
+    }
+
+    #[test]
+    fn report_changes() {
+        let first = result(Ok((Some(Answer::Int(24)), Some(Answer::Int(7)))), 170);
+        assert_eq!(
+            report(None, &first),
+            ["Part 1: 24", "Part 2: 7", "Completed in: 170 μs"]
+        );
+        let second = result(Ok((Some(Answer::Int(22)), Some(Answer::Int(7)))), 163);
+        assert_eq!(
+            report(Some(&first), &second),
+            [
+                "Part 1: 22 (was 24)",
+                "Part 2: 7 (unchanged)",
+                "Completed in: 163 μs (was 170 μs)"
+            ]
+        );
+        let failed = result(Err(Failure::Panic("oops".to_string())), 0);
+        assert_eq!(report(Some(&second), &failed), ["PANIC: oops"]);
+        assert_eq!(
+            report(Some(&failed), &second),
+            ["Part 1: 22", "Part 2: 7", "Completed in: 163 μs"]
+        );
+    }
+
+    #[test]
+    fn file_stamp() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        assert_eq!(stamp(&path), None);
+        fs::write(&path, "1").unwrap();
+        let first = stamp(&path);
+        fs::write(&path, "12").unwrap();
+        let second = stamp(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(first.is_some());
+        assert_ne!(first, second);
+    }
+}
@@ -1,44 +1,79 @@
 //! # Grid utility
 //!
-//! Provides a struct for managing a 2D grid of elements of type char,
-//! stored internally in a one-dimensional vector.
+//! Provides a struct for managing a 2D grid of elements of type `T`, stored internally in a
+//! one-dimensional vector. The default element type is `u8`, holding the ASCII characters of the
+//! input, which takes a quarter of the memory of `char`. Grids of other types, e.g. numbers or
+//! booleans, are parsed with a closure mapping each character to an element.
 use core::fmt;
 
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
 
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Grid {
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T = u8> {
     pub x_max: usize,
     pub y_max: usize,
-    pub elements: Vec<char>,
+    pub elements: Vec<T>,
+}
+
+/// Returns the length of the lines of `input`, as measured by `len`. Returns an error if the input
+/// is empty or the lines differ in length.
+fn line_length(input: &str, len: impl Fn(&str) -> usize) -> Result<usize, ParseError> {
+    let mut lines = input.lines();
+    let x_max = lines
+        .next()
+        .map(&len)
+        .filter(|&x_max| x_max > 0)
+        .ok_or_else(|| ParseError::missing("missing grid", input))?;
+    if let Some(line) = lines.find(|line| len(line) != x_max) {
+        return Err(ParseError::new(
+            format!("expected a line of {x_max} characters"),
+            line,
+        ));
+    }
+    Ok(x_max)
 }
 
 impl Grid {
     #[inline]
-    /// Creates a new `Grid` instance based on a string input.
-    /// The function assumes that the input is correct, i.e. non-empty string and the number
-    /// of characters in each line is consistent.
-    pub fn parse(input: &str) -> Self {
-        let lines: Vec<_> = input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect();
-        let x_max = lines[0].len();
-        let y_max = lines.len();
-        let mut elements = Vec::with_capacity(x_max * y_max);
-        lines
-            .iter()
-            .for_each(|line| line.iter().for_each(|c| elements.push(*c)));
-        Self {
+    /// Creates a new `Grid` instance based on a string input, with one element per byte. Returns an
+    /// error if the input is empty or its lines differ in length.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let x_max = line_length(input, str::len)?;
+        let elements: Vec<_> = input.lines().flat_map(str::bytes).collect();
+        Ok(Self {
             x_max,
-            y_max,
+            y_max: elements.len() / x_max,
             elements,
+        })
+    }
+
+    /// Creates a new `Grid` instance holding the values of the digits in the string input. Returns
+    /// an error at the first character which isn't a digit.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        if let Some(i) = input.find(|c: char| !c.is_ascii_digit() && c != '\n' && c != '\r') {
+            return Err(ParseError::new("expected a digit", &input[i..]));
         }
+        Self::parse_with(input, |c| c as u8 - b'0')
+    }
+}
+
+impl<T: Copy + PartialEq> Grid<T> {
+    /// Creates a new `Grid` instance based on a string input, with each character mapped to an
+    /// element by `f`. Returns the same errors as `parse`.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let x_max = line_length(input, |line| line.chars().count())?;
+        let elements: Vec<_> = input.lines().flat_map(str::chars).map(&mut f).collect();
+        Ok(Self {
+            x_max,
+            y_max: elements.len() / x_max,
+            elements,
+        })
     }
 
     /// Creates a new `Grid` instance of the specified dimensions `x_max` : `y_max` and initiated to the
-    /// character in `val`.
-    pub fn new(x_max: usize, y_max: usize, val: char) -> Self {
+    /// value in `val`.
+    pub fn new(x_max: usize, y_max: usize, val: T) -> Self {
         Self {
             x_max,
             y_max,
@@ -46,74 +81,348 @@ impl Grid {
         }
     }
 
-    /// Returns the value of the element in point `p`. Returns `None` if the point doesn't exist in the grid.
-    #[inline]
-    pub fn get_element(&self, p: &Point) -> Option<char> {
-        if (0..self.x_max).contains(&(p.x as usize)) && (0..self.y_max).contains(&(p.y as usize)) {
-            Some(self.elements[self.x_max * (p.y as usize) + (p.x as usize)])
-        } else {
-            None
+    /// Returns a grid of the same dimensions, with each element mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            x_max: self.x_max,
+            y_max: self.y_max,
+            elements: self.elements.iter().copied().map(f).collect(),
         }
     }
 
-    /// Returns the value of the element in point `p`, translated to an u8 number. Returns `None` if the point
-    /// doesn't exist in the grid or if the element is not a digit.
+    /// Returns true if the point `p` is inside the grid.
     #[inline]
-    pub fn get_uint_element(&self, p: &Point) -> Option<u8> {
-        if (0..self.x_max).contains(&(p.x as usize)) && (0..self.y_max).contains(&(p.y as usize)) {
-            self.elements[self.x_max * (p.y as usize) + (p.x as usize)]
-                .to_digit(10)
-                .map(|n| n as u8)
-        } else {
-            None
-        }
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.x_max && (p.y as usize) < self.y_max
     }
 
-    /// Returns the first position in the grid containing the character `item`. Returns `None` if the character
+    /// Returns the value of the element in point `p`. Returns `None` if the point doesn't exist in the grid.
+    #[inline]
+    pub fn get_element(&self, p: &Point) -> Option<T> {
+        self.get_index(p).map(|i| self.elements[i])
+    }
+
+    /// Returns the first position in the grid containing the value `item`. Returns `None` if the value
     /// is not found in the grid.
     #[inline]
-    pub fn find(&self, item: char) -> Option<Point> {
+    pub fn find(&self, item: T) -> Option<Point> {
         self.elements
             .iter()
             .position(|&c| c == item)
-            .map(|i| Point::new((i % self.x_max) as i32, (i / self.x_max) as i32))
+            .and_then(|i| self.get_point(i))
     }
 
-    /// Returns the index in the element array of the point `p`.
-    /// TBD - change to return Option and None if the point is outside the grid.
+    /// Returns the index in the element array of the point `p`. Returns `None` if the point is
+    /// outside the grid.
     #[inline]
-    pub fn get_index(&self, p: &Point) -> usize {
-        self.x_max * p.y as usize + p.x as usize
+    pub fn get_index(&self, p: &Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| self.x_max * p.y as usize + p.x as usize)
     }
 
-    /// Returns the point of the `index` of the element array.
-    /// TBD - change to return Option and None if the index is outside the array.
+    /// Returns the point of the `index` of the element array. Returns `None` if the index is
+    /// outside the array.
     #[inline]
-    pub fn get_point(&self, index: usize) -> Point {
-        Point::new((index % self.x_max) as i32, (index / self.x_max) as i32)
+    pub fn get_point(&self, index: usize) -> Option<Point> {
+        (index < self.elements.len())
+            .then(|| Point::new((index % self.x_max) as i32, (index / self.x_max) as i32))
     }
 
-    /// Sets the character in point `p` to the character `val`.
+    /// Sets the element in point `p` to the value `val`. Points outside the grid are ignored.
     #[inline]
-    pub fn set_point(&mut self, p: &Point, val: char) {
-        if let Some(e) = self
-            .elements
-            .get_mut(self.x_max * p.y as usize + p.x as usize)
-        {
-            *e = val;
+    pub fn set_point(&mut self, p: &Point, val: T) {
+        if let Some(i) = self.get_index(p) {
+            self.elements[i] = val;
+        }
+    }
+
+    /// Returns an iterator over the points next to `p` in the directions `dirs` which are inside
+    /// the grid, with their values.
+    fn neighbors_in(&self, p: &Point, dirs: &'static [Point]) -> impl Iterator<Item = (Point, T)> {
+        let p = *p;
+        dirs.iter()
+            .map(move |d| *d + p)
+            .filter_map(|n| Some((n, self.get_element(&n)?)))
+    }
+
+    /// Returns an iterator over the up to 4 horizontal and vertical neighbours of `p` inside the
+    /// grid, with their values.
+    pub fn neighbors(&self, p: &Point) -> impl Iterator<Item = (Point, T)> {
+        self.neighbors_in(p, &NEIGHBORS_STRAIGHT)
+    }
+
+    /// Returns an iterator over the up to 8 neighbours of `p` inside the grid, including the
+    /// diagonal ones, with their values.
+    pub fn neighbors_all(&self, p: &Point) -> impl Iterator<Item = (Point, T)> {
+        self.neighbors_in(p, &NEIGHBORS_ALL)
+    }
+
+    /// Returns an iterator over all points of the grid with their values, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, T)> {
+        self.elements.iter().enumerate().map(|(i, &e)| {
+            (
+                Point::new((i % self.x_max) as i32, (i / self.x_max) as i32),
+                e,
+            )
+        })
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.elements.chunks(self.x_max.max(1))
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right, each one iterating
+    /// over its values from top to bottom. The columns of a grid without rows are empty.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = T>> {
+        (0..self.x_max).map(|x| self.elements.iter().skip(x).step_by(self.x_max).copied())
+    }
+
+    /// Returns a grid of the dimensions `x_max` : `y_max`, with the element in each point taken
+    /// from the point of this grid returned by `source`, which must be inside the grid.
+    fn remap(
+        &self,
+        x_max: usize,
+        y_max: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let elements = (0..y_max)
+            .flat_map(|y| (0..x_max).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (sx, sy) = source(x, y);
+                self.elements[sy * self.x_max + sx]
+            })
+            .collect();
+        Self {
+            x_max,
+            y_max,
+            elements,
+        }
+    }
+
+    /// Returns the grid mirrored in its main diagonal, i.e. with the rows as columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.y_max, self.x_max, |x, y| (y, x))
+    }
+
+    /// Returns the grid rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(self.y_max, self.x_max, |x, y| (y, self.y_max - 1 - x))
+    }
+
+    /// Returns the grid rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(self.y_max, self.x_max, |x, y| (self.x_max - 1 - y, x))
+    }
+
+    /// Returns the grid mirrored left to right.
+    pub fn flip_h(&self) -> Self {
+        self.remap(self.x_max, self.y_max, |x, y| (self.x_max - 1 - x, y))
+    }
+
+    /// Returns the grid mirrored top to bottom.
+    pub fn flip_v(&self) -> Self {
+        self.remap(self.x_max, self.y_max, |x, y| (x, self.y_max - 1 - y))
+    }
+
+    /// Returns the part of the grid of the dimensions `x_max` : `y_max` with its top left corner in
+    /// point `corner`. Returns `None` if the part doesn't fit inside the grid.
+    pub fn sub_grid(&self, corner: &Point, x_max: usize, y_max: usize) -> Option<Self> {
+        let fits = corner.x >= 0
+            && corner.y >= 0
+            && corner.x as usize + x_max <= self.x_max
+            && corner.y as usize + y_max <= self.y_max;
+        let (cx, cy) = (corner.x as usize, corner.y as usize);
+        fits.then(|| self.remap(x_max, y_max, |x, y| (cx + x, cy + y)))
+    }
+
+    /// Copies `other` into the grid with its top left corner in point `corner`. The parts of
+    /// `other` falling outside the grid are ignored.
+    pub fn paste(&mut self, other: &Self, corner: &Point) {
+        for (p, val) in other.cells() {
+            self.set_point(&(p + *corner), val);
         }
     }
+
+    /// Returns a view of the grid repeated infinitely in all directions.
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
+
+    /// Renders the grid as text, one line per row, with each element shown as the character
+    /// returned by `f`.
+    pub fn to_string_with(&self, mut f: impl FnMut(T) -> char) -> String {
+        let mut out = String::with_capacity(self.elements.len() + self.y_max);
+        for row in self.elements.chunks(self.x_max.max(1)) {
+            out.extend(row.iter().map(|&e| f(e)));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// A view of a grid repeated infinitely in all directions, so that every point has an element.
+/// The grid must not be empty.
+#[derive(Clone, Copy, Debug)]
+pub struct Tiled<'a, T = u8> {
+    grid: &'a Grid<T>,
+}
+
+impl<T: Copy + PartialEq> Tiled<'_, T> {
+    /// Returns the point inside the underlying grid corresponding to point `p`.
+    #[inline]
+    pub fn wrap(&self, p: &Point) -> Point {
+        Point::new(
+            p.x.rem_euclid(self.grid.x_max as i32),
+            p.y.rem_euclid(self.grid.y_max as i32),
+        )
+    }
+
+    /// Returns the value of the element in point `p`.
+    #[inline]
+    pub fn get_element(&self, p: &Point) -> T {
+        let p = self.wrap(p);
+        self.grid.elements[self.grid.x_max * p.y as usize + p.x as usize]
+    }
+
+    /// Returns an iterator over the 4 horizontal and vertical neighbours of `p`, with their values.
+    pub fn neighbors(&self, p: &Point) -> impl Iterator<Item = (Point, T)> {
+        let p = *p;
+        NEIGHBORS_STRAIGHT
+            .iter()
+            .map(move |d| *d + p)
+            .map(|n| (n, self.get_element(&n)))
+    }
 }
 
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut out = Vec::with_capacity(self.elements.len() + self.y_max);
-        for y in 0..self.y_max {
-            for x in 0..self.x_max {
-                out.push(self.elements[y * self.x_max + x]);
-            }
-            out.push('\n');
-        }
-        write!(f, "{}", out.iter().collect::<String>())
+        write!(f, "{}", self.to_string_with(char::from))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(|c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse("#..\n.#S\n").unwrap();
+        assert_eq!((grid.x_max, grid.y_max), (3, 2));
+        assert_eq!(grid.get_element(&Point::new(2, 1)), Some(b'S'));
+        assert_eq!(grid.get_element(&Point::new(3, 0)), None);
+        assert_eq!(grid.find(b'S'), Some(Point::new(2, 1)));
+        assert_eq!(grid.to_string(), "#..\n.#S\n");
+
+        let error = Grid::parse("#..\n.#").unwrap_err();
+        assert_eq!(error.message, "expected a line of 3 characters");
+        assert_eq!(error.text, ".#");
+        assert!(Grid::parse("").is_err());
+    }
+
+    #[test]
+    fn typed_elements() {
+        let mut walls = Grid::parse_with("#..\n.#.", |c| c == '#').unwrap();
+        walls.set_point(&Point::new(2, 0), true);
+        assert_eq!(walls.find(false), Some(Point::new(1, 0)));
+        assert_eq!(
+            walls.to_string_with(|w| if w { '#' } else { '.' }),
+            "#.#\n.#.\n"
+        );
+
+        let heights = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!(heights.get_element(&Point::new(1, 1)), Some(5));
+        assert_eq!(heights.map(|h| h * 2).elements, [2, 4, 6, 8, 10, 12]);
+        let error = Grid::parse_digits("12\n3x").unwrap_err();
+        assert_eq!(error.text, "x");
+
+        let chars = Grid::new(2, 1, 'é');
+        assert_eq!(chars.to_string(), "éé\n");
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = Grid::parse("abc\ndef").unwrap();
+        assert!(grid.contains(&Point::new(2, 1)));
+        assert!(!grid.contains(&Point::new(3, 0)));
+        assert!(!grid.contains(&Point::new(-1, 0)));
+        assert_eq!(grid.get_index(&Point::new(1, 1)), Some(4));
+        assert_eq!(grid.get_index(&Point::new(3, 0)), None);
+        assert_eq!(grid.get_point(5), Some(Point::new(2, 1)));
+        assert_eq!(grid.get_point(6), None);
+        assert_eq!(grid.get_element(&Point::new(0, -1)), None);
+        grid.set_point(&Point::new(3, 0), b'x');
+        grid.set_point(&Point::new(-1, 1), b'x');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn iterators() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        let straight: Vec<_> = grid.neighbors(&Point::new(0, 0)).collect();
+        assert_eq!(
+            straight,
+            [(Point::new(1, 0), b'b'), (Point::new(0, 1), b'd')]
+        );
+        assert_eq!(grid.neighbors_all(&Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors_all(&Point::new(1, 1)).count(), 5);
+        assert_eq!(grid.neighbors(&Point::new(5, 5)).count(), 0);
+
+        let cells: Vec<_> = grid.cells().collect();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[4], (Point::new(1, 1), b'e'));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        let columns: Vec<Vec<_>> = grid.columns().map(Iterator::collect).collect();
+        assert_eq!(columns, [b"ad", b"be", b"cf"]);
+
+        let no_rows = Grid::new(3, 0, b'.');
+        let columns: Vec<Vec<_>> = no_rows.columns().map(Iterator::collect).collect();
+        assert_eq!(columns, [[], [], []]);
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_h().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_v().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_h().flip_v());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn sub_grids() {
+        let mut grid = Grid::parse("abcd\nefgh\nijkl").unwrap();
+        let sub = grid.sub_grid(&Point::new(1, 1), 3, 2).unwrap();
+        assert_eq!(sub.to_string(), "fgh\njkl\n");
+        assert_eq!(grid.sub_grid(&Point::new(2, 0), 3, 1), None);
+        assert_eq!(grid.sub_grid(&Point::new(-1, 0), 1, 1), None);
+        assert_eq!(grid.sub_grid(&Point::new(0, 0), 4, 3).as_ref(), Some(&grid));
+
+        grid.paste(&Grid::parse("XY\nZW").unwrap(), &Point::new(0, 1));
+        assert_eq!(grid.to_string(), "abcd\nXYgh\nZWkl\n");
+        grid.paste(&Grid::parse("12\n34").unwrap(), &Point::new(3, -1));
+        assert_eq!(grid.to_string(), "abc3\nXYgh\nZWkl\n");
+    }
+
+    #[test]
+    fn tiled_view() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        let tiled = grid.tiled();
+        assert_eq!(tiled.get_element(&Point::new(1, 1)), b'e');
+        assert_eq!(tiled.get_element(&Point::new(4, 2)), b'b');
+        assert_eq!(tiled.get_element(&Point::new(-1, -1)), b'f');
+        assert_eq!(tiled.wrap(&Point::new(-4, 5)), Point::new(2, 1));
+        let values: Vec<_> = tiled.neighbors(&Point::new(0, 0)).map(|(_, v)| v).collect();
+        assert_eq!(values, [b'b', b'd', b'c', b'd']);
     }
 }
@@ -0,0 +1,169 @@
+//! # Input parsing
+//!
+//! A common error type for the input parsers of all days, together with some helper functions
+//! returning it. A `ParseError` remembers where the offending text is in memory, so as long as the
+//! text is a slice of the puzzle input, the error can afterwards be located by line and column
+//! with `locate`, without the parsers having to keep track of line numbers.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The maximum number of characters of offending text included in the error message.
+const MAX_TEXT_LEN: usize = 30;
+
+/// An error found when parsing the puzzle input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// What was wrong, e.g. "expected `Operation:`".
+    pub message: String,
+    /// The beginning of the offending text, up to the end of its line.
+    pub text: String,
+    /// The (1-based) line and column of the offending text, once located.
+    pub position: Option<(usize, usize)>,
+    /// The address of the offending text, used to locate it within the input.
+    address: usize,
+}
+
+impl ParseError {
+    /// Creates an error with `message` about the text `at`, which should be a slice of the input.
+    pub fn new(message: impl Into<String>, at: &str) -> Self {
+        let line = at.lines().next().unwrap_or_default();
+        Self {
+            message: message.into(),
+            text: line.chars().take(MAX_TEXT_LEN).collect(),
+            position: None,
+            address: at.as_ptr() as usize,
+        }
+    }
+
+    /// Creates an error with `message` about something missing at the end of `s`, which should be
+    /// a slice of the input.
+    pub fn missing(message: impl Into<String>, s: &str) -> Self {
+        Self::new(message, &s[s.len()..])
+    }
+
+    /// Sets the line and column of the error, if the offending text is a part of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.position.is_none() && (start..=start + input.len()).contains(&self.address) {
+            let before = &input[..self.address - start];
+            let line = before.matches('\n').count() + 1;
+            let column = before
+                .rfind('\n')
+                .map_or(before.len(), |i| before.len() - i - 1)
+                + 1;
+            self.position = Some((line, column));
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((line, column)) = self.position {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ", found `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s` as a number, or any other type implementing `FromStr`.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new("expected a number", s))
+}
+
+/// Describes the separator or prefix `s` for an error message.
+fn describe(s: &str) -> String {
+    match s.trim() {
+        "" if s.contains("\n\n") => "a blank line".to_string(),
+        "" if s.contains('\n') => "a line break".to_string(),
+        "" => "a space".to_string(),
+        trimmed => format!("`{trimmed}`"),
+    }
+}
+
+/// Returns the rest of `s` after `prefix`.
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("expected {}", describe(prefix)), s))
+}
+
+/// Splits `s` at the first occurrence of `delimiter`.
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(format!("expected {}", describe(delimiter)), s))
+}
+
+/// Returns the next item from `iter`, typically the next line or word of `s`, or an error saying
+/// that `what` is missing at the end of `s`.
+pub fn next<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    what: &str,
+    s: &'a str,
+) -> Result<&'a str, ParseError> {
+    iter.next()
+        .ok_or_else(|| ParseError::missing(format!("missing {what}"), s))
+}
+
+/// Parses each line of `s` with `f`.
+pub fn lines<'a, T>(
+    s: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_in_input() {
+        let input = "Monkey 0:\n  Starting items: 79, 98\n  Operatio: new = old * 19";
+        let line = input.lines().nth(2).unwrap();
+        let e = strip_prefix(line.trim_start(), "Operation: ")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(e.position, Some((3, 3)));
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 3: expected `Operation:`, found `Operatio: new = old * 19`"
+        );
+    }
+
+    #[test]
+    fn locate_outside_input() {
+        let e = number::<u8>("x1").unwrap_err().locate("1\n2\n");
+        assert_eq!(e.position, None);
+        assert_eq!(e.to_string(), "expected a number, found `x1`");
+    }
+
+    #[test]
+    fn missing_at_end() {
+        let input = "a\nb";
+        let mut words = input.lines();
+        words.next();
+        words.next();
+        let e = next(&mut words, "line", input).unwrap_err().locate(input);
+        assert_eq!(e.to_string(), "line 2, column 2: missing line");
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(number::<i32>("-12"), Ok(-12));
+        assert_eq!(split_once("a -> b", " -> ").unwrap(), ("a", "b"));
+        assert_eq!(
+            split_once("a\nb", "\n\n").unwrap_err().message,
+            "expected a blank line"
+        );
+        assert_eq!(lines("1\n2\n3", number::<u8>).unwrap(), vec![1, 2, 3]);
+        assert!(lines("1\nx", number::<u8>).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 //! # Point utility
 //!
 //! Provides a struct and some useful functions for managing a 2D point in x-y plane.
-use std::error::Error;
+use crate::aoc_util::parse::{self, ParseError};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 use std::str::FromStr;
@@ -57,17 +57,17 @@ impl Point {
 }
 
 impl FromStr for Point {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some((x, y)) = s.split_once([',', '-']) {
             Ok(Self {
-                x: x.parse()?,
-                y: y.parse()?,
+                x: parse::number(x)?,
+                y: parse::number(y)?,
             })
         } else {
-            Err(format!("Can't parse to point: {s}").into())
+            Err(ParseError::new("expected a point `x,y`", s))
         }
     }
 }
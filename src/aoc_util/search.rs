@@ -0,0 +1,312 @@
+//! # Search utility
+//!
+//! Shortest path searches over any state type, e.g. a point or a point together with a direction.
+//! The state space is given by a successor function, returning the states reachable from a state,
+//! together with the cost of each step for the weighted searches:
+//!
+//! * [`bfs`] - breadth first search, where every step costs 1
+//! * [`dijkstra`] - Dijkstra's algorithm for positive step costs
+//! * [`astar`] - A* with a heuristic, which must never overestimate the remaining cost
+//!
+//! [`grid_bfs`] runs a breadth first search directly on a [`Grid`], between neighbouring points
+//! for which a predicate holds.
+//!
+//! The search stops once all goal states at the shortest distance are reached, or when the state
+//! space is exhausted if there is no goal. It returns the distances of the visited states and,
+//! for each state, all its predecessors on shortest paths to it, from which a path or the set of
+//! all states on any shortest path can be recovered.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::aoc_util::grid::Grid;
+use crate::aoc_util::point::Point;
+
+/// The result of a search.
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    /// The shortest distance from a start state to each visited state.
+    pub distances: HashMap<S, usize>,
+    /// For each visited state, the states preceding it on the shortest paths to it. Start states
+    /// have no predecessors.
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// The goal states at the shortest distance, in the order they were reached.
+    pub goals: Vec<S>,
+}
+
+impl<S: Copy + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records the step from `from` to `to` at the distance `dist`. Returns true if it is the
+    /// shortest distance to `to` found so far, rather than an equally short alternative.
+    fn relax(&mut self, from: S, to: S, dist: usize) -> bool {
+        match self.distances.get(&to) {
+            Some(&d) if d < dist => false,
+            Some(&d) if d == dist => {
+                self.predecessors.entry(to).or_default().push(from);
+                false
+            }
+            _ => {
+                self.distances.insert(to, dist);
+                self.predecessors.insert(to, vec![from]);
+                true
+            }
+        }
+    }
+
+    /// Returns the shortest distance to `state`, or `None` if it wasn't reached.
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// Returns the shortest distance to a goal, or `None` if no goal was reached.
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// Returns one of the shortest paths to `state`, from a start state up to and including
+    /// `state`. The path is empty if `state` wasn't reached.
+    pub fn path(&self, state: &S) -> Vec<S> {
+        if !self.distances.contains_key(state) {
+            return Vec::new();
+        }
+        let mut path = vec![*state];
+        while let Some(&previous) = self
+            .predecessors
+            .get(path.last().unwrap())
+            .and_then(|p| p.first())
+        {
+            path.push(previous);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns all the states on any of the shortest paths to any of `states`.
+    pub fn on_shortest_paths(&self, states: &[S]) -> HashSet<S> {
+        let mut seen: HashSet<S> = states
+            .iter()
+            .filter(|s| self.distances.contains_key(s))
+            .copied()
+            .collect();
+        let mut queue: Vec<_> = seen.iter().copied().collect();
+        while let Some(state) = queue.pop() {
+            for previous in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(*previous) {
+                    queue.push(*previous);
+                }
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth first search from the states `starts`, where each step to one of the states returned by
+/// `successors` costs 1, until reaching the states for which `is_goal` is true.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((state, dist)) = queue.pop_front() {
+        if search.cost().is_some_and(|cost| dist > cost) {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+        for next in successors(&state) {
+            if search.relax(state, next, dist + 1) {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from the states `starts`, where each step to one of the states returned by
+/// `successors` has the cost returned along with it, until reaching the states for which
+/// `is_goal` is true.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search from the states `starts`, where each step to one of the states returned by
+/// `successors` has the cost returned along with it, until reaching the states for which
+/// `is_goal` is true. `heuristic` estimates the remaining cost from a state to the closest goal,
+/// and must never overestimate it.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    // The heap holds the estimated total cost and an index into `states`, so that the states
+    // themselves don't need to be ordered.
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push(Reverse((heuristic(&start), states.len())));
+            states.push((start, 0));
+        }
+    }
+    while let Some(Reverse((estimate, i))) = queue.pop() {
+        let (state, dist) = states[i];
+        if search.cost().is_some_and(|cost| estimate > cost) {
+            break;
+        }
+        if search.distances[&state] < dist {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            if search.relax(state, next, dist + step) {
+                queue.push(Reverse((dist + step + heuristic(&next), states.len())));
+                states.push((next, dist + step));
+            }
+        }
+    }
+    search
+}
+
+/// Breadth first search on `grid` from the point `start`, stepping horizontally or vertically to
+/// the neighbouring points for which `passable` is true, given the values of the current and the
+/// next point, until reaching the points for which `is_goal` is true.
+pub fn grid_bfs<T: Copy + PartialEq>(
+    grid: &Grid<T>,
+    start: Point,
+    passable: impl Fn(T, T) -> bool,
+    is_goal: impl FnMut(&Point) -> bool,
+) -> Search<Point> {
+    bfs(
+        [start],
+        |p| {
+            let current = grid.get_element(p);
+            grid.neighbors(p)
+                .filter(|(_, next)| current.is_some_and(|current| passable(current, *next)))
+                .map(|(n, _)| n)
+                .collect::<Vec<_>>()
+        },
+        is_goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_search() {
+        let grid = Grid::parse("S.#..\n..#.#\n....E").unwrap();
+        let start = grid.find(b'S').unwrap();
+        let exit = grid.find(b'E').unwrap();
+        let search = grid_bfs(&grid, start, |_, next| next != b'#', |p| *p == exit);
+        assert_eq!(search.cost(), Some(6));
+        assert_eq!(search.goals, [exit]);
+        let path = search.path(&exit);
+        assert_eq!(path.len(), 7);
+        assert_eq!((path[0], path[6]), (start, exit));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        let on_paths = search.on_shortest_paths(&[exit]);
+        assert_eq!(on_paths.len(), 9);
+        assert!(on_paths.contains(&Point::new(1, 1)));
+        assert!(!on_paths.contains(&Point::new(3, 1)));
+
+        let walled = Grid::parse("S#.\n##E").unwrap();
+        let search = grid_bfs(
+            &walled,
+            Point::new(0, 0),
+            |_, next| next != b'#',
+            |p| *p == Point::new(2, 1),
+        );
+        assert_eq!(search.cost(), None);
+        assert!(search.path(&Point::new(2, 1)).is_empty());
+        assert_eq!(search.distances.len(), 1);
+    }
+
+    /// A small weighted graph with two equally short paths from 0 to 4, 0-1-3-4 and 0-2-3-4, and
+    /// a longer direct edge 0-4.
+    fn edges(node: &u8) -> Vec<(u8, usize)> {
+        match node {
+            0 => vec![(1, 1), (2, 2), (4, 10)],
+            1 => vec![(3, 3)],
+            2 => vec![(3, 2)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted_search() {
+        let search = dijkstra([0], edges, |n| *n == 4);
+        assert_eq!(search.cost(), Some(5));
+        assert_eq!(search.distance(&3), Some(4));
+        assert_eq!(search.predecessors[&3], [1, 2]);
+        assert_eq!(search.path(&4), [0, 1, 3, 4]);
+        let on_paths = search.on_shortest_paths(&search.goals);
+        assert_eq!(on_paths, HashSet::from([0, 1, 2, 3, 4]));
+
+        let search = astar([0], edges, |n| usize::from(*n < 3), |n| *n == 4);
+        assert_eq!(search.cost(), Some(5));
+
+        let search = dijkstra([0], edges, |_| false);
+        assert_eq!(search.distances.len(), 5);
+        assert_eq!(search.cost(), None);
+
+        let search = bfs([0], |n| edges(n).into_iter().map(|(m, _)| m), |n| *n == 4);
+        assert_eq!(search.cost(), Some(1));
+        assert_eq!(search.path(&4), [0, 4]);
+    }
+
+    #[test]
+    fn several_goals() {
+        // From 0 both 2 and 3 are two steps away, but 4 is three.
+        let successors = |n: &u8| match n {
+            0 => vec![1],
+            1 => vec![2, 3],
+            3 => vec![4],
+            _ => vec![],
+        };
+        let search = bfs([0], successors, |n| *n >= 2);
+        assert_eq!(search.goals, [2, 3]);
+        assert_eq!(search.cost(), Some(2));
+        let search = bfs([5, 0], successors, |n| *n == 1);
+        assert_eq!(search.path(&1), [0, 1]);
+        assert_eq!(search.distance(&5), Some(0));
+    }
+}
@@ -0,0 +1,193 @@
+//! # Sparse grid utility
+//!
+//! Provides a struct for managing a 2D grid without fixed bounds, for maps which grow in any
+//! direction or have offset coordinates. Only the points which have been set are stored, in a
+//! hash map, and every other point holds a default value. The bounding box of the set points is
+//! tracked, so that the occupied region can be rendered or converted to a dense [`Grid`].
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::aoc_util::grid::Grid;
+use crate::aoc_util::point::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T = u8> {
+    elements: HashMap<Point, T>,
+    default: T,
+    min: Point,
+    max: Point,
+}
+
+impl<T: Copy + PartialEq> SparseGrid<T> {
+    /// Creates a new empty `SparseGrid` instance, where every point holds the value `default`.
+    pub fn new(default: T) -> Self {
+        Self {
+            elements: HashMap::new(),
+            default,
+            min: ORIGIN,
+            max: ORIGIN,
+        }
+    }
+
+    /// Creates a new `SparseGrid` instance holding the elements of `grid` which aren't `default`,
+    /// with the top left corner of `grid` in the origin.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        for (p, val) in grid.cells().filter(|(_, val)| *val != default) {
+            sparse.set_point(&p, val);
+        }
+        sparse
+    }
+
+    /// Returns the number of points which have been set.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if no point has been set.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns true if the point `p` has been set.
+    #[inline]
+    pub fn contains(&self, p: &Point) -> bool {
+        self.elements.contains_key(p)
+    }
+
+    /// Returns the value of the element in point `p`, or the default value if it hasn't been set.
+    #[inline]
+    pub fn get_element(&self, p: &Point) -> T {
+        self.elements.get(p).copied().unwrap_or(self.default)
+    }
+
+    /// Sets the element in point `p` to the value `val`, expanding the bounding box if needed.
+    #[inline]
+    pub fn set_point(&mut self, p: &Point, val: T) {
+        if self.elements.is_empty() {
+            (self.min, self.max) = (*p, *p);
+        } else {
+            self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+            self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+        }
+        self.elements.insert(*p, val);
+    }
+
+    /// Returns the top left and bottom right corners of the smallest box holding all the points
+    /// which have been set. Returns `None` if no point has been set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        (!self.elements.is_empty()).then_some((self.min, self.max))
+    }
+
+    /// Returns an iterator over the 4 horizontal and vertical neighbours of `p`, with their values.
+    pub fn neighbors(&self, p: &Point) -> impl Iterator<Item = (Point, T)> {
+        let p = *p;
+        NEIGHBORS_STRAIGHT
+            .iter()
+            .map(move |d| *d + p)
+            .map(|n| (n, self.get_element(&n)))
+    }
+
+    /// Returns an iterator over the 8 neighbours of `p`, including the diagonal ones, with their
+    /// values.
+    pub fn neighbors_all(&self, p: &Point) -> impl Iterator<Item = (Point, T)> {
+        let p = *p;
+        NEIGHBORS_ALL
+            .iter()
+            .map(move |d| *d + p)
+            .map(|n| (n, self.get_element(&n)))
+    }
+
+    /// Returns an iterator over the points which have been set with their values, in no
+    /// particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, T)> {
+        self.elements.iter().map(|(p, val)| (*p, *val))
+    }
+
+    /// Returns the bounding box as a dense grid, together with the point of the sparse grid in its
+    /// top left corner. An empty sparse grid gives an empty grid.
+    pub fn to_grid(&self) -> (Grid<T>, Point) {
+        let Some((min, max)) = self.bounds() else {
+            return (Grid::new(0, 0, self.default), ORIGIN);
+        };
+        let mut grid = Grid::new(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            self.default,
+        );
+        for (p, val) in self.cells() {
+            grid.set_point(&(p - min), val);
+        }
+        (grid, min)
+    }
+
+    /// Renders the bounding box as text, one line per row, with each element shown as the
+    /// character returned by `f`.
+    pub fn to_string_with(&self, f: impl FnMut(T) -> char) -> String {
+        self.to_grid().0.to_string_with(f)
+    }
+}
+
+impl fmt::Display for SparseGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(char::from))
+    }
+}
+
+impl fmt::Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(|c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expanding() {
+        let mut grid = SparseGrid::new(b'.');
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.set_point(&Point::new(2, -1), b'#');
+        grid.set_point(&Point::new(-1, 1), b'o');
+        grid.set_point(&Point::new(0, 0), b'#');
+        assert_eq!(grid.len(), 3);
+        assert!(grid.contains(&Point::new(0, 0)));
+        assert!(!grid.contains(&Point::new(1, 0)));
+        assert_eq!(grid.get_element(&Point::new(-1, 1)), b'o');
+        assert_eq!(grid.get_element(&Point::new(100, 100)), b'.');
+        assert_eq!(grid.bounds(), Some((Point::new(-1, -1), Point::new(2, 1))));
+        assert_eq!(grid.to_string(), "...#\n.#..\no...\n");
+        assert_eq!(
+            grid.neighbors(&Point::new(1, 0))
+                .filter(|(_, v)| *v == b'#')
+                .count(),
+            1
+        );
+        assert_eq!(
+            grid.neighbors_all(&Point::new(1, 0))
+                .filter(|(_, v)| *v == b'#')
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn dense_conversion() {
+        let dense = Grid::parse("..#\n#..").unwrap();
+        let sparse = SparseGrid::from_grid(&dense, b'.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.bounds(), Some((Point::new(0, 0), Point::new(2, 1))));
+        assert_eq!(sparse.to_grid(), (dense, ORIGIN));
+
+        let mut sparse = SparseGrid::new(false);
+        sparse.set_point(&Point::new(-3, 5), true);
+        sparse.set_point(&Point::new(-2, 5), true);
+        let (grid, corner) = sparse.to_grid();
+        assert_eq!((grid.x_max, grid.y_max, corner), (2, 1, Point::new(-3, 5)));
+        assert_eq!(sparse.to_string_with(|v| if v { '#' } else { '.' }), "##\n");
+    }
+}
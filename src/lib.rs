@@ -1,15 +1,44 @@
-use std::env;
+use aoc_runner::bench::BenchOptions;
+use aoc_runner::format::Format;
+use aoc_runner::input::InputSource;
+use aoc_runner::solver::Solver;
 use std::error::Error;
-use std::fs;
-use std::time::Instant;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub use aoc_runner::answer::Answer;
+pub use aoc_runner::params::Params;
+pub use aoc_runner::solver::{Outcome, Parts, PhaseTimes, SolveError, solve};
 
 pub mod aoc_util {
     pub mod grid;
     pub mod math;
+    pub mod parse;
     pub mod point;
+    pub mod search;
+    pub mod sparse_grid;
     pub mod thread;
 }
 
+pub mod aoc_runner {
+    pub mod answer;
+    pub mod answers;
+    pub mod batch;
+    pub mod bench;
+    pub mod coverage;
+    pub mod examples;
+    pub mod format;
+    pub mod history;
+    pub mod input;
+    pub mod memory;
+    pub mod params;
+    pub mod scaffold;
+    pub mod shape;
+    pub mod solver;
+    pub mod watch;
+}
+
 pub mod year2015;
 pub mod year2016;
 pub mod year2017;
@@ -22,222 +51,554 @@ pub mod year2023;
 pub mod year2024;
 pub mod year2025;
 
+/// The solver tables of all years, in order.
+const YEARS: &[&[Solver]] = &[
+    year2015::SOLVERS,
+    year2016::SOLVERS,
+    year2017::SOLVERS,
+    year2018::SOLVERS,
+    year2019::SOLVERS,
+    year2020::SOLVERS,
+    year2021::SOLVERS,
+    year2022::SOLVERS,
+    year2023::SOLVERS,
+    year2024::SOLVERS,
+    year2025::SOLVERS,
+];
+
+/// Which puzzles to run.
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    /// A single puzzle, e.g. `2015 1`.
+    Day(u16, u8),
+    /// A range of days in one year, e.g. `2024 1..=12`.
+    Days(u16, RangeInclusive<u8>),
+    /// All implemented days of one year, e.g. `2023`.
+    Year(u16),
+    /// All implemented puzzles, i.e. `all`.
+    All,
+}
+
+impl Selection {
+    /// Returns true if the puzzle described by `solver` is part of the selection.
+    pub fn contains(&self, solver: &Solver) -> bool {
+        match self {
+            Selection::Day(year, day) => solver.year == *year && solver.day == *day,
+            Selection::Days(year, days) => solver.year == *year && days.contains(&solver.day),
+            Selection::Year(year) => solver.year == *year,
+            Selection::All => true,
+        }
+    }
+}
+
+/// Parses a day range on the form `a..=b` or `a..b`, or a single day `a`.
+fn parse_days(arg: &str) -> Result<RangeInclusive<u8>, &'static str> {
+    const ERROR_MSG: &str = "Invalid day or day range.";
+    let parse = |s: &str| s.parse::<u8>().map_err(|_| ERROR_MSG);
+    if let Some((a, b)) = arg.split_once("..=") {
+        Ok(parse(a)?..=parse(b)?)
+    } else if let Some((a, b)) = arg.split_once("..") {
+        Ok(parse(a)?..=parse(b)?.checked_sub(1).ok_or(ERROR_MSG)?)
+    } else {
+        let day = parse(arg)?;
+        Ok(day..=day)
+    }
+}
+
+/// What to do with the answers of the selected puzzles.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mode {
+    /// Only print the answers.
+    Solve,
+    /// Compare the answers to the answer store, `--verify`.
+    Verify,
+    /// Write the answers to the answer store, `--record`.
+    Record,
+    /// Time repeated runs of each puzzle, `--bench N`, optionally comparing the result to a
+    /// baseline revision with `--compare REV [--threshold PERCENT]`.
+    Bench(BenchOptions),
+    /// Solve the examples of each puzzle and check their answers, `--example [N]`, optionally
+    /// only example N.
+    Example(Option<usize>),
+    /// Create and register a new day from the template, `new YEAR DAY TITLE`.
+    New(String),
+    /// List the puzzles whose input format a file matches, `identify FILE`.
+    Identify(PathBuf),
+    /// Show which puzzles are implemented, verified and have examples, with their latest median
+    /// time, `--coverage`.
+    Coverage,
+}
+
 pub struct Config {
-    pub year: String,
-    pub day: String,
+    pub selection: Selection,
+    pub mode: Mode,
+    pub input: InputSource,
+    pub format: Format,
+    pub parts: Parts,
+    /// The time limit for each puzzle, `--timeout SECONDS`, where 0 means no limit.
+    pub timeout: Option<Duration>,
+    /// Run the puzzles one at a time rather than in parallel, `--sequential`.
+    pub sequential: bool,
+    /// Overrides of solver parameters, `--param NAME=VALUE`.
+    pub params: Params,
+    /// Solve again whenever the input file changes, `--watch`.
+    pub watch: bool,
 }
 
 impl Config {
-    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+    pub fn build(args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+        let mut args = args.peekable();
         args.next();
 
-        let year = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get year."),
-        };
+        /// Number of benchmark iterations when comparing without `--bench`.
+        const DEFAULT_ITERATIONS: usize = 10;
+        /// Default regression threshold in percent.
+        const DEFAULT_THRESHOLD: f64 = 10.0;
+        /// Default time limit for each puzzle.
+        const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+        let mut mode = Mode::Solve;
+        let mut iterations = None;
+        let mut baseline = None;
+        let mut threshold = DEFAULT_THRESHOLD;
+        let mut input = InputSource::default();
+        let mut format = Format::default();
+        let mut parts = Parts::default();
+        let mut timeout = Some(DEFAULT_TIMEOUT);
+        let mut sequential = false;
+        let mut params = Params::default();
+        let mut watch = false;
+        let mut positional = Vec::new();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--verify" => mode = Mode::Verify,
+                "--record" => mode = Mode::Record,
+                "--coverage" => mode = Mode::Coverage,
+                "--example" => {
+                    // The example number is optional, and told apart from a year by its size.
+                    let number = args.next_if(|n| n.parse::<u8>().is_ok());
+                    mode = Mode::Example(match number {
+                        Some(n) => Some(
+                            n.parse()
+                                .ok()
+                                .filter(|n| *n > 0)
+                                .ok_or("Invalid example number.")?,
+                        ),
+                        None => None,
+                    });
+                }
+                "--sequential" => sequential = true,
+                "--watch" => watch = true,
+                "--bench" => {
+                    iterations = Some(
+                        args.next()
+                            .and_then(|n| n.parse().ok())
+                            .filter(|n| *n > 0)
+                            .ok_or("Invalid or missing number of iterations for --bench.")?,
+                    );
+                }
+                "--compare" => {
+                    baseline = Some(args.next().ok_or("Missing revision for --compare.")?);
+                }
+                "--threshold" => {
+                    threshold = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n: &f64| *n >= 0.0)
+                        .ok_or("Invalid or missing percentage for --threshold.")?;
+                }
+                "--input" => {
+                    input = InputSource::from_arg(&args.next().ok_or("Missing file for --input.")?);
+                }
+                "--format" => {
+                    format = args.next().ok_or("Missing format for --format.")?.parse()?;
+                }
+                "--part" => {
+                    parts = args.next().ok_or("Missing part for --part.")?.parse()?;
+                }
+                "--timeout" => {
+                    let seconds: f64 = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n: &f64| n.is_finite() && *n >= 0.0)
+                        .ok_or("Invalid or missing number of seconds for --timeout.")?;
+                    timeout = (seconds > 0.0).then(|| Duration::from_secs_f64(seconds));
+                }
+                "--param" => {
+                    params.extend(args.next().ok_or("Missing value for --param.")?.parse()?);
+                }
+                "--input-dir" => {
+                    input = InputSource::Directory(Some(
+                        args.next().ok_or("Missing folder for --input-dir.")?.into(),
+                    ));
+                }
+                a if a.starts_with("--") => return Err("Unknown option."),
+                _ => positional.push(arg),
+            }
+        }
 
-        let day = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get day."),
+        if parts != Parts::Both {
+            if mode == Mode::Record {
+                return Err("--record needs both parts, and can't be combined with --part.");
+            }
+            if baseline.is_some() {
+                return Err("--compare needs both parts, and can't be combined with --part.");
+            }
+        }
+        if !params.is_empty() {
+            if mode == Mode::Record {
+                return Err(
+                    "--record needs the default parameters, and can't be combined with --param.",
+                );
+            }
+            if baseline.is_some() {
+                return Err(
+                    "--compare needs the default parameters, and can't be combined with --param.",
+                );
+            }
+        }
+        if mode == Mode::Record && input != InputSource::default() {
+            return Err(
+                "--record needs the default inputs, and can't be combined with --input options.",
+            );
+        }
+        if iterations.is_some() || baseline.is_some() {
+            if mode != Mode::Solve {
+                return Err("--bench and --compare can't be combined with --verify or --record.");
+            }
+            mode = Mode::Bench(BenchOptions {
+                iterations: iterations.unwrap_or(DEFAULT_ITERATIONS),
+                baseline,
+                threshold,
+            });
+        }
+
+        if positional.first().is_some_and(|arg| arg == "new") {
+            if mode != Mode::Solve {
+                return Err("new can't be combined with other modes.");
+            }
+            match positional.pop() {
+                Some(title) if positional.len() == 3 => mode = Mode::New(title),
+                _ => return Err("Expected new YEAR DAY TITLE."),
+            }
+            positional.remove(0);
+        }
+        if positional.first().is_some_and(|arg| arg == "identify") {
+            if mode != Mode::Solve {
+                return Err("identify can't be combined with other modes.");
+            }
+            match positional.pop() {
+                Some(file) if positional.len() == 1 => mode = Mode::Identify(file.into()),
+                _ => return Err("Expected identify FILE."),
+            }
+            positional[0] = "all".to_string();
+        }
+        let mut positional = positional.into_iter();
+        let selection = match positional.next() {
+            Some(arg) if arg == "all" => Selection::All,
+            Some(arg) => {
+                let year = arg.parse().map_err(|_| "Invalid year.")?;
+                match positional.next() {
+                    Some(arg) if arg.contains("..") => Selection::Days(year, parse_days(&arg)?),
+                    Some(arg) => Selection::Day(year, arg.parse().map_err(|_| "Invalid day.")?),
+                    None => Selection::Year(year),
+                }
+            }
+            None => return Err("Didn't get year, or 'all'."),
         };
+        if positional.next().is_some() {
+            return Err("Too many arguments.");
+        }
+        if matches!(mode, Mode::New(_)) && !matches!(selection, Selection::Day(..)) {
+            return Err("new needs a single year and day.");
+        }
+        if matches!(input, InputSource::File(_) | InputSource::Stdin)
+            && !matches!(selection, Selection::Day(..))
+        {
+            return Err("--input can only be used with a single puzzle.");
+        }
+        if watch {
+            if !matches!(selection, Selection::Day(..)) {
+                return Err("--watch can only be used with a single puzzle.");
+            }
+            if let Mode::Example(_) = mode {
+                return Err("--watch only watches input files, the examples are built in.");
+            }
+            if mode != Mode::Solve || format != Format::Text {
+                return Err("--watch can't be combined with other modes or formats.");
+            }
+            if input == InputSource::Stdin {
+                return Err("--watch needs an input file, not stdin.");
+            }
+        }
+        if mode == Mode::Coverage && !matches!(format, Format::Text | Format::Markdown) {
+            return Err("--coverage only supports the text and markdown formats.");
+        }
+        if mode != Mode::Coverage && format == Format::Markdown {
+            return Err("--format markdown is only supported with --coverage.");
+        }
+        if matches!(mode, Mode::Bench(_)) && format != Format::Text {
+            return Err("--format is not supported with --bench or --compare.");
+        }
+        if let Mode::Example(_) = mode {
+            if format != Format::Text {
+                return Err("--format is not supported with --example.");
+            }
+            if input != InputSource::default() {
+                return Err(
+                    "--example uses the built-in examples, and can't be combined with --input.",
+                );
+            }
+        }
 
-        Ok(Config { year, day })
+        Ok(Config {
+            selection,
+            mode,
+            input,
+            format,
+            parts,
+            timeout,
+            sequential,
+            params,
+            watch,
+        })
     }
 }
 
 pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    // Read input file
-    let cwd = env::current_dir().unwrap();
-    let filename = cwd
-        .join("AdventOfCode-Input")
-        .join(&config.year)
-        .join(format!("day{:02}.txt", config.day));
-    let aoc_input = fs::read_to_string(filename)?
-        .trim_end_matches('\n')
-        .to_string();
-
-    const DAY_ERROR_MSG: &str = "Day not implemented";
-    // Run solver
-    let timer = Instant::now();
-    let (p1, p2) = match config.year.as_str() {
-        "2015" => match config.day.as_str() {
-            "01" => year2015::day01::solve(&aoc_input)?,
-            "02" => year2015::day02::solve(&aoc_input)?,
-            "03" => year2015::day03::solve(&aoc_input)?,
-            "04" => year2015::day04::solve(&aoc_input)?,
-            "05" => year2015::day05::solve(&aoc_input)?,
-            "06" => year2015::day06::solve(&aoc_input)?,
-            "07" => year2015::day07::solve(&aoc_input)?,
-            "08" => year2015::day08::solve(&aoc_input)?,
-            "09" => year2015::day09::solve(&aoc_input)?,
-            "10" => year2015::day10::solve(&aoc_input)?,
-            "11" => year2015::day11::solve(&aoc_input)?,
-            "19" => year2015::day19::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2016" => match config.day.as_str() {
-            "01" => year2016::day01::solve(&aoc_input)?,
-            "02" => year2016::day02::solve(&aoc_input)?,
-            "03" => year2016::day03::solve(&aoc_input)?,
-            "04" => year2016::day04::solve(&aoc_input)?,
-            "06" => year2016::day06::solve(&aoc_input)?,
-            "07" => year2016::day07::solve(&aoc_input)?,
-            "08" => year2016::day08::solve(&aoc_input)?,
-            "09" => year2016::day09::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2017" => match config.day.as_str() {
-            "01" => year2017::day01::solve(&aoc_input)?,
-            "02" => year2017::day02::solve(&aoc_input)?,
-            "03" => year2017::day03::solve(&aoc_input)?,
-            "04" => year2017::day04::solve(&aoc_input)?,
-            "05" => year2017::day05::solve(&aoc_input)?,
-            "06" => year2017::day06::solve(&aoc_input)?,
-            "07" => year2017::day07::solve(&aoc_input)?,
-            "08" => year2017::day08::solve(&aoc_input)?,
-            "09" => year2017::day09::solve(&aoc_input)?,
-            "10" => year2017::day10::solve(&aoc_input)?,
-            "11" => year2017::day11::solve(&aoc_input)?,
-            "12" => year2017::day12::solve(&aoc_input)?,
-            "13" => year2017::day13::solve(&aoc_input)?,
-            "19" => year2017::day19::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2018" => match config.day.as_str() {
-            "01" => year2018::day01::solve(&aoc_input)?,
-            "02" => year2018::day02::solve(&aoc_input)?,
-            "03" => year2018::day03::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2019" => match config.day.as_str() {
-            "01" => year2019::day01::solve(&aoc_input)?,
-            "02" => year2019::day02::solve(&aoc_input)?,
-            "03" => year2019::day03::solve(&aoc_input)?,
-            "04" => year2019::day04::solve(&aoc_input)?,
-            "05" => year2019::day05::solve(&aoc_input)?,
-            "06" => year2019::day06::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2020" => match config.day.as_str() {
-            "01" => year2020::day01::solve(&aoc_input)?,
-            "02" => year2020::day02::solve(&aoc_input)?,
-            "03" => year2020::day03::solve(&aoc_input)?,
-            "04" => year2020::day04::solve(&aoc_input)?,
-            "05" => year2020::day05::solve(&aoc_input)?,
-            "06" => year2020::day06::solve(&aoc_input)?,
-            "07" => year2020::day07::solve(&aoc_input)?,
-            "08" => year2020::day08::solve(&aoc_input)?,
-            "09" => year2020::day09::solve(&aoc_input)?,
-            "10" => year2020::day10::solve(&aoc_input)?,
-            "11" => year2020::day11::solve(&aoc_input)?,
-            "12" => year2020::day12::solve(&aoc_input)?,
-            "13" => year2020::day13::solve(&aoc_input)?,
-            "14" => year2020::day14::solve(&aoc_input)?,
-            "15" => year2020::day15::solve(&aoc_input)?,
-            "16" => year2020::day16::solve(&aoc_input)?,
-            "17" => year2020::day17::solve(&aoc_input)?,
-            "18" => year2020::day18::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2021" => match config.day.as_str() {
-            "01" => year2021::day01::solve(&aoc_input)?,
-            "02" => year2021::day02::solve(&aoc_input)?,
-            "03" => year2021::day03::solve(&aoc_input)?,
-            "04" => year2021::day04::solve(&aoc_input)?,
-            "05" => year2021::day05::solve(&aoc_input)?,
-            "06" => year2021::day06::solve(&aoc_input)?,
-            "07" => year2021::day07::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2022" => match config.day.as_str() {
-            "01" => year2022::day01::solve(&aoc_input)?,
-            "02" => year2022::day02::solve(&aoc_input)?,
-            "03" => year2022::day03::solve(&aoc_input)?,
-            "04" => year2022::day04::solve(&aoc_input)?,
-            "05" => year2022::day05::solve(&aoc_input)?,
-            "06" => year2022::day06::solve(&aoc_input)?,
-            "07" => year2022::day07::solve(&aoc_input)?,
-            "08" => year2022::day08::solve(&aoc_input)?,
-            "09" => year2022::day09::solve(&aoc_input)?,
-            "10" => year2022::day10::solve(&aoc_input)?,
-            "11" => year2022::day11::solve(&aoc_input)?,
-            "12" => year2022::day12::solve(&aoc_input)?,
-            "13" => year2022::day13::solve(&aoc_input)?,
-            "14" => year2022::day14::solve(&aoc_input)?,
-            "15" => year2022::day15::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2023" => match config.day.as_str() {
-            "01" => year2023::day01::solve(&aoc_input)?,
-            "02" => year2023::day02::solve(&aoc_input)?,
-            "03" => year2023::day03::solve(&aoc_input)?,
-            "04" => year2023::day04::solve(&aoc_input)?,
-            "05" => year2023::day05::solve(&aoc_input)?,
-            "06" => year2023::day06::solve(&aoc_input)?,
-            "07" => year2023::day07::solve(&aoc_input)?,
-            "08" => year2023::day08::solve(&aoc_input)?,
-            "09" => year2023::day09::solve(&aoc_input)?,
-            "10" => year2023::day10::solve(&aoc_input)?,
-            "11" => year2023::day11::solve(&aoc_input)?,
-            "12" => year2023::day12::solve(&aoc_input)?,
-            "13" => year2023::day13::solve(&aoc_input)?,
-            "14" => year2023::day14::solve(&aoc_input)?,
-            "15" => year2023::day15::solve(&aoc_input)?,
-            "16" => year2023::day16::solve(&aoc_input)?,
-            "17" => year2023::day17::solve(&aoc_input)?,
-            "18" => year2023::day18::solve(&aoc_input)?,
-            "19" => year2023::day19::solve(&aoc_input)?,
-            "20" => year2023::day20::solve(&aoc_input)?,
-            "21" => year2023::day21::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2024" => match config.day.as_str() {
-            "01" => year2024::day01::solve(&aoc_input)?,
-            "02" => year2024::day02::solve(&aoc_input)?,
-            "03" => year2024::day03::solve(&aoc_input)?,
-            "04" => year2024::day04::solve(&aoc_input)?,
-            "05" => year2024::day05::solve(&aoc_input)?,
-            "06" => year2024::day06::solve(&aoc_input)?,
-            "07" => year2024::day07::solve(&aoc_input)?,
-            "08" => year2024::day08::solve(&aoc_input)?,
-            "09" => year2024::day09::solve(&aoc_input)?,
-            "10" => year2024::day10::solve(&aoc_input)?,
-            "11" => year2024::day11::solve(&aoc_input)?,
-            "12" => year2024::day12::solve(&aoc_input)?,
-            "13" => year2024::day13::solve(&aoc_input)?,
-            "14" => year2024::day14::solve(&aoc_input)?,
-            "15" => year2024::day15::solve(&aoc_input)?,
-            "16" => year2024::day16::solve(&aoc_input)?,
-            "17" => year2024::day17::solve(&aoc_input)?,
-            "18" => year2024::day18::solve(&aoc_input)?,
-            "19" => year2024::day19::solve(&aoc_input)?,
-            "20" => year2024::day20::solve(&aoc_input)?,
-            "21" => year2024::day21::solve(&aoc_input)?,
-            "22" => year2024::day22::solve(&aoc_input)?,
-            "23" => year2024::day23::solve(&aoc_input)?,
-            "24" => year2024::day24::solve(&aoc_input)?,
-            "25" => year2024::day25::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        "2025" => match config.day.as_str() {
-            "01" => year2025::day01::solve(&aoc_input)?,
-            "02" => year2025::day02::solve(&aoc_input)?,
-            "03" => year2025::day03::solve(&aoc_input)?,
-            "04" => year2025::day04::solve(&aoc_input)?,
-            "05" => year2025::day05::solve(&aoc_input)?,
-            "06" => year2025::day06::solve(&aoc_input)?,
-            "07" => year2025::day07::solve(&aoc_input)?,
-            "08" => year2025::day08::solve(&aoc_input)?,
-            "09" => year2025::day09::solve(&aoc_input)?,
-            "10" => year2025::day10::solve(&aoc_input)?,
-            "11" => year2025::day11::solve(&aoc_input)?,
-            "12" => year2025::day12::solve(&aoc_input)?,
-            _ => return Err(DAY_ERROR_MSG.into()),
-        },
-        _ => return Err("Year not implemented".into()),
-    };
-    let t = timer.elapsed().as_micros();
-    println!("Part 1: {p1}\nPart 2: {p2}\nCompleted in: {t} μs");
-
-    Ok(())
+    if let (Mode::New(title), Selection::Day(year, day)) = (&config.mode, &config.selection) {
+        return aoc_runner::scaffold::run(*year, *day, title);
+    }
+    if let Mode::Identify(path) = &config.mode {
+        return aoc_runner::shape::run(path);
+    }
+    if let Selection::Day(year, day) = config.selection {
+        let solver = aoc_runner::solver::lookup(year, day)?;
+        if config.watch {
+            return aoc_runner::watch::run(solver, &config);
+        }
+    }
+
+    let solvers: Vec<_> = aoc_runner::solver::all()
+        .filter(|s| config.selection.contains(s))
+        .collect();
+    if let Some(name) = config.params.names().find(|name| {
+        !solvers
+            .iter()
+            .any(|s| s.params.iter().any(|p| p.name == *name))
+    }) {
+        return Err(format!("Unknown parameter `{name}` for the selected puzzles").into());
+    }
+    let solvers = solvers.into_iter();
+    match &config.mode {
+        Mode::Bench(options) => aoc_runner::bench::run(
+            solvers,
+            options,
+            &config.input,
+            config.parts,
+            &config.params,
+            config.timeout,
+        ),
+        Mode::Example(number) => aoc_runner::examples::run(solvers, *number, &config),
+        Mode::Coverage => aoc_runner::coverage::run(solvers, config.format),
+        _ => aoc_runner::batch::run(solvers, &config),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(args: &str) -> Result<Selection, &'static str> {
+        Config::build(args.split_whitespace().map(String::from)).map(|c| c.selection)
+    }
+
+    fn build_mode(args: &str) -> Result<Mode, &'static str> {
+        Config::build(args.split_whitespace().map(String::from)).map(|c| c.mode)
+    }
+
+    #[test]
+    fn selection_args() {
+        assert_eq!(build("aoc 2015 1"), Ok(Selection::Day(2015, 1)));
+        assert_eq!(build("aoc 2015 01"), Ok(Selection::Day(2015, 1)));
+        assert_eq!(build("aoc 2024 1..=12"), Ok(Selection::Days(2024, 1..=12)));
+        assert_eq!(build("aoc 2024 1..12"), Ok(Selection::Days(2024, 1..=11)));
+        assert_eq!(build("aoc 2023"), Ok(Selection::Year(2023)));
+        assert_eq!(build("aoc all"), Ok(Selection::All));
+        assert!(build("aoc").is_err());
+        assert!(build("aoc 2024 x..=3").is_err());
+        assert!(build("aoc 2024 1 2").is_err());
+    }
+
+    #[test]
+    fn input_args() {
+        let build_input =
+            |args: &str| Config::build(args.split_whitespace().map(String::from)).map(|c| c.input);
+        assert_eq!(build_input("aoc 2015 1"), Ok(InputSource::Directory(None)));
+        assert_eq!(build_input("aoc 2015 1 --input -"), Ok(InputSource::Stdin));
+        assert_eq!(
+            build_input("aoc 2015 1 --input a.txt"),
+            Ok(InputSource::File("a.txt".into()))
+        );
+        assert_eq!(
+            build_input("aoc all --input-dir /inputs"),
+            Ok(InputSource::Directory(Some("/inputs".into())))
+        );
+        assert!(build_input("aoc 2015 --input a.txt").is_err());
+    }
+
+    #[test]
+    fn format_args() {
+        let build_format =
+            |args: &str| Config::build(args.split_whitespace().map(String::from)).map(|c| c.format);
+        assert_eq!(build_format("aoc all"), Ok(Format::Text));
+        assert_eq!(build_format("aoc all --format json"), Ok(Format::Json));
+        assert_eq!(build_format("aoc 2015 1 --format csv"), Ok(Format::Csv));
+        assert!(build_format("aoc all --format xml").is_err());
+        assert!(build_format("aoc all --format json --bench 5").is_err());
+        assert_eq!(
+            build_format("aoc all --coverage --format markdown"),
+            Ok(Format::Markdown)
+        );
+        assert!(build_format("aoc all --format markdown").is_err());
+        assert!(build_format("aoc all --coverage --format csv").is_err());
+    }
+
+    #[test]
+    fn part_args() {
+        let build_parts =
+            |args: &str| Config::build(args.split_whitespace().map(String::from)).map(|c| c.parts);
+        assert_eq!(build_parts("aoc 2024 22"), Ok(Parts::Both));
+        assert_eq!(build_parts("aoc 2024 22 --part 1"), Ok(Parts::Part1));
+        assert_eq!(build_parts("aoc 2023 --part 2 --verify"), Ok(Parts::Part2));
+        assert_eq!(build_parts("aoc 2023 --part 2 --bench 5"), Ok(Parts::Part2));
+        assert!(build_parts("aoc 2023 --part 3").is_err());
+        assert!(build_parts("aoc 2023 --part").is_err());
+        assert!(build_parts("aoc 2023 --part 1 --record").is_err());
+        assert!(build_parts("aoc 2023 --part 1 --compare main").is_err());
+    }
+
+    #[test]
+    fn timeout_args() {
+        let build_timeout = |args: &str| {
+            Config::build(args.split_whitespace().map(String::from)).map(|c| c.timeout)
+        };
+        assert_eq!(build_timeout("aoc all"), Ok(Some(Duration::from_secs(60))));
+        assert_eq!(
+            build_timeout("aoc all --timeout 2.5"),
+            Ok(Some(Duration::from_millis(2500)))
+        );
+        assert_eq!(build_timeout("aoc all --timeout 0"), Ok(None));
+        assert!(build_timeout("aoc all --timeout -1").is_err());
+        assert!(build_timeout("aoc all --timeout").is_err());
+    }
+
+    #[test]
+    fn sequential_args() {
+        let build_sequential = |args: &str| {
+            Config::build(args.split_whitespace().map(String::from)).map(|c| c.sequential)
+        };
+        assert_eq!(build_sequential("aoc all"), Ok(false));
+        assert_eq!(build_sequential("aoc all --sequential"), Ok(true));
+    }
+
+    #[test]
+    fn param_args() {
+        let build_params =
+            |args: &str| Config::build(args.split_whitespace().map(String::from)).map(|c| c.params);
+        assert_eq!(build_params("aoc 2024 18"), Ok(Params::default()));
+        assert_eq!(
+            build_params("aoc 2024 18 --param dim=7 --param bytes=12"),
+            "dim=7,bytes=12".parse()
+        );
+        assert_eq!(
+            build_params("aoc 2024 18 --param dim=7,bytes=1 --param bytes=12"),
+            "dim=7,bytes=12".parse()
+        );
+        assert!(build_params("aoc 2024 18 --param dim").is_err());
+        assert!(build_params("aoc 2024 18 --param").is_err());
+        assert!(build_params("aoc 2024 --param dim=7 --record").is_err());
+        assert!(build_params("aoc 2024 --param dim=7 --compare main").is_err());
+    }
+
+    #[test]
+    fn mode_args() {
+        assert_eq!(build_mode("aoc 2015 1"), Ok(Mode::Solve));
+        assert_eq!(build_mode("aoc --verify all"), Ok(Mode::Verify));
+        assert_eq!(build_mode("aoc 2023 --record"), Ok(Mode::Record));
+        assert!(build_mode("aoc 2023 1 --record --input a.txt").is_err());
+        assert!(build_mode("aoc 2023 1 --record --input -").is_err());
+        assert!(build_mode("aoc 2023 --record --input-dir inputs").is_err());
+        assert_eq!(build_mode("aoc all --coverage"), Ok(Mode::Coverage));
+        assert_eq!(
+            build_mode("aoc 2023 --bench 50"),
+            Ok(Mode::Bench(BenchOptions {
+                iterations: 50,
+                baseline: None,
+                threshold: 10.0,
+            }))
+        );
+        assert_eq!(
+            build_mode("aoc all --compare main --threshold 5"),
+            Ok(Mode::Bench(BenchOptions {
+                iterations: 10,
+                baseline: Some("main".to_string()),
+                threshold: 5.0,
+            }))
+        );
+        assert!(build_mode("aoc all --compare main --verify").is_err());
+        assert!(build_mode("aoc 2023 --bench").is_err());
+        assert!(build_mode("aoc 2023 --bench 0").is_err());
+        assert!(build_mode("aoc 2023 --foo").is_err());
+    }
+
+    #[test]
+    fn watch_args() {
+        let build_watch =
+            |args: &str| Config::build(args.split_whitespace().map(String::from)).map(|c| c.watch);
+        assert_eq!(build_watch("aoc 2024 18"), Ok(false));
+        assert_eq!(build_watch("aoc 2024 18 --watch --input ex.txt"), Ok(true));
+        assert!(build_watch("aoc 2024 --watch").is_err());
+        assert!(build_watch("aoc 2024 18 --watch --input -").is_err());
+        assert!(build_watch("aoc 2024 18 --watch --bench 5").is_err());
+        assert!(build_watch("aoc 2024 18 --watch --example").is_err());
+    }
+
+    #[test]
+    fn new_args() {
+        let args = ["aoc", "new", "2026", "3", "Some Title"].map(String::from);
+        let config = Config::build(args.into_iter()).unwrap();
+        assert_eq!(config.selection, Selection::Day(2026, 3));
+        assert_eq!(config.mode, Mode::New("Some Title".to_string()));
+        assert!(build("aoc new 2026 3").is_err());
+        assert!(build("aoc new 2026 1..3 Title").is_err());
+        assert!(build("aoc new 2026 3 Title --verify").is_err());
+    }
+
+    #[test]
+    fn identify_args() {
+        assert_eq!(
+            build_mode("aoc identify input.txt"),
+            Ok(Mode::Identify("input.txt".into()))
+        );
+        assert_eq!(build("aoc identify input.txt"), Ok(Selection::All));
+        assert!(build("aoc identify").is_err());
+        assert!(build("aoc identify a.txt b.txt").is_err());
+        assert!(build("aoc identify a.txt --record").is_err());
+    }
+
+    #[test]
+    fn example_args() {
+        assert_eq!(build_mode("aoc 2024 18 --example"), Ok(Mode::Example(None)));
+        assert_eq!(
+            build_mode("aoc 2024 18 --example 2"),
+            Ok(Mode::Example(Some(2)))
+        );
+        assert_eq!(build("aoc --example 2024 18"), Ok(Selection::Day(2024, 18)));
+        assert_eq!(build("aoc --example 1 2024"), Ok(Selection::Year(2024)));
+        assert!(build_mode("aoc 2024 18 --example 0").is_err());
+        assert!(build_mode("aoc 2024 18 --example --input a.txt").is_err());
+        assert!(build_mode("aoc 2024 --example --format json").is_err());
+        assert!(build_mode("aoc 2024 --example --bench 5").is_err());
+    }
 }
@@ -1,20 +1,47 @@
 //! # 2015 day 1 - Not Quite Lisp
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("0"),
+        Example::new(EXAMPLE_2).part1("0"),
+        Example::new(EXAMPLE_3).part1("3"),
+        Example::new(EXAMPLE_4).part1("3"),
+        Example::new(EXAMPLE_5).part1("3"),
+        Example::new(EXAMPLE_6).part1("-1"),
+        Example::new(EXAMPLE_7).part1("-1"),
+        Example::new(EXAMPLE_8).part1("-3"),
+        Example::new(EXAMPLE_9).part1("-3"),
+        Example::new(EXAMPLE_10).part2("1"),
+        Example::new(EXAMPLE_11).part2("5"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[()]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     directions: Vec<isize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             directions: s
@@ -48,83 +75,35 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "(())";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_1: &str = "(())";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "()()";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_2: &str = "()()";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "(((";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_3: &str = "(((";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "(()(()(";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_4: &str = "(()(()(";
 
-    #[test]
-    fn part1_example_5() {
-        let testdata = "))(((((";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_5: &str = "))(((((";
 
-    #[test]
-    fn part1_example_6() {
-        let testdata = "())";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), -1);
-    }
+const EXAMPLE_6: &str = "())";
 
-    #[test]
-    fn part1_example_7() {
-        let testdata = "))(";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), -1);
-    }
+const EXAMPLE_7: &str = "))(";
 
-    #[test]
-    fn part1_example_8() {
-        let testdata = ")))";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), -3);
-    }
+const EXAMPLE_8: &str = ")))";
 
-    #[test]
-    fn part1_example_9() {
-        let testdata = ")())())";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), -3);
-    }
+const EXAMPLE_9: &str = ")())())";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = ")";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_10: &str = ")";
+
+const EXAMPLE_11: &str = "()())";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let testdata = "()())";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 5);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,37 +1,52 @@
 //! # 2015 day 2 - I Was Told There Would Be No Math
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("58").part2("34"),
+        Example::new(EXAMPLE_2).part1("43").part2("14"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}x{int}x{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     gifts: Vec<(usize, usize, usize)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            gifts: s
-                .lines()
-                .map(|line| {
-                    let mut sides = line
-                        .split('x')
-                        .map(|digit| digit.parse::<usize>().unwrap())
-                        .collect::<Vec<_>>();
-                    sides.sort_unstable();
-                    if sides.len() != 3 {
-                        (0, 0, 0)
-                    } else {
-                        (sides[0], sides[1], sides[2])
-                    }
-                })
-                .collect(),
+            gifts: parse::lines(s, |line| {
+                let mut sides = line
+                    .split('x')
+                    .map(parse::number::<usize>)
+                    .collect::<Result<Vec<_>, _>>()?;
+                sides.sort_unstable();
+                if sides.len() != 3 {
+                    Err(ParseError::new("expected three sides `LxWxH`", line))
+                } else {
+                    Ok((sides[0], sides[1], sides[2]))
+                }
+            })?,
         })
     }
 }
@@ -52,34 +67,17 @@ impl InputData {
     }
 }
 
+const EXAMPLE_1: &str = "2x3x4";
+
+const EXAMPLE_2: &str = "1x1x10";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "2x3x4";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 58);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let testdata = "1x1x10";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 43);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let testdata = "2x3x4";
-        let solution_data = InputData::from_str(&testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 34);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let testdata = "1x1x10";
-        let solution_data = InputData::from_str(&testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 14);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,21 +1,42 @@
 //! # 2015 day 3 - Perfectly Spherical Houses in a Vacuum
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
-use std::{collections::HashSet, error::Error, str::FromStr};
-
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+use crate::aoc_util::sparse_grid::SparseGrid;
+use std::{error::Error, str::FromStr};
+
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2"),
+        Example::new(EXAMPLE_2).part1("4").part2("3"),
+        Example::new(EXAMPLE_3).part1("2").part2("11"),
+        Example::new(EXAMPLE_4).part2("3"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[<>^v]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     steps: Vec<Point>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             steps: s
@@ -32,76 +53,57 @@ impl FromStr for InputData {
     }
 }
 
+/// Delivers a present to the house in point `p`.
+fn deliver(presents: &mut SparseGrid<u32>, p: &Point) {
+    presents.set_point(p, presents.get_element(p) + 1);
+}
+
 impl InputData {
     fn solve_part1(&self) -> usize {
         let mut santa = ORIGIN;
-        let mut visited = HashSet::from([santa]);
+        let mut presents = SparseGrid::new(0);
+        deliver(&mut presents, &santa);
         for step in &self.steps {
             santa += *step;
-            visited.insert(santa);
+            deliver(&mut presents, &santa);
         }
-        visited.len()
+        presents.len()
     }
 
     fn solve_part2(&self) -> usize {
         let mut santa = ORIGIN;
         let mut robosanta = ORIGIN;
-        let mut visited = HashSet::from([santa]);
+        let mut presents = SparseGrid::new(0);
+        deliver(&mut presents, &santa);
+        deliver(&mut presents, &robosanta);
         for (count, step) in self.steps.iter().enumerate() {
             if count % 2 == 0 {
                 santa += *step;
-                visited.insert(santa);
+                deliver(&mut presents, &santa);
             } else {
                 robosanta += *step;
-                visited.insert(robosanta);
+                deliver(&mut presents, &robosanta);
             }
         }
-        visited.len()
+        presents.len()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = ">";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+const EXAMPLE_1: &str = ">";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "^>v<";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
+const EXAMPLE_2: &str = "^>v<";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "^v^v^v^v^v";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+const EXAMPLE_3: &str = "^v^v^v^v^v";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "^v";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 3);
-    }
+const EXAMPLE_4: &str = "^v";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "^>v<";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 3);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_3() {
-        let testdata = "^v^v^v^v^v";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 11);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,21 +1,39 @@
 //! # 2015 day 4 - The Ideal Stocking Stuffer
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use md5::{Digest, Md5};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("609043"),
+        Example::new(EXAMPLE_2).part1("1048970"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     secret_key: &'a str,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self { secret_key: s })
     }
@@ -44,20 +62,17 @@ impl InputData<'_> {
     }
 }
 
+const EXAMPLE_1: &str = "abcdef";
+
+const EXAMPLE_2: &str = "pqrstuv";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "abcdef";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 609043);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_2() {
-        let testdata = "pqrstuv";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1048970);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,20 +1,45 @@
 //! # 2015 day 5 - Doesn't He Have Intern-Elves For This?
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1"),
+        Example::new(EXAMPLE_2).part1("1"),
+        Example::new(EXAMPLE_3).part1("0"),
+        Example::new(EXAMPLE_4).part1("0"),
+        Example::new(EXAMPLE_5).part1("0"),
+        Example::new(EXAMPLE_6).part2("1"),
+        Example::new(EXAMPLE_7).part2("1"),
+        Example::new(EXAMPLE_8).part2("0"),
+        Example::new(EXAMPLE_9).part2("0"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     santa_strings: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
             santa_strings: s.lines().collect(),
@@ -79,69 +104,31 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "ugknbfddgicrmopn";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_1: &str = "ugknbfddgicrmopn";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "aaa";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_2: &str = "aaa";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "jchzalrnumimnmhp";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_3: &str = "jchzalrnumimnmhp";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "haegwjzuvuyypxyu";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_4: &str = "haegwjzuvuyypxyu";
 
-    #[test]
-    fn part1_example_5() {
-        let testdata = "dvszwmarrgswjxmb";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_5: &str = "dvszwmarrgswjxmb";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "qjhvhtzxzqqjkmpb";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_6: &str = "qjhvhtzxzqqjkmpb";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "xxyxx";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_7: &str = "xxyxx";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "uurcxstgmygtbstg";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
-    }
+const EXAMPLE_8: &str = "uurcxstgmygtbstg";
+
+const EXAMPLE_9: &str = "ieodomkazucvgmuy";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_4() {
-        let testdata = "ieodomkazucvgmuy";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
+    fn examples() {
+        check::<InputData>();
     }
 }
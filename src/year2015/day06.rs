@@ -1,16 +1,41 @@
 //! # 2015 day 6 - Probably a Fire Hazard
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     cmp::{max, min},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1000000"),
+        Example::new(EXAMPLE_2).part1("1000"),
+        Example::new(EXAMPLE_3).part1("999996"),
+        Example::new(EXAMPLE_4).part2("1"),
+        Example::new(EXAMPLE_5).part2("2000000"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&[
+        "turn on {int},{int} through {int},{int}",
+        "turn off {int},{int} through {int},{int}",
+        "toggle {int},{int} through {int},{int}",
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -21,13 +46,16 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.chars().nth(6) {
-            Some('n') => Ok(Self::TurnOn),
-            Some('f') => Ok(Self::TurnOff),
-            Some(' ') => Ok(Self::Toggle),
-            _ => panic!("Can't map operation"),
+        match s {
+            "turn on" => Ok(Self::TurnOn),
+            "turn off" => Ok(Self::TurnOff),
+            "toggle" => Ok(Self::Toggle),
+            _ => Err(ParseError::new(
+                "expected `turn on`, `turn off` or `toggle`",
+                s,
+            )),
         }
     }
 }
@@ -39,9 +67,18 @@ struct Area {
 }
 
 impl FromStr for Area {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nbrs: Vec<usize> = s.split([' ', ',']).filter_map(|s| s.parse().ok()).collect();
+        let (from, to) = parse::split_once(s, " through ")?;
+        let (x0, y0) = parse::split_once(from, ",")?;
+        let (x1, y1) = parse::split_once(to, ",")?;
+        let nbrs: Vec<usize> = [x0, y0, x1, y1]
+            .into_iter()
+            .map(|s| match parse::number(s)? {
+                n if n < GRIDSIZE => Ok(n),
+                _ => Err(ParseError::new("expected a number below 1000", s)),
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             x_range: (min(nbrs[0], nbrs[2]), max(nbrs[0], nbrs[2])),
             y_range: (min(nbrs[1], nbrs[3]), max(nbrs[1], nbrs[3])),
@@ -55,26 +92,31 @@ struct Instruction {
     area: Area,
 }
 
-impl Instruction {
-    fn from(line: &str) -> Self {
-        Self {
-            op: Operation::from_str(line).unwrap(),
-            area: Area::from_str(line).unwrap(),
-        }
+impl FromStr for Instruction {
+    type Err = ParseError;
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let area_start = line
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(|| ParseError::missing("missing area", line))?;
+        let (op, area) = line.split_at(area_start);
+        Ok(Self {
+            op: Operation::from_str(op.trim_end())?,
+            area: Area::from_str(area)?,
+        })
     }
 }
 
 const GRIDSIZE: usize = 1000;
 
-struct InputData {
+pub struct InputData {
     santa_instructions: Vec<Instruction>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            santa_instructions: s.lines().map(Instruction::from).collect(),
+            santa_instructions: parse::lines(s, Instruction::from_str)?,
         })
     }
 }
@@ -129,41 +171,23 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "turn on 0,0 through 999,999";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1000 * 1000);
-    }
+const EXAMPLE_1: &str = "turn on 0,0 through 999,999";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "toggle 0,0 through 999,0";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1000);
-    }
+const EXAMPLE_2: &str = "toggle 0,0 through 999,0";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "turn on 0,0 through 999,999\nturn off 499,499 through 500,500";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1000 * 1000 - 4);
-    }
+const EXAMPLE_3: &str = "turn on 0,0 through 999,999\nturn off 499,499 through 500,500";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "turn on 0,0 through 0,0";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_4: &str = "turn on 0,0 through 0,0";
+
+const EXAMPLE_5: &str = "toggle 0,0 through 999,999";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let testdata = "toggle 0,0 through 999,999";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2000000);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -5,99 +5,167 @@
 //! Can possibly be improved by removing duplicated code for extracting the node
 //! values in get_values(). Maybe convert the strings to usize values to use as
 //! keys instead to avoid having to deal with strings?
-use std::{collections::HashMap, error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{
+    cell::OnceCell,
+    collections::{HashMap, HashSet},
+    error::Error,
+};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("72")];
+
+    const SHAPE: Shape = Shape::Lines(&[
+        "{word} -> {word}",
+        "NOT {word} -> {word}",
+        "{word} AND {word} -> {word}",
+        "{word} OR {word} -> {word}",
+        "{word} LSHIFT {int} -> {word}",
+        "{word} RSHIFT {int} -> {word}",
+    ]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.signal_a() as usize
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.override_b() as usize
+    }
 }
 
-enum Node {
-    Wire(String),
+enum Node<'a> {
+    Wire(&'a str),
     Number(u16),
 }
 
-impl FromStr for Node {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         if let Ok(v) = s.parse::<u16>() {
             Ok(Self::Number(v))
+        } else if !s.is_empty() && s.bytes().all(|b| b.is_ascii_lowercase()) {
+            Ok(Self::Wire(s))
         } else {
-            Ok(Self::Wire(s.to_string()))
+            Err(ParseError::new("expected a wire or a signal", s))
         }
     }
 }
 
-enum Gate {
-    Plain(Node),
-    Not(Node),
-    And(Node, Node),
-    Or(Node, Node),
-    Lshift(Node, u8),
-    Rshift(Node, u8),
+enum Gate<'a> {
+    Plain(Node<'a>),
+    Not(Node<'a>),
+    And(Node<'a>, Node<'a>),
+    Or(Node<'a>, Node<'a>),
+    Lshift(Node<'a>, u8),
+    Rshift(Node<'a>, u8),
 }
 
-impl FromStr for Gate {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl<'a> TryFrom<&'a str> for Gate<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let shift = |s| match parse::number(s)? {
+            n if n < 16 => Ok(n),
+            _ => Err(ParseError::new("expected a shift below 16", s)),
+        };
         let mut tokens = s.split_whitespace();
-        let first = tokens.next().unwrap();
+        let first = parse::next(&mut tokens, "input", s)?;
         if let Some(second) = tokens.next() {
             if let Some(third) = tokens.next() {
                 match second {
-                    "AND" => Ok(Self::And(
-                        Node::from_str(first).unwrap(),
-                        Node::from_str(third).unwrap(),
-                    )),
-                    "OR" => Ok(Self::Or(
-                        Node::from_str(first).unwrap(),
-                        Node::from_str(third).unwrap(),
+                    "AND" => Ok(Self::And(Node::try_from(first)?, Node::try_from(third)?)),
+                    "OR" => Ok(Self::Or(Node::try_from(first)?, Node::try_from(third)?)),
+                    "LSHIFT" => Ok(Self::Lshift(Node::try_from(first)?, shift(third)?)),
+                    "RSHIFT" => Ok(Self::Rshift(Node::try_from(first)?, shift(third)?)),
+                    _ => Err(ParseError::new(
+                        "expected `AND`, `OR`, `LSHIFT` or `RSHIFT`",
+                        second,
                     )),
-                    "LSHIFT" => Ok(Self::Lshift(
-                        Node::from_str(first).unwrap(),
-                        third.parse().unwrap(),
-                    )),
-                    "RSHIFT" => Ok(Self::Rshift(
-                        Node::from_str(first).unwrap(),
-                        third.parse().unwrap(),
-                    )),
-                    _ => unreachable!(),
                 }
+            } else if first == "NOT" {
+                Ok(Self::Not(Node::try_from(second)?))
             } else {
-                Ok(Self::Not(Node::from_str(second).unwrap()))
+                Err(ParseError::new("expected `NOT`", first))
             }
         } else {
-            Ok(Self::Plain(Node::from_str(first).unwrap()))
+            Ok(Self::Plain(Node::try_from(first)?))
         }
     }
 }
 
-struct InputData {
-    circuit: HashMap<String, Gate>,
+impl<'a> Gate<'a> {
+    /// Returns the wires connected to the inputs of the gate.
+    fn inputs(&self) -> impl Iterator<Item = &'a str> {
+        let (a, b) = match self {
+            Gate::And(a, b) | Gate::Or(a, b) => (a, Some(b)),
+            Gate::Plain(a) | Gate::Not(a) | Gate::Lshift(a, _) | Gate::Rshift(a, _) => (a, None),
+        };
+        [Some(a), b].into_iter().flatten().filter_map(|n| match n {
+            Node::Wire(w) => Some(*w),
+            Node::Number(_) => None,
+        })
+    }
+}
+
+pub struct InputData<'a> {
+    circuit: HashMap<&'a str, Gate<'a>>,
+    signal_a: OnceCell<u16>,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl<'a> TryFrom<&'a str> for InputData<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let circuit: HashMap<_, _> = parse::lines(s, |line| {
+            let (left, right) = parse::split_once(line, " -> ")?;
+            Ok((right, Gate::try_from(left)?))
+        })?
+        .into_iter()
+        .collect();
+        if !circuit.contains_key("a") {
+            return Err(ParseError::missing("missing wire `a`", s));
+        }
+        // Every wire must get its signal from wires which eventually get theirs from a number
+        let mut resolved = HashSet::new();
+        let mut unresolved: Vec<_> = circuit.iter().collect();
+        loop {
+            let before = unresolved.len();
+            for (wire, gate) in &unresolved {
+                if let Some(input) = gate.inputs().find(|w| !circuit.contains_key(w)) {
+                    return Err(ParseError::new("unknown wire", input));
+                }
+                if gate.inputs().all(|w| resolved.contains(w)) {
+                    resolved.insert(**wire);
+                }
+            }
+            unresolved.retain(|(wire, _)| !resolved.contains(*wire));
+            match unresolved.first() {
+                None => break,
+                Some((wire, _)) if unresolved.len() == before => {
+                    return Err(ParseError::new("the wire is connected in a loop", wire));
+                }
+                _ => {}
+            }
+        }
         Ok(Self {
-            circuit: s
-                .lines()
-                .map(|line| {
-                    let (left, right) = line.split_once(" -> ").unwrap();
-                    (right.to_string(), Gate::from_str(left).unwrap())
-                })
-                .collect(),
+            circuit,
+            signal_a: OnceCell::new(),
         })
     }
 }
 
-impl InputData {
-    fn get_value(&self, v: &str, wires: &mut HashMap<String, u16>) -> u16 {
+impl<'a> InputData<'a> {
+    fn get_value(&self, v: &'a str, wires: &mut HashMap<&'a str, u16>) -> u16 {
         if let Some(w) = wires.get(v) {
             *w
         } else {
-            let result = match self.circuit.get(v).unwrap() {
+            let result = match &self.circuit[v] {
                 Gate::Plain(a) => match a {
                     Node::Number(n) => *n,
                     Node::Wire(w) => self.get_value(w, wires),
@@ -146,27 +214,27 @@ impl InputData {
                     left >> b
                 }
             };
-            wires.insert(v.to_string(), result);
+            wires.insert(v, result);
             result
         }
     }
 
-    fn solve(&self) -> (usize, usize) {
-        let mut wires = HashMap::new();
-        let p1 = self.get_value("a", &mut wires);
-        wires.clear();
-        wires.insert("b".to_string(), p1);
-        let p2 = self.get_value("a", &mut wires);
-        (p1 as usize, p2 as usize)
+    /// Returns the signal on wire `a`, which part 2 also starts from.
+    fn signal_a(&self) -> u16 {
+        *self
+            .signal_a
+            .get_or_init(|| self.get_value("a", &mut HashMap::new()))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns the signal on wire `a` after overriding wire `b` with the original signal of `a`.
+    fn override_b(&self) -> u16 {
+        let mut wires = HashMap::from([("b", self.signal_a())]);
+        self.get_value("a", &mut wires)
+    }
+}
 
-    // Note: changed d->a in the example input to get it to match the actual target
-    const TEST_DATA: &str = "123 -> x
+// Note: changed d->a in the example input to get it to match the actual target
+const EXAMPLE: &str = "123 -> x
 456 -> y
 x AND y -> a
 x OR y -> e
@@ -175,10 +243,13 @@ y RSHIFT 2 -> g
 NOT x -> h
 NOT y -> i";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 72);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -9,29 +9,64 @@
 //! ## Part 2
 //!
 //! Count the characters that needs to be escaped.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const SHAPE: Shape = Shape::Lines(&["\"{any}\""]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     literals: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
-            literals: s.lines().collect(),
+            literals: parse::lines(s, check_literal)?,
         })
     }
 }
 
+/// Returns `literal` if it is a double quoted string of ASCII characters, where a double quote or
+/// backslash inside is escaped by a backslash and any other character may be escaped as `\xHH`.
+fn check_literal(literal: &str) -> Result<&str, ParseError> {
+    let inner = literal
+        .strip_prefix('"')
+        .and_then(|l| l.strip_suffix('"'))
+        .ok_or_else(|| ParseError::new("expected a double quoted string", literal))?;
+    let mut rest = inner;
+    while let Some(i) = rest.find(['"', '\\']) {
+        rest = &rest[i..];
+        let escaped = match rest.as_bytes() {
+            [b'\\', b'"' | b'\\', ..] => 2,
+            [b'\\', b'x', h1, h2, ..] if h1.is_ascii_hexdigit() && h2.is_ascii_hexdigit() => 4,
+            _ => return Err(ParseError::new("expected an escape sequence", rest)),
+        };
+        rest = &rest[escaped..];
+    }
+    if !literal.is_ascii() {
+        return Err(ParseError::new("expected ASCII characters", literal));
+    }
+    Ok(literal)
+}
+
 impl InputData<'_> {
     fn solve_part1(&self) -> usize {
         let mut count = 0;
@@ -1,28 +1,48 @@
 //! # 2015 day 9 - All in a Single Night
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use itertools::Itertools;
-use std::{collections::HashMap, error::Error, str::FromStr};
+use std::{cell::OnceCell, collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("605").part2("982")];
+
+    const SHAPE: Shape = Shape::Lines(&["{word} to {word} = {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve()
+            .map_or(Answer::NotApplicable, |(shortest, _)| shortest.into())
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve()
+            .map_or(Answer::NotApplicable, |(_, longest)| longest.into())
+    }
 }
 
-struct InputData {
+pub struct InputData {
     cities: HashMap<String, usize>,
     distances: HashMap<(usize, usize), usize>,
+    route_lengths: OnceCell<Option<(usize, usize)>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut distances: HashMap<(usize, usize), usize> = HashMap::new();
         let mut cities: HashMap<String, usize> = HashMap::new();
         for line in s.lines() {
-            let tokens: Vec<&str> = line.split_whitespace().collect();
-            let f = tokens[0].to_string();
-            let t = tokens[2].to_string();
-            let d: usize = tokens[4].parse().unwrap();
+            let (f, rest) = parse::split_once(line, " to ")?;
+            let (t, d) = parse::split_once(rest, " = ")?;
+            let (f, t) = (f.to_string(), t.to_string());
+            let d: usize = parse::number(d)?;
             let mut len = cities.len();
             if !cities.contains_key(&f) {
                 cities.insert(f.clone(), len);
@@ -31,53 +51,58 @@ impl FromStr for InputData {
             if !cities.contains_key(&t) {
                 cities.insert(t.clone(), len);
             }
-            distances.insert((*cities.get(&f).unwrap(), *cities.get(&t).unwrap()), d);
-            distances.insert((*cities.get(&t).unwrap(), *cities.get(&f).unwrap()), d);
+            distances.insert((cities[&f], cities[&t]), d);
+            distances.insert((cities[&t], cities[&f]), d);
         }
-        Ok(Self { cities, distances })
+        Ok(Self {
+            cities,
+            distances,
+            route_lengths: OnceCell::new(),
+        })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut p1 = usize::MAX;
-        let mut p2 = 0;
+    /// Returns the lengths of the shortest and longest routes, which are found in the same pass, or
+    /// `None` if there is no route visiting all cities.
+    fn solve(&self) -> Option<(usize, usize)> {
+        *self.route_lengths.get_or_init(|| {
+            let mut lengths = None;
 
-        // Note: filter first < last to avoid calculating also the reverse route
-        'outer: for route in self
-            .cities
-            .values()
-            .permutations(self.cities.len())
-            .filter(|r| r.first().unwrap() < r.last().unwrap())
-        {
-            let mut newlen = 0;
-            for i in 0..route.len() - 1 {
-                if let Some(d) = self.distances.get(&(*route[i], *route[i + 1])) {
-                    newlen += *d;
-                } else {
-                    continue 'outer;
+            // Note: filter first < last to avoid calculating also the reverse route
+            'outer: for route in self
+                .cities
+                .values()
+                .permutations(self.cities.len())
+                .filter(|r| r.first() < r.last())
+            {
+                let mut newlen = 0;
+                for pair in route.windows(2) {
+                    if let Some(d) = self.distances.get(&(*pair[0], *pair[1])) {
+                        newlen += *d;
+                    } else {
+                        continue 'outer;
+                    }
                 }
+                let (p1, p2) = lengths.unwrap_or((usize::MAX, 0));
+                lengths = Some((p1.min(newlen), p2.max(newlen)));
             }
-            p1 = p1.min(newlen);
-            p2 = p2.max(newlen);
-        }
-        (p1, p2)
+            lengths
+        })
     }
 }
 
+const EXAMPLE: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "London to Dublin = 464
-London to Belfast = 518
-Dublin to Belfast = 141";
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 605);
-        assert_eq!(p2, 982);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,28 +1,63 @@
 //! # 2015 day 10 - Elves Look, Elves Say
 //!
 //! Straight transfer from my python solution => brute force
+//!
+//! The number of rounds of part 1 is the parameter `rounds`, so the examples can be run with the
+//! single round given in the puzzle description.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const PARAMS: &'static [Param] = &[Param::new("rounds", "40")];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2").params("rounds=1"),
+        Example::new(EXAMPLE_2).part1("2").params("rounds=1"),
+        Example::new(EXAMPLE_3).part1("4").params("rounds=1"),
+        Example::new(EXAMPLE_4).part1("6").params("rounds=1"),
+        Example::new(EXAMPLE_5).part1("6").params("rounds=1"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &'a str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("rounds")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.get_generated_length(self.rounds)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     start_numbers: &'a str,
+    rounds: usize,
 }
 
-impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { start_numbers: s })
+impl<'a> InputData<'a> {
+    fn parse_input(input: &'a str, rounds: usize) -> Result<Self, ParseError> {
+        if let Some(i) = input.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseError::new("expected a digit", &input[i..]));
+        }
+        Ok(Self {
+            start_numbers: input,
+            rounds,
+        })
     }
-}
 
-impl<'a> InputData<'a> {
     fn get_generated_length(&self, rounds: usize) -> usize {
         let mut sequence = self.start_numbers.to_string();
         for _ in 0..rounds {
@@ -48,51 +83,28 @@ impl<'a> InputData<'a> {
         sequence.len()
     }
 
-    fn solve_part1(&self) -> usize {
-        self.get_generated_length(40)
-    }
-
     fn solve_part2(&self) -> usize {
         self.get_generated_length(50)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "1";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "1";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.get_generated_length(1), 2);
-    }
+const EXAMPLE_2: &str = "11";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "11";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.get_generated_length(1), 2);
-    }
+const EXAMPLE_3: &str = "21";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "21";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.get_generated_length(1), 4);
-    }
+const EXAMPLE_4: &str = "1211";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "1211";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.get_generated_length(1), 6);
-    }
+const EXAMPLE_5: &str = "111221";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_5() {
-        let testdata = "111221";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.get_generated_length(1), 6);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -6,35 +6,69 @@
 //! There could be optimizations made to increment in larger interval chunks by evaluating
 //! the current password more intelligently. But the current solution is still decently fast
 //! on modern hardware.
-use std::{collections::HashSet, error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use std::{cell::OnceCell, collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1, p2))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("abcdffaa"),
+        Example::new(EXAMPLE_2).part1("ghjaabcc"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        to_string(self.next_password())
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        let after_next = get_next_password(self.next_password());
+        to_string(&next_valid_password(after_next))
+    }
 }
 
 const OFFSET_VAL: u8 = b'a';
 const RANGE_VAL: u8 = b'z' - OFFSET_VAL;
 const FORBIDDEN_VALS: [u8; 3] = [b'i' - OFFSET_VAL, b'l' - OFFSET_VAL, b'o' - OFFSET_VAL];
 
-struct InputData {
+pub struct InputData {
     current_password: Vec<u8>,
+    next_password: OnceCell<Vec<u8>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.chars().any(|c| !c.is_ascii_lowercase()) {
-            Err(())
+        if let Some(i) = s.find(|c: char| !c.is_ascii_lowercase()) {
+            Err(ParseError::new("expected a lowercase letter", &s[i..]))
+        } else if s.len() < 5 {
+            // Shorter passwords can't hold both a straight of three letters and two pairs
+            Err(ParseError::new("expected at least 5 letters", s))
         } else {
             Ok(Self {
-                current_password: s.chars().map(|c| c as u8 - OFFSET_VAL).collect(),
+                current_password: from_string(s),
+                next_password: OnceCell::new(),
             })
         }
     }
 }
 
+impl InputData {
+    /// Returns the next valid password, which part 2 continues from.
+    fn next_password(&self) -> &[u8] {
+        self.next_password
+            .get_or_init(|| next_valid_password(self.current_password.clone()))
+    }
+}
+
 /// Evaluates a password to see if it fulfills the validity conditions.
 fn is_password_valid(pwd: &[u8]) -> bool {
     if pwd.iter().any(|v| FORBIDDEN_VALS.contains(v)) {
@@ -81,26 +115,35 @@ fn get_next_password(pwd: &[u8]) -> Vec<u8> {
     }
 }
 
-impl InputData {
-    fn solve(&self) -> (String, String) {
-        let mut pwd1 = self.current_password.clone();
-        while !is_password_valid(&pwd1) {
-            pwd1 = get_next_password(&pwd1);
-        }
-        let mut pwd2 = get_next_password(&pwd1);
-        while !is_password_valid(&pwd2) {
-            pwd2 = get_next_password(&pwd2);
-        }
-        (
-            pwd1.iter().map(|v| (v + OFFSET_VAL) as char).collect(),
-            pwd2.iter().map(|v| (v + OFFSET_VAL) as char).collect(),
-        )
+/// Returns the first valid password, starting from `pwd` itself.
+fn next_valid_password(mut pwd: Vec<u8>) -> Vec<u8> {
+    while !is_password_valid(&pwd) {
+        pwd = get_next_password(&pwd);
     }
+    pwd
+}
+
+fn from_string(s: &str) -> Vec<u8> {
+    s.bytes().map(|c| c - OFFSET_VAL).collect()
 }
 
+fn to_string(pwd: &[u8]) -> String {
+    pwd.iter().map(|v| (v + OFFSET_VAL) as char).collect()
+}
+
+const EXAMPLE_1: &str = "abcdefgh";
+
+const EXAMPLE_2: &str = "ghijklmn";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
+    }
 
     #[test]
     fn invalid_input() {
@@ -109,42 +152,23 @@ mod tests {
 
         let testdata = "abc{efgh";
         assert!(InputData::from_str(testdata).is_err());
+
+        let testdata = "abcd";
+        assert!(InputData::from_str(testdata).is_err());
     }
 
     #[test]
     fn validate_example_1() {
-        let testdata = "hijklmmn";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert!(!is_password_valid(&solution_data.current_password));
+        assert!(!is_password_valid(&from_string("hijklmmn")));
     }
 
     #[test]
     fn validate_example_2() {
-        let testdata = "abbceffg";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert!(!is_password_valid(&solution_data.current_password));
+        assert!(!is_password_valid(&from_string("abbceffg")));
     }
 
     #[test]
     fn validate_example_3() {
-        let testdata = "abbcegjk";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert!(!is_password_valid(&solution_data.current_password));
-    }
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "abcdefgh";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, "abcdffaa");
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let testdata = "ghijklmn";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, "ghjaabcc");
+        assert!(!is_password_valid(&from_string("abbcegjk")));
     }
 }
@@ -1,29 +1,50 @@
 //! # 2015 day 19 - Medicine for Rudolph
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("4"),
+        Example::new(EXAMPLE_2).part1("7"),
+    ];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{word} => {word}"]),
+        Shape::Lines(&["{word}"]),
+    ]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     replacements: HashMap<String, Vec<String>>,
     molecule: &'a str,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let (r, molecule) = s.split_once("\n\n").unwrap();
+        let (r, molecule) = parse::split_once(s, "\n\n")?;
         let mut replacements: HashMap<String, Vec<String>> = HashMap::new();
         for line in r.lines() {
-            let (left, right) = line.split_once(" => ").unwrap();
+            let (left, right) = parse::split_once(line, " => ")?;
             replacements
                 .entry(left.to_string())
                 .and_modify(|v| v.push(right.to_string()))
@@ -64,29 +85,25 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "H => HO
+const EXAMPLE_1: &str = "H => HO
 H => OH
 O => HH
 
 HOH";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "H => HO
+const EXAMPLE_2: &str = "H => HO
 H => OH
 O => HH
 
 HOHOHO";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
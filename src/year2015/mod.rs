@@ -10,3 +10,20 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 pub mod day19;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2015:
+    1 => day01: "Not Quite Lisp",
+    2 => day02: "I Was Told There Would Be No Math",
+    3 => day03: "Perfectly Spherical Houses in a Vacuum",
+    4 => day04: "The Ideal Stocking Stuffer",
+    5 => day05: "Doesn't He Have Intern-Elves For This?",
+    6 => day06: "Probably a Fire Hazard",
+    7 => day07: "Some Assembly Required",
+    8 => day08: "Matchsticks",
+    9 => day09: "All in a Single Night",
+    10 => day10: "Elves Look, Elves Say",
+    11 => day11: "Corporate Policy",
+    19 => day19: "Medicine for Rudolph",
+}
@@ -1,13 +1,33 @@
 //! # 2016 day 1 - No Time for a Taxicab
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("5"),
+        Example::new(EXAMPLE_2).part1("2"),
+        Example::new(EXAMPLE_3).part1("12"),
+        Example::new(EXAMPLE_4).part2("4"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[LR]{int}", "[LR]{int}, [LR0123456789, ]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum Rotation {
@@ -16,31 +36,33 @@ enum Rotation {
 }
 
 impl FromStr for Rotation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.chars().nth(0) {
-            Some('L') => Ok(Self::Left),
-            Some('R') => Ok(Self::Right),
-            _ => Err(()),
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(ParseError::new("expected L or R", s)),
         }
     }
 }
 
-struct InputData {
+pub struct InputData {
     instructions: Vec<(Rotation, i32)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             instructions: s
                 .split(", ")
                 .map(|word| {
-                    let (left, right) = word.split_at(1);
-                    (Rotation::from_str(left).unwrap(), right.parse().unwrap())
+                    let (left, right) = word
+                        .split_at_checked(1)
+                        .ok_or_else(|| ParseError::new("expected an instruction", word))?;
+                    Ok((Rotation::from_str(left)?, parse::number(right)?))
                 })
-                .collect(),
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -81,34 +103,21 @@ impl InputData {
     }
 }
 
+const EXAMPLE_1: &str = "R2, L3";
+
+const EXAMPLE_2: &str = "R2, R2, R2";
+
+const EXAMPLE_3: &str = "R5, L5, R5, R3";
+
+const EXAMPLE_4: &str = "R8, R4, R4, R8";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "R2, L3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let testdata = "R2, R2, R2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
-
-    #[test]
-    fn part1_example_3() {
-        let testdata = "R5, L5, R5, R3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 12);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let testdata = "R8, R4, R4, R8";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4);
+    fn examples() {
+        check::<InputData>();
     }
 }
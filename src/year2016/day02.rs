@@ -1,10 +1,28 @@
 //! # 2016 day 2 - Bathroom Security
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((solution_data.solve_part1(), solution_data.solve_part2()))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("1985").part2("5DB3")];
+
+    const SHAPE: Shape = Shape::Lines(&["[UDLR]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Point {
@@ -20,12 +38,12 @@ impl Point {
     }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     instructions: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
             instructions: s.lines().collect(),
@@ -36,50 +54,45 @@ impl<'a> TryFrom<&'a str> for InputData<'a> {
 impl InputData<'_> {
     fn get_bathroom_code(&self, keypad: &Grid) -> String {
         let mut result = Vec::new();
-        let mut current = keypad.find('5').unwrap();
+        let mut current = keypad.find(b'5').unwrap();
         for row in &self.instructions {
             for step in row.chars().map(|c| Point::to_dir(&c)) {
                 let next = step + current;
                 if let Some(e) = keypad.get_element(&next)
-                    && e != ' '
+                    && e != b' '
                 {
                     current = next;
                 }
             }
             result.push(keypad.get_element(&current).unwrap());
         }
-        result.iter().collect()
+        result.iter().map(|&c| char::from(c)).collect()
     }
 
     fn solve_part1(&self) -> String {
-        let keypad = Grid::parse("123\n456\n789");
+        let keypad = Grid::parse("123\n456\n789").expect("the keypad is a grid");
         self.get_bathroom_code(&keypad)
     }
 
     fn solve_part2(&self) -> String {
-        let keypad = Grid::parse("  1  \n 234 \n56789\n ABC \n  D  ");
+        let keypad =
+            Grid::parse("  1  \n 234 \n56789\n ABC \n  D  ").expect("the keypad is a grid");
         self.get_bathroom_code(&keypad)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "ULL
+const EXAMPLE: &str = "ULL
 RRDDD
 LURDL
 UUUUD";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), "1985");
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), "5DB3");
+    fn examples() {
+        check::<InputData>();
     }
 }
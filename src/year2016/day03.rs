@@ -1,23 +1,44 @@
 //! # 2016 day 3 - Squares With Three Sides
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("0"),
+        Example::new(EXAMPLE_2).part2("6"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int} {int} {int}", " {int} {int} {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     sides: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            sides: s.split_whitespace().map(|n| n.parse().unwrap()).collect(),
+            sides: s
+                .split_whitespace()
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -59,26 +80,22 @@ fn validate_triangle(sides: &[usize]) -> bool {
         && sides[1] + sides[2] > sides[0]
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "5 10 25";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_1: &str = "5 10 25";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "101 301 501
+const EXAMPLE_2: &str = "101 301 501
 102 302 502
 103 303 503
 201 401 601
 202 402 602
 203 403 603";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
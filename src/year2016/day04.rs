@@ -1,10 +1,27 @@
 //! # 2016 day 4 - Security Through Obscurity
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("1514")];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz-]{int}[[]{word}]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Room<'a> {
@@ -14,15 +31,15 @@ struct Room<'a> {
 }
 
 impl<'a> TryFrom<&'a str> for Room<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         let mut name = Vec::new();
         let mut sector_id = 0;
         let mut checksum = "";
         for part in value.split('-') {
             if part.ends_with(']') {
-                let (left, right) = part.split_once('[').unwrap();
-                sector_id = left.parse().unwrap();
+                let (left, right) = parse::split_once(part, "[")?;
+                sector_id = parse::number(left)?;
                 checksum = right.trim_end_matches(']');
             } else {
                 name.push(part);
@@ -87,51 +104,49 @@ impl Room<'_> {
     }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     rooms: Vec<Room<'a>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
-            rooms: value
-                .lines()
-                .map(|line| Room::try_from(line).unwrap())
-                .collect(),
+            rooms: parse::lines(value, Room::try_from)?,
         })
     }
 }
 
 impl InputData<'_> {
-    fn solve(&self) -> (usize, usize) {
-        let mut p1 = 0;
-        let mut p2 = 0;
+    /// Returns the real rooms, leaving out the decoys.
+    fn real_rooms(&self) -> impl Iterator<Item = &Room<'_>> {
+        self.rooms.iter().filter(|room| room.validate().is_some())
+    }
+
+    fn solve_part1(&self) -> usize {
+        self.real_rooms().map(|room| room.sector_id).sum()
+    }
+
+    fn solve_part2(&self) -> usize {
         const TARGET: [&str; 3] = ["northpole", "object", "storage"];
-        for room in &self.rooms {
-            if let Some(s_id) = room.validate() {
-                p1 += s_id;
-                if room.decode_check(&TARGET) {
-                    p2 = s_id;
-                }
-            }
-        }
-        (p1, p2)
+        self.real_rooms()
+            .find(|room| room.decode_check(&TARGET))
+            .map_or(0, |room| room.sector_id)
     }
 }
 
+const EXAMPLE: &str = "aaaaa-bbb-z-y-x-123[abxyz]
+a-b-c-d-e-f-g-h-987[abcde]
+not-a-real-room-404[oarel]
+totally-real-room-200[decoy]";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let testdata = "aaaaa-bbb-z-y-x-123[abxyz]
-a-b-c-d-e-f-g-h-987[abcde]
-not-a-real-room-404[oarel]
-totally-real-room-200[decoy]";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 1514);
+    fn examples() {
+        check::<InputData>();
     }
 }
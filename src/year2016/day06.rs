@@ -1,18 +1,46 @@
 //! # 2016 day 6 - Signals and Noise
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok(solution_data.solve())
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("easter").part2("advent")];
+
+    const SHAPE: Shape = Shape::Grid("abcdefghijklmnopqrstuvwxyz");
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     codes: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let width = s.lines().next().map_or(0, str::len);
+        for code in s.lines() {
+            if code
+                .bytes()
+                .enumerate()
+                .any(|(i, c)| !c.is_ascii_lowercase() || i >= width)
+            {
+                return Err(ParseError::new("expected a lowercase letter", code));
+            }
+        }
         Ok(Self {
             codes: s.lines().collect(),
         })
@@ -20,38 +48,41 @@ impl<'a> TryFrom<&'a str> for InputData<'a> {
 }
 
 impl InputData<'_> {
-    fn solve(&self) -> (String, String) {
-        let width = self.codes.first().unwrap().len();
+    /// Returns the message made of the most or least common letter in each position. Ties are
+    /// broken by taking the letter later in the alphabet for the most common one, and earlier for
+    /// the least common one.
+    fn decode(&self, most_common: bool) -> String {
+        let width = self.codes.first().map_or(0, |code| code.len());
         let mut counter = vec![vec![0_usize; (1 + b'z' - b'a') as usize]; width];
         for code in &self.codes {
             for (i, c) in code.bytes().enumerate() {
                 counter[i][(c - b'a') as usize] += 1;
             }
         }
-        let mut p1 = Vec::new();
-        let mut p2 = Vec::new();
-        for count in &counter {
-            let mut a: Vec<(usize, u8)> = count
-                .iter()
-                .enumerate()
-                .filter(|(_, n)| **n > 0)
-                .map(|(i, n)| (*n, i as u8))
-                .collect();
-            a.sort();
-            p1.push((a.last().unwrap().1 + b'a') as char);
-            p2.push((a.first().unwrap().1 + b'a') as char);
-        }
-        (p1.iter().collect(), p2.iter().collect())
+        counter
+            .iter()
+            .filter_map(|count| {
+                let letters = (b'a'..=b'z').zip(count).filter(|(_, n)| **n > 0);
+                let letter = if most_common {
+                    letters.max_by_key(|(c, n)| (**n, *c))
+                } else {
+                    letters.min_by_key(|(c, n)| (**n, *c))
+                };
+                letter.map(|(c, _)| char::from(c))
+            })
+            .collect()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part1(&self) -> String {
+        self.decode(true)
+    }
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "eedadn
+    fn solve_part2(&self) -> String {
+        self.decode(false)
+    }
+}
+
+const EXAMPLE: &str = "eedadn
 drvtee
 eandsr
 raavrd
@@ -67,9 +98,14 @@ vntsnd
 vrdear
 dvrsen
 enarar";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, "easter");
-        assert_eq!(p2, "advent");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
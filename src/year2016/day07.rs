@@ -1,12 +1,36 @@
-//! # 2016 day 7 -Internet Protocol Version 7
+//! # 2016 day 7 - Internet Protocol Version 7
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1"),
+        Example::new(EXAMPLE_2).part1("0"),
+        Example::new(EXAMPLE_3).part1("0"),
+        Example::new(EXAMPLE_4).part1("1"),
+        Example::new(EXAMPLE_5).part2("1"),
+        Example::new(EXAMPLE_6).part2("0"),
+        Example::new(EXAMPLE_7).part2("1"),
+        Example::new(EXAMPLE_8).part2("1"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{word}[[]{any}]{word}"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum Ipv7seq<'a> {
@@ -19,7 +43,7 @@ struct Ipv7addr<'a> {
 }
 
 impl<'a> TryFrom<&'a str> for Ipv7addr<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
             seqs: s
@@ -90,18 +114,15 @@ impl Ipv7addr<'_> {
     }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     addresses: Vec<Ipv7addr<'a>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
-            addresses: s
-                .lines()
-                .map(|line| Ipv7addr::try_from(line).unwrap())
-                .collect(),
+            addresses: parse::lines(s, Ipv7addr::try_from)?,
         })
     }
 }
@@ -116,63 +137,29 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "abba[mnop]qrst";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "abba[mnop]qrst";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_2: &str = "abcd[bddb]xyyx";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "abcd[bddb]xyyx";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_3: &str = "aaaa[qwer]tyui";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "aaaa[qwer]tyui";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_4: &str = "ioxxoj[asdfgh]zxcvbn";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "ioxxoj[asdfgh]zxcvbn";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_5: &str = "aba[bab]xyz";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "aba[bab]xyz";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_6: &str = "xyx[xyx]xyx";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "xyx[xyx]xyx";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
-    }
+const EXAMPLE_7: &str = "aaa[kek]eke";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "aaa[kek]eke";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_8: &str = "zazbz[bzb]cdb";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_4() {
-        let testdata = "zazbz[bzb]cdb";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,11 +1,50 @@
 //! # 2016 day 8 - Two-Factor Authentication
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::{grid::*, point::*};
-use std::{error::Error, str::FromStr};
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("width", "50"), Param::new("height", "6")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("6")
+        .part2(".#..#.#\n#.#....\n.#.....")
+        .params("width=7,height=3")];
+
+    const SHAPE: Shape = Shape::Lines(&[
+        "rect {int}x{int}",
+        "rotate column x={int} by {int}",
+        "rotate row y={int} by {int}",
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(
+            input,
+            params.get("width")?,
+            params.get("height")?,
+        )?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.screen()
+            .elements
+            .iter()
+            .filter(|e| **e == b'#')
+            .count()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        Answer::screen(&self.screen().to_string())
+    }
 }
 
 enum Operation {
@@ -15,26 +54,19 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        match tokens.next().unwrap() {
-            "rect" => {
-                let (x, y) = tokens.next().unwrap().split_once('x').unwrap();
-                Ok(Self::Rect(x.parse().unwrap(), y.parse().unwrap()))
-            }
-            "rotate" => {
-                tokens.next();
-                let (a, b) = tokens.next().unwrap().split_once('=').unwrap();
-                tokens.next();
-                let c: u8 = tokens.next().unwrap().parse().unwrap();
-                match a {
-                    "x" => Ok(Self::RotateCol(b.parse().unwrap(), c)),
-                    "y" => Ok(Self::RotateRow(b.parse().unwrap(), c)),
-                    _ => Err(()),
-                }
-            }
-            _ => Err(()),
+        if let Some(size) = s.strip_prefix("rect ") {
+            let (x, y) = parse::split_once(size, "x")?;
+            Ok(Self::Rect(parse::number(x)?, parse::number(y)?))
+        } else if let Some(rotation) = s.strip_prefix("rotate column x=") {
+            let (x, by) = parse::split_once(rotation, " by ")?;
+            Ok(Self::RotateCol(parse::number(x)?, parse::number(by)?))
+        } else if let Some(rotation) = s.strip_prefix("rotate row y=") {
+            let (y, by) = parse::split_once(rotation, " by ")?;
+            Ok(Self::RotateRow(parse::number(y)?, parse::number(by)?))
+        } else {
+            Err(ParseError::new("expected `rect` or `rotate`", s))
         }
     }
 }
@@ -45,20 +77,15 @@ impl Grid {
             Operation::Rect(x_len, y_len) => {
                 for x in 0..*x_len {
                     for y in 0..*y_len {
-                        self.set_point(&Point::new(x as i32, y as i32), '#');
+                        self.set_point(&Point::new(x as i32, y as i32), b'#');
                     }
                 }
             }
             Operation::RotateRow(y, n) => {
                 let mut swapbuf = Vec::with_capacity(self.x_max);
                 for x in 0..self.x_max as i32 {
-                    swapbuf.push(
-                        self.get_element(&Point::new(
-                            (self.x_max as i32 + x - *n as i32) % self.x_max as i32,
-                            *y as i32,
-                        ))
-                        .unwrap(),
-                    );
+                    let from_x = (x - *n as i32).rem_euclid(self.x_max as i32);
+                    swapbuf.push(self.elements[*y as usize * self.x_max + from_x as usize]);
                 }
                 for x in 0..self.x_max as i32 {
                     self.set_point(&Point::new(x, *y as i32), swapbuf[x as usize]);
@@ -67,13 +94,8 @@ impl Grid {
             Operation::RotateCol(x, n) => {
                 let mut swapbuf = Vec::with_capacity(self.y_max);
                 for y in 0..self.y_max as i32 {
-                    swapbuf.push(
-                        self.get_element(&Point::new(
-                            *x as i32,
-                            (self.y_max as i32 + y - *n as i32) % self.y_max as i32,
-                        ))
-                        .unwrap(),
-                    );
+                    let from_y = (y - *n as i32).rem_euclid(self.y_max as i32);
+                    swapbuf.push(self.elements[from_y as usize * self.x_max + *x as usize]);
                 }
                 for y in 0..self.y_max as i32 {
                     self.set_point(&Point::new(*x as i32, y), swapbuf[y as usize]);
@@ -83,52 +105,57 @@ impl Grid {
     }
 }
 
-struct InputData {
+pub struct InputData {
     ops: Vec<Operation>,
     width: usize,
     height: usize,
+    screen: OnceCell<Grid>,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl InputData {
+    fn parse_input(input: &str, width: usize, height: usize) -> Result<Self, ParseError> {
+        let ops = parse::lines(input, |line| {
+            let op = Operation::from_str(line)?;
+            let fits = match op {
+                Operation::Rect(x, y) => x as usize <= width && y as usize <= height,
+                Operation::RotateRow(y, _) => (y as usize) < height,
+                Operation::RotateCol(x, _) => (x as usize) < width,
+            };
+            if !fits {
+                return Err(ParseError::new("outside the screen", line));
+            }
+            Ok(op)
+        })?;
         Ok(Self {
-            ops: s
-                .lines()
-                .map(|line| Operation::from_str(line).unwrap())
-                .collect(),
-            width: 50,
-            height: 6,
+            ops,
+            width,
+            height,
+            screen: OnceCell::new(),
         })
     }
-}
 
-impl InputData {
-    fn solve(&self) -> (usize, String) {
-        let mut screen = Grid::new(self.width, self.height, '.');
-        for op in &self.ops {
-            screen.perform_operation(op);
-        }
-        (
-            screen.elements.iter().filter(|e| **e == '#').count(),
-            "\n".to_string() + &screen.to_string(),
-        )
+    /// Returns the screen after all operations, which both parts read.
+    fn screen(&self) -> &Grid {
+        self.screen.get_or_init(|| {
+            let mut screen = Grid::new(self.width, self.height, b'.');
+            for op in &self.ops {
+                screen.perform_operation(op);
+            }
+            screen
+        })
     }
 }
 
+const EXAMPLE: &str =
+    "rect 3x2\nrotate column x=1 by 1\nrotate row y=0 by 4\nrotate column x=1 by 1";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let testdata =
-            "rect 3x2\nrotate column x=1 by 1\nrotate row y=0 by 4\nrotate column x=1 by 1";
-        let mut solution_data = InputData::from_str(testdata).unwrap();
-        solution_data.width = 7;
-        solution_data.height = 3;
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 6);
-        assert_eq!(p2, "\n.#..#.#\n#.#....\n.#.....\n");
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,143 +1,134 @@
 //! # 2016 day 9 - Explosives in Cyberspace
-use std::error::Error;
-
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{error::Error, str::FromStr};
+
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("6"),
+        Example::new(EXAMPLE_2).part1("7"),
+        Example::new(EXAMPLE_3).part1("9").part2("9"),
+        Example::new(EXAMPLE_4).part1("11"),
+        Example::new(EXAMPLE_5).part1("6"),
+        Example::new(EXAMPLE_6).part1("18").part2("20"),
+        Example::new(EXAMPLE_7).part2("241920"),
+        Example::new(EXAMPLE_8).part2("445"),
+    ];
+
+    const SHAPE: Shape =
+        Shape::Lines(&["[ABCDEFGHIJKLMNOPQRSTUVWXYZ(][ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789()x]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
-    filedata: &'a str,
+pub struct InputData {
+    pieces: Vec<Piece>,
 }
 
-impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { filedata: s })
+impl FromStr for InputData {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            pieces: parse_pieces(s)?,
+        })
     }
 }
 
-impl InputData<'_> {
+impl InputData {
     fn solve_part1(&self) -> usize {
-        get_decompressed_len(self.filedata, false)
+        get_decompressed_len(&self.pieces, false)
     }
 
     fn solve_part2(&self) -> usize {
-        get_decompressed_len(self.filedata, true)
+        get_decompressed_len(&self.pieces, true)
     }
 }
 
-fn get_decompressed_len(filedata: &str, recurse: bool) -> usize {
-    let mut fp = 0;
-    let mut total = 0;
-    while let Some(c) = filedata.chars().nth(fp) {
-        match c {
-            '(' => {
-                fp += 1;
-                let mut marker = Vec::new();
-                while let Some(d) = filedata.chars().nth(fp) {
-                    if d != ')' {
-                        marker.push(d);
-                        fp += 1;
-                    } else {
-                        break;
-                    }
-                }
-                fp += 1;
-                let m = marker.iter().collect::<String>();
-                let (a, b) = m.split_once('x').unwrap();
-                let a: usize = a.parse().unwrap();
-                let b: usize = b.parse().unwrap();
-                if recurse {
-                    total += b * get_decompressed_len(&filedata[fp..fp + a], true);
-                } else {
-                    total += a * b;
-                }
-                fp += a;
-            }
-            _ => {
-                fp += 1;
-                total += 1;
-            }
+/// A piece of the compressed file, either plain text or a marker with the data it repeats.
+enum Piece {
+    Text(usize),
+    Marker {
+        times: usize,
+        len: usize,
+        data: Vec<Piece>,
+    },
+}
+
+fn parse_pieces(s: &str) -> Result<Vec<Piece>, ParseError> {
+    let mut pieces = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        if let Some(marker) = rest.strip_prefix('(') {
+            let (spec, after) = parse::split_once(marker, ")")?;
+            let (len, times) = parse::split_once(spec, "x")?;
+            let len = parse::number(len)?;
+            let data = after.get(..len).ok_or_else(|| {
+                ParseError::new("the marker reaches past the end of the file", rest)
+            })?;
+            pieces.push(Piece::Marker {
+                times: parse::number(times)?,
+                len,
+                data: parse_pieces(data)?,
+            });
+            rest = &after[len..];
+        } else {
+            let text = rest.find('(').unwrap_or(rest.len());
+            pieces.push(Piece::Text(text));
+            rest = &rest[text..];
         }
     }
-    total
+    Ok(pieces)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn get_decompressed_len(pieces: &[Piece], recurse: bool) -> usize {
+    pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Text(len) => *len,
+            Piece::Marker { times, data, .. } if recurse => {
+                times * get_decompressed_len(data, true)
+            }
+            Piece::Marker { times, len, .. } => times * len,
+        })
+        .sum()
+}
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "ADVENT";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 6);
-    }
+const EXAMPLE_1: &str = "ADVENT";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "A(1x5)BC";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
-    }
+const EXAMPLE_2: &str = "A(1x5)BC";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "(3x3)XYZ";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 9);
-    }
+const EXAMPLE_3: &str = "(3x3)XYZ";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "A(2x2)BCD(2x2)EFG";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 11);
-    }
+const EXAMPLE_4: &str = "A(2x2)BCD(2x2)EFG";
 
-    #[test]
-    fn part1_example_5() {
-        let testdata = "(6x1)(1x3)A";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 6);
-    }
+const EXAMPLE_5: &str = "(6x1)(1x3)A";
 
-    #[test]
-    fn part1_example_6() {
-        let testdata = "X(8x2)(3x3)ABCY";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 18);
-    }
+const EXAMPLE_6: &str = "X(8x2)(3x3)ABCY";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "(3x3)XYZ";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 9);
-    }
+const EXAMPLE_7: &str = "(27x12)(20x12)(13x14)(7x10)(1x12)A";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "X(8x2)(3x3)ABCY";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 20);
-    }
+const EXAMPLE_8: &str = "(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "(27x12)(20x12)(13x14)(7x10)(1x12)A";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 241920);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_4() {
-        let testdata = "(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 445);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -6,3 +6,16 @@ pub mod day06;
 pub mod day07;
 pub mod day08;
 pub mod day09;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2016:
+    1 => day01: "No Time for a Taxicab",
+    2 => day02: "Bathroom Security",
+    3 => day03: "Squares With Three Sides",
+    4 => day04: "Security Through Obscurity",
+    6 => day06: "Signals and Noise",
+    7 => day07: "Internet Protocol Version 7",
+    8 => day08: "Two-Factor Authentication",
+    9 => day09: "Explosives in Cyberspace",
+}
@@ -1,23 +1,55 @@
 //! # 2017 day 1 - Inverse Captcha
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("3"),
+        Example::new(EXAMPLE_2).part1("4"),
+        Example::new(EXAMPLE_3).part1("0"),
+        Example::new(EXAMPLE_4).part1("9"),
+        Example::new(EXAMPLE_5).part2("6"),
+        Example::new(EXAMPLE_6).part2("0"),
+        Example::new(EXAMPLE_7).part2("4"),
+        Example::new(EXAMPLE_8).part2("12"),
+        Example::new(EXAMPLE_9).part2("4"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     sequence: Vec<u8>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            sequence: s.chars().map(|c| c.to_digit(10).unwrap() as u8).collect(),
+            sequence: s
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as u8)
+                        .ok_or_else(|| ParseError::new("expected a digit", &s[i..]))
+                })
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -47,69 +79,31 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "1122";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_1: &str = "1122";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "1111";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
+const EXAMPLE_2: &str = "1111";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "1234";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_3: &str = "1234";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "91212129";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 9);
-    }
+const EXAMPLE_4: &str = "91212129";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "1212";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 6);
-    }
+const EXAMPLE_5: &str = "1212";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "1221";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
-    }
+const EXAMPLE_6: &str = "1221";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "123425";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4);
-    }
+const EXAMPLE_7: &str = "123425";
 
-    #[test]
-    fn part2_example_4() {
-        let testdata = "123123";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 12);
-    }
+const EXAMPLE_8: &str = "123123";
+
+const EXAMPLE_9: &str = "12131415";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_5() {
-        let testdata = "12131415";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4);
+    fn examples() {
+        check::<InputData>();
     }
 }
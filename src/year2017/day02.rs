@@ -1,33 +1,54 @@
 //! # 2017 day 2 - Corruption Checksum
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("18"),
+        Example::new(EXAMPLE_2).part2("9"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}[ \t][0123456789 \t]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     spreadheet: Vec<Vec<usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            spreadheet: s
-                .lines()
-                .map(|row| {
-                    let mut values: Vec<_> = row
-                        .split_whitespace()
-                        .map(|nbr| nbr.parse().unwrap())
-                        .collect();
-                    values.sort_unstable();
-                    values
-                })
-                .collect(),
+            spreadheet: parse::lines(s, |row| {
+                let mut values: Vec<_> = row
+                    .split_whitespace()
+                    .map(|v| match parse::number(v)? {
+                        0 => Err(ParseError::new("expected a positive number", v)),
+                        n => Ok(n),
+                    })
+                    .collect::<Result<_, _>>()?;
+                if values.is_empty() {
+                    return Err(ParseError::missing("missing values", row));
+                }
+                values.sort_unstable();
+                Ok(values)
+            })?,
         })
     }
 }
@@ -36,7 +57,7 @@ impl InputData {
     fn solve_part1(&self) -> usize {
         self.spreadheet
             .iter()
-            .map(|row| row.last().unwrap() - row.first().unwrap())
+            .map(|row| row[row.len() - 1] - row[0])
             .sum()
     }
 
@@ -59,20 +80,17 @@ impl InputData {
     }
 }
 
+const EXAMPLE_1: &str = "5 1 9 5\n7 5 3\n2 4 6 8";
+
+const EXAMPLE_2: &str = "5 9 2 8\n9 4 7 3\n3 8 6 5";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "5 1 9 5\n7 5 3\n2 4 6 8";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 18);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let testdata = "5 9 2 8\n9 4 7 3\n3 8 6 5";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 9);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,4 +1,9 @@
 //! # 2017 day 3 - Spiral Memory
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point;
 use std::{
     collections::{HashMap, HashSet},
@@ -6,23 +11,39 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("0"),
+        Example::new(EXAMPLE_2).part1("3"),
+        Example::new(EXAMPLE_3).part1("2"),
+        Example::new(EXAMPLE_4).part1("31"),
+        Example::new(EXAMPLE_5).part2("806"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     puzzle_input: usize,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            puzzle_input: s.parse().unwrap(),
+            puzzle_input: parse::number(s)?,
         })
     }
 }
@@ -72,41 +93,23 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_1: &str = "1";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "12";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_2: &str = "12";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "23";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+const EXAMPLE_3: &str = "23";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "1024";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 31);
-    }
+const EXAMPLE_4: &str = "1024";
+
+const EXAMPLE_5: &str = "750";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let testdata = "750";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 806);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,20 +1,44 @@
 //! # 2017 day 4 - High-Entropy Passphrases
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::HashSet, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1"),
+        Example::new(EXAMPLE_2).part1("0"),
+        Example::new(EXAMPLE_3).part1("1"),
+        Example::new(EXAMPLE_4).part2("1"),
+        Example::new(EXAMPLE_5).part2("0"),
+        Example::new(EXAMPLE_6).part2("1"),
+        Example::new(EXAMPLE_7).part2("1"),
+        Example::new(EXAMPLE_8).part2("0"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz ]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     passphrases: Vec<Vec<&'a str>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
             passphrases: s
@@ -61,62 +85,29 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part1_example_1() {
-        let testdata = "aa bb cc dd ee";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_1: &str = "aa bb cc dd ee";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "aa bb cc dd aa";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_2: &str = "aa bb cc dd aa";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "aa bb cc dd aaa";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_3: &str = "aa bb cc dd aaa";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "abcde fghij";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_4: &str = "abcde fghij";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "abcde xyz ecdab";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
-    }
+const EXAMPLE_5: &str = "abcde xyz ecdab";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "a ab abc abd abf abj";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_6: &str = "a ab abc abd abf abj";
 
-    #[test]
-    fn part2_example_4() {
-        let testdata = "iiii oiii ooii oooi oooo";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_7: &str = "iiii oiii ooii oooi oooo";
+
+const EXAMPLE_8: &str = "oiii ioii iioi iiio";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_5() {
-        let testdata = "oiii ioii iioi iiio";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
+    fn examples() {
+        check::<InputData>();
     }
 }
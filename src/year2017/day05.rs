@@ -1,23 +1,38 @@
 //! # 2017 day 5 - Maze of Twisty Trampolines, All Alike
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("5").part2("10")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     instructions: Vec<i32>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            instructions: s.lines().map(|i| i.parse().unwrap()).collect(),
+            instructions: parse::lines(s, parse::number)?,
         })
     }
 }
@@ -51,21 +66,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "0\n3\n0\n1\n-3";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "0\n3\n0\n1\n-3";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 10);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,42 +1,63 @@
 //! # 2017 day 6 - Memory Reallocation
-use std::{collections::HashSet, error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{cmp::Reverse, collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let mut solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve().to_string(),
-        solution_data.solve().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("5").part2("4")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}[ \t][0123456789 \t]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.clone().solve()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        let mut memory = self.clone();
+        memory.solve();
+        memory.solve()
+    }
 }
 
-struct InputData {
-    memorybanks: Vec<i32>,
+#[derive(Clone)]
+pub struct InputData {
+    memorybanks: Vec<u32>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            memorybanks: s
-                .split_whitespace()
-                .map(|nbr| nbr.parse().unwrap())
-                .collect(),
-        })
+        let memorybanks: Vec<_> = s
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        if memorybanks.is_empty() {
+            return Err(ParseError::missing("missing memory banks", s));
+        }
+        Ok(Self { memorybanks })
     }
 }
 
 impl InputData {
     fn rebalance(&mut self) {
-        let mut maxidx = self
+        // Note - need to use min-by and reverse the key to get the first index, since
+        // max-by returns the last index in case of duplicate max values
+        let Some((mut maxidx, &maxval)) = self
             .memorybanks
             .iter()
             .enumerate()
-            .min_by_key(|(_, val)| -*val)
-            .unwrap()
-            .0;
-        // Note - need to use min-by and negate the key to get the first index, since
-        // max-by returns the last index in case of duplicate max values
-        let mut maxval = self.memorybanks[maxidx];
+            .min_by_key(|(_, val)| Reverse(**val))
+        else {
+            return;
+        };
+        let mut maxval = maxval;
         let nbrbanks = self.memorybanks.len();
         self.memorybanks[maxidx] = 0;
         while maxval > 0 {
@@ -56,14 +77,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "0 2 7 0";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
+
     #[test]
-    fn part1_2_example_1() {
-        let testdata = "0 2 7 0";
-        let mut solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve(), 5);
-        assert_eq!(solution_data.solve(), 4);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,103 +1,125 @@
 //! # 2017 day 7 - Recursive Circus
-use std::{collections::HashMap, error::Error, str::FromStr};
-
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1(),
-        solution_data.solve_part2().to_string(),
-    ))
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+};
+
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("tknk").part2("60")];
+
+    const SHAPE: Shape = Shape::Lines(&["{word} ({int})", "{word} ({int}) -> {any}"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct Program {
+struct Program<'a> {
     weight: usize,
-    leafs: Vec<String>,
+    leafs: Vec<&'a str>,
 }
 
-impl FromStr for Program {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tokens: Vec<String> = s
-            .split_whitespace()
-            .map(|token| token.to_string())
-            .collect();
-        let mut leaflist: Vec<String> = Vec::new();
-        if tokens.len() > 2 {
-            for t in tokens.iter().skip(2) {
-                leaflist.push(t.trim_end_matches(',').to_string());
-            }
-        }
+impl<'a> TryFrom<&'a str> for Program<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let (weight, leafs) = match s.split_once(" -> ") {
+            Some((weight, leafs)) => (weight, leafs.split(", ").collect()),
+            None => (s, Vec::new()),
+        };
+        let weight = parse::strip_prefix(weight, "(")?;
+        let weight = parse::split_once(weight, ")")?.0;
         Ok(Self {
-            weight: tokens[0]
-                .strip_prefix('(')
-                .unwrap()
-                .strip_suffix(')')
-                .unwrap()
-                .parse()
-                .unwrap(),
-            leafs: leaflist,
+            weight: parse::number(weight)?,
+            leafs,
         })
     }
 }
 
-struct InputData {
-    programs: HashMap<String, Program>,
-    root: String,
+pub struct InputData<'a> {
+    programs: HashMap<&'a str, Program<'a>>,
+    root: &'a str,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let name_and_prog_list: Vec<(String, Program)> = s
-            .lines()
-            .map(|line| {
-                let (name, rest) = line.split_once(' ').unwrap();
-                (name.to_string(), Program::from_str(rest).unwrap())
-            })
-            .collect();
-        let mut parents: HashMap<String, String> = HashMap::new();
-        for (p_name, prog) in name_and_prog_list.iter() {
-            for leaf_name in prog.leafs.iter() {
-                parents.insert(leaf_name.clone(), p_name.clone());
+impl<'a> TryFrom<&'a str> for InputData<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let programs: HashMap<_, _> = parse::lines(s, |line| {
+            let (name, rest) = parse::split_once(line, " ")?;
+            Ok((name, Program::try_from(rest)?))
+        })?
+        .into_iter()
+        .collect();
+        let mut parents = HashMap::new();
+        for (p_name, prog) in &programs {
+            for leaf_name in &prog.leafs {
+                if !programs.contains_key(leaf_name) {
+                    return Err(ParseError::new("unknown program", leaf_name));
+                }
+                if parents.insert(*leaf_name, *p_name).is_some() {
+                    return Err(ParseError::new("program on more than one disc", leaf_name));
+                }
             }
         }
-        let mut root = None;
-        for (p_name, _) in name_and_prog_list.iter() {
-            if !parents.contains_key(p_name) {
-                root = Some(p_name.clone());
-            }
+        let mut roots = programs.keys().filter(|p| !parents.contains_key(*p));
+        let root = *roots
+            .next()
+            .ok_or_else(|| ParseError::new("no bottom program", s))?;
+        if let Some(other) = roots.next() {
+            return Err(ParseError::new("more than one bottom program", other));
         }
-        Ok(Self {
-            programs: HashMap::from_iter(name_and_prog_list),
-            root: root.unwrap(),
-        })
+        // With a single bottom program, any program not above it is on a loop of discs
+        let mut stack = vec![root];
+        let mut above_root = HashSet::new();
+        while let Some(p_name) = stack.pop() {
+            above_root.insert(p_name);
+            stack.extend(&programs[p_name].leafs);
+        }
+        if let Some(p_name) = programs.keys().find(|p| !above_root.contains(*p)) {
+            return Err(ParseError::new("the discs form a loop", p_name));
+        }
+        Ok(Self { programs, root })
     }
 }
 
-impl InputData {
+impl InputData<'_> {
     fn solve_part1(&self) -> String {
-        self.root.clone()
+        self.root.to_string()
     }
 
-    fn solve_part2(&self) -> usize {
-        let (_, a) = self.get_weight_and_correction(&self.root);
-        a as usize
+    /// Returns `None` if the tower can't be balanced by correcting the weight of one program.
+    fn solve_part2(&self) -> Option<usize> {
+        let (_, a) = self.get_weight_and_correction(self.root)?;
+        usize::try_from(a).ok()
     }
 
-    fn get_weight_and_correction(&self, prog: &str) -> (usize, isize) {
-        let mut leafweights: HashMap<usize, Vec<String>> = HashMap::new();
+    fn get_weight_and_correction(&self, prog: &str) -> Option<(usize, isize)> {
+        let mut leafweights: HashMap<usize, Vec<&str>> = HashMap::new();
         let mut leafcorrections: Vec<isize> = Vec::new();
         if self.programs[prog].leafs.is_empty() {
-            return (self.programs[prog].weight, 0);
+            return Some((self.programs[prog].weight, 0));
         }
 
         for leaf in self.programs[prog].leafs.iter() {
-            let (w, c) = self.get_weight_and_correction(leaf);
+            let (w, c) = self.get_weight_and_correction(leaf)?;
             leafweights
                 .entry(w)
                 .or_default()
                 //.or_insert_with(Vec::new)
-                .push(leaf.to_string());
+                .push(leaf);
             leafcorrections.push(c);
         }
 
@@ -105,41 +127,36 @@ impl InputData {
         let leafcount: usize = leafweights.values().map(|v| v.len()).sum();
         if correction != 0 {
             // A node further down the tree has reported a correction, just propagate upwards
-            return (
+            return Some((
                 self.programs[prog].weight + (leafweights.keys().sum::<usize>() * leafcount),
                 correction,
-            );
+            ));
         } else if leafweights.len() > 1 {
             // These leafs are not balanced; figure out which one is bad and calculate the required correction
-            let mut correct_weight: usize = 0;
-            let mut bad_weight: usize = 0;
-            let mut bad_node = String::from("");
+            let mut correct_weight = None;
+            let mut bad = None;
             for (weight, nodes) in leafweights.iter() {
-                if nodes.len() > 1 {
-                    correct_weight = *weight;
-                } else {
-                    bad_weight = *weight;
-                    bad_node = nodes.first().unwrap().to_string();
+                match nodes[..] {
+                    [node] if bad.is_none() => bad = Some((*weight, node)),
+                    [_, _, ..] if correct_weight.is_none() => correct_weight = Some(*weight),
+                    _ => return None,
                 }
             }
-            return (
+            let (correct_weight, (bad_weight, bad_node)) = correct_weight.zip(bad)?;
+            return Some((
                 self.programs[prog].weight + (correct_weight * leafcount),
                 correct_weight as isize
-                    - (bad_weight as isize - self.programs[&bad_node].weight as isize),
-            );
+                    - (bad_weight as isize - self.programs[bad_node].weight as isize),
+            ));
         } // else - all leafs have reported the same weight and are thus balanced
-        (
+        Some((
             self.programs[prog].weight + (leafweights.keys().sum::<usize>() * leafcount),
             0,
-        )
+        ))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "pbga (66)
+const EXAMPLE: &str = "pbga (66)
 xhth (57)
 ebii (61)
 havc (66)
@@ -153,15 +170,13 @@ ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), String::from("tknk"));
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 60);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,76 +1,127 @@
 //! # 2017 day 8 - I Heard You Like Registers
-use std::{collections::HashMap, error::Error};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{cell::OnceCell, collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("1").part2("10")];
+
+    const SHAPE: Shape = Shape::Lines(&[
+        "{word} inc {int} if {word} [<>=!] {int}",
+        "{word} dec {int} if {word} [<>=!] {int}",
+    ]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.run().0
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.run().1
+    }
+}
+
+struct Instruction<'a> {
+    register: &'a str,
+    delta: isize,
+    condition_register: &'a str,
+    comparison: fn(&isize, &isize) -> bool,
+    condition_value: isize,
 }
 
-struct InputData<'a> {
-    instructions: &'a str,
+impl<'a> TryFrom<&'a str> for Instruction<'a> {
+    type Error = ParseError;
+    fn try_from(instr: &'a str) -> Result<Self, Self::Error> {
+        let mut parts = instr.split_whitespace();
+        let mut next = |what| parse::next(&mut parts, what, instr);
+        let register = next("register")?;
+        let op = next("operation")?;
+        let val: isize = parse::number(next("value")?)?;
+        next("`if`")?;
+        let condition_register = next("register")?;
+        let cmp = next("comparison")?;
+        let condition_value = parse::number(next("value")?)?;
+
+        let comparison = match cmp {
+            ">" => isize::gt,
+            ">=" => isize::ge,
+            "<" => isize::lt,
+            "<=" => isize::le,
+            "==" => isize::eq,
+            "!=" => isize::ne,
+            _ => return Err(ParseError::new("expected a comparison", cmp)),
+        };
+        let delta = match op {
+            "inc" => val,
+            "dec" => -val,
+            _ => return Err(ParseError::new("expected `inc` or `dec`", op)),
+        };
+        Ok(Self {
+            register,
+            delta,
+            condition_register,
+            comparison,
+            condition_value,
+        })
+    }
+}
+
+pub struct InputData<'a> {
+    instructions: Vec<Instruction<'a>>,
+    register_values: OnceCell<(isize, isize)>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { instructions: s })
+        Ok(Self {
+            instructions: parse::lines(s, Instruction::try_from)?,
+            register_values: OnceCell::new(),
+        })
     }
 }
 
 impl InputData<'_> {
-    fn solve(&self) -> (isize, isize) {
-        let mut registers = HashMap::new();
-        let mut maxval = 0;
-        for instr in self.instructions.lines() {
-            let mut parts = instr.split_whitespace();
-            let reg = parts.next().unwrap();
-            let op = parts.next().unwrap();
-            let val: isize = parts.next().unwrap().parse().unwrap();
-            parts.next();
-            let cnd = parts.next().unwrap();
-            let cmp = parts.next().unwrap();
-            let cnd_val: isize = parts.next().unwrap().parse().unwrap();
-
-            let cnd_reg = registers.entry(cnd).or_insert(0);
-
-            let condition_met = match cmp {
-                ">" => *cnd_reg > cnd_val,
-                ">=" => *cnd_reg >= cnd_val,
-                "<" => *cnd_reg < cnd_val,
-                "<=" => *cnd_reg <= cnd_val,
-                "==" => *cnd_reg == cnd_val,
-                "!=" => *cnd_reg != cnd_val,
-                _ => unreachable!(),
-            };
-
-            if condition_met {
-                let reg_val = registers.entry(reg).or_insert(0);
-                match op {
-                    "inc" => *reg_val += val,
-                    "dec" => *reg_val -= val,
-                    _ => unreachable!(),
+    /// Runs the instructions, and returns the largest register value at the end and the highest
+    /// value held in any register during the run.
+    fn run(&self) -> (isize, isize) {
+        *self.register_values.get_or_init(|| {
+            let mut registers = HashMap::new();
+            let mut highest = 0;
+            for instr in &self.instructions {
+                let cnd_reg = *registers.entry(instr.condition_register).or_insert(0);
+                if (instr.comparison)(&cnd_reg, &instr.condition_value) {
+                    let reg_val = registers.entry(instr.register).or_insert(0);
+                    *reg_val += instr.delta;
+                    highest = highest.max(*reg_val);
                 }
-                maxval = maxval.max(*reg_val);
-            };
-        }
-        (*registers.values().max().unwrap(), maxval)
+            }
+            (
+                registers.values().copied().max().unwrap_or_default(),
+                highest,
+            )
+        })
     }
 }
 
+const EXAMPLE: &str = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn parts_1_2_example_1() {
-        let testdata = "b inc 5 if a > 1
-a inc 1 if b < 5
-c dec -10 if a >= 1
-c inc -20 if c == 10";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 1);
-        assert_eq!(p2, 10);
+    fn examples() {
+        check::<InputData>();
     }
 }
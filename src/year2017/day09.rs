@@ -1,177 +1,134 @@
 //! # 2017 day 9 - Stream Processing
-use std::error::Error;
-
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve_parts_1_2();
-    Ok((p1.to_string(), p2.to_string()))
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use std::{cell::OnceCell, error::Error};
+
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1"),
+        Example::new(EXAMPLE_2).part1("6"),
+        Example::new(EXAMPLE_3).part1("5"),
+        Example::new(EXAMPLE_4).part1("16"),
+        Example::new(EXAMPLE_5).part1("1"),
+        Example::new(EXAMPLE_6).part1("9"),
+        Example::new(EXAMPLE_7).part1("9"),
+        Example::new(EXAMPLE_8).part1("3"),
+        Example::new(EXAMPLE_9).part2("0"),
+        Example::new(EXAMPLE_10).part2("17"),
+        Example::new(EXAMPLE_11).part2("3"),
+        Example::new(EXAMPLE_12).part2("2"),
+        Example::new(EXAMPLE_13).part2("0"),
+        Example::new(EXAMPLE_14).part2("0"),
+        Example::new(EXAMPLE_15).part2("10"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{{any}}", "<{any}>"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_parts_1_2().0
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_parts_1_2().1
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     stream: &'a str,
+    counts: OnceCell<(usize, usize)>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { stream: s })
+        Ok(Self {
+            stream: s,
+            counts: OnceCell::new(),
+        })
     }
 }
 
 impl InputData<'_> {
+    /// Returns the total score of the groups and the amount of garbage, which are counted in the
+    /// same pass over the stream.
     fn solve_parts_1_2(&self) -> (usize, usize) {
-        let mut tokens = self.stream.chars();
-        let mut garbage_count = 0;
-        let mut score = 0;
-        let mut level = 1;
-        while let Some(token) = tokens.next() {
-            match token {
-                '<' => {
-                    while let Some(garbage) = tokens.next() {
-                        match garbage {
-                            '!' => {
-                                tokens.next();
+        *self.counts.get_or_init(|| {
+            let mut tokens = self.stream.chars();
+            let mut garbage_count = 0;
+            let mut score = 0;
+            let mut level = 1;
+            while let Some(token) = tokens.next() {
+                match token {
+                    '<' => {
+                        while let Some(garbage) = tokens.next() {
+                            match garbage {
+                                '!' => {
+                                    tokens.next();
+                                }
+                                '>' => break,
+                                _ => garbage_count += 1,
                             }
-                            '>' => break,
-                            _ => garbage_count += 1,
                         }
                     }
+                    '{' => {
+                        score += level;
+                        level += 1;
+                    }
+                    '}' => {
+                        level -= 1;
+                    }
+                    _ => (),
                 }
-                '{' => {
-                    score += level;
-                    level += 1;
-                }
-                '}' => {
-                    level -= 1;
-                }
-                _ => (),
             }
-        }
-        (score, garbage_count)
+            (score, garbage_count)
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "{}";
 
-    #[test]
-    fn part_1_example_1() {
-        let testdata = "{}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 1);
-    }
+const EXAMPLE_2: &str = "{{{}}}";
 
-    #[test]
-    fn part_1_example_2() {
-        let testdata = "{{{}}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 6);
-    }
+const EXAMPLE_3: &str = "{{},{}}";
 
-    #[test]
-    fn part_1_example_3() {
-        let testdata = "{{},{}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 5);
-    }
+const EXAMPLE_4: &str = "{{{},{},{{}}}}";
 
-    #[test]
-    fn part_1_example_4() {
-        let testdata = "{{{},{},{{}}}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 16);
-    }
+const EXAMPLE_5: &str = "{<a>,<a>,<a>,<a>}";
 
-    #[test]
-    fn part_1_example_5() {
-        let testdata = "{<a>,<a>,<a>,<a>}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 1);
-    }
+const EXAMPLE_6: &str = "{{<ab>},{<ab>},{<ab>},{<ab>}}";
 
-    #[test]
-    fn part_1_example_6() {
-        let testdata = "{{<ab>},{<ab>},{<ab>},{<ab>}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 9);
-    }
+const EXAMPLE_7: &str = "{{<!!>},{<!!>},{<!!>},{<!!>}}";
 
-    #[test]
-    fn part_1_example_7() {
-        let testdata = "{{<!!>},{<!!>},{<!!>},{<!!>}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 9);
-    }
+const EXAMPLE_8: &str = "{{<a!>},{<a!>},{<a!>},{<ab>}}";
 
-    #[test]
-    fn part_1_example_8() {
-        let testdata = "{{<a!>},{<a!>},{<a!>},{<ab>}}";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, _) = solution_data.solve_parts_1_2();
-        assert_eq!(p1, 3);
-    }
+const EXAMPLE_9: &str = "<>";
 
-    #[test]
-    fn part_2_example_1() {
-        let testdata = "<>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 0);
-    }
+const EXAMPLE_10: &str = "<random characters>";
 
-    #[test]
-    fn part_2_example_2() {
-        let testdata = "<random characters>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 17);
-    }
+const EXAMPLE_11: &str = "<<<<>";
 
-    #[test]
-    fn part_2_example_3() {
-        let testdata = "<<<<>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 3);
-    }
+const EXAMPLE_12: &str = "<{!>}>";
 
-    #[test]
-    fn part_2_example_4() {
-        let testdata = "<{!>}>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 2);
-    }
+const EXAMPLE_13: &str = "<!!>";
 
-    #[test]
-    fn part_2_example_5() {
-        let testdata = "<!!>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 0);
-    }
+const EXAMPLE_14: &str = "<!!!>>";
 
-    #[test]
-    fn part_2_example_6() {
-        let testdata = "<!!!>>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 0);
-    }
+const EXAMPLE_15: &str = "<{o\"i!a,<{i<a>";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part_2_example_7() {
-        let testdata = "<{o\"i!a,<{i<a>";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (_, p2) = solution_data.solve_parts_1_2();
-        assert_eq!(p2, 10);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,38 +1,73 @@
 //! # 2017 day 10 - Knot Hash
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use std::{error::Error, fmt::Write};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1(256).to_string(),
-        solution_data.solve_part2(256),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const PARAMS: &'static [Param] = &[Param::new("list_len", "256")];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("12").params("list_len=5"),
+        Example::new(EXAMPLE_2).part2("a2582a3a0e66e6e86e3812dcb672a272"),
+        Example::new(EXAMPLE_3).part2("33efeb34ea91902bb2f59c9920caa6cd"),
+        Example::new(EXAMPLE_4).part2("3efbe78a8d82f29979031a4aa0b16a9d"),
+        Example::new(EXAMPLE_5).part2("63960835bcdc130f0b66d7ff4f6a5a8e"),
+    ];
+
+    // Part 2 hashes the input as text, so any input is valid, even an empty one
+    const SHAPE: Shape = Shape::Any;
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &'a str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get_in("list_len", 1..)?))
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1(self.list_len)
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2(256)
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     rawdata: &'a str,
+    list_len: usize,
 }
 
-impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { rawdata: s })
+impl<'a> InputData<'a> {
+    /// The list length only applies to part 1, as part 2 needs 256 numbers to make up the hash.
+    fn parse_input(s: &'a str, list_len: usize) -> Self {
+        Self {
+            rawdata: s,
+            list_len,
+        }
     }
 }
 
 impl InputData<'_> {
-    fn solve_part1(&self, buffer_len: usize) -> usize {
+    /// Returns `None` if the input isn't a list of lengths fitting in the list, since part 1 reads
+    /// the input as such a list, unlike part 2, whose examples include any kind of text.
+    fn solve_part1(&self, buffer_len: usize) -> Option<usize> {
         let lengths: Vec<usize> = self
             .rawdata
             .split(',')
-            .map(|c| c.parse().unwrap())
-            .collect();
+            .map(|c| c.parse().ok().filter(|&length| length <= buffer_len))
+            .collect::<Option<_>>()?;
         let nbrs = generate_hash(&lengths, buffer_len, 1);
-        nbrs.iter().take(2).product()
+        Some(nbrs.iter().take(2).product())
     }
 
     fn solve_part2(&self, buffer_len: usize) -> String {
-        let mut lengths: Vec<usize> = self.rawdata.chars().map(|c| c as usize).collect();
+        let mut lengths: Vec<usize> = self.rawdata.bytes().map(usize::from).collect();
         lengths.extend([17, 31, 73, 47, 23]);
         let sparse = generate_hash(&lengths, buffer_len, 64);
         let mut dense = String::new();
@@ -63,54 +98,23 @@ fn generate_hash(lengths: &[usize], buffersize: usize, rounds: usize) -> Vec<usi
     nbrs
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "3,4,1,5";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "3,4,1,5";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(5), 12);
-    }
+const EXAMPLE_2: &str = "";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(
-            solution_data.solve_part2(256),
-            "a2582a3a0e66e6e86e3812dcb672a272"
-        );
-    }
+const EXAMPLE_3: &str = "AoC 2017";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "AoC 2017";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(
-            solution_data.solve_part2(256),
-            "33efeb34ea91902bb2f59c9920caa6cd"
-        );
-    }
+const EXAMPLE_4: &str = "1,2,3";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "1,2,3";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(
-            solution_data.solve_part2(256),
-            "3efbe78a8d82f29979031a4aa0b16a9d"
-        );
-    }
+const EXAMPLE_5: &str = "1,2,4";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_4() {
-        let testdata = "1,2,4";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(
-            solution_data.solve_part2(256),
-            "63960835bcdc130f0b66d7ff4f6a5a8e"
-        );
+    fn examples() {
+        check::<InputData>();
     }
 }
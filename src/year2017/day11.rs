@@ -1,15 +1,38 @@
 //! # 2017 day 11 - Hex Ed
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{
     error::Error,
     ops::{Add, AddAssign},
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("3").part2("3"),
+        Example::new(EXAMPLE_2).part1("0").part2("2"),
+        Example::new(EXAMPLE_3).part1("2").part2("2"),
+        Example::new(EXAMPLE_4).part1("3").part2("3"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[nesw,]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
+#[derive(Clone, Copy)]
 struct Hexpoint {
     q: isize,
     r: isize,
@@ -20,15 +43,15 @@ impl Hexpoint {
         Self { q, r }
     }
 
-    fn direction_from_str(input: &str) -> Self {
+    fn direction_from_str(input: &str) -> Result<Self, ParseError> {
         match input {
-            "n" => Self { q: 0, r: -1 },
-            "ne" => Self { q: 1, r: -1 },
-            "se" => Self { q: 1, r: 0 },
-            "s" => Self { q: 0, r: 1 },
-            "sw" => Self { q: -1, r: 1 },
-            "nw" => Self { q: -1, r: 0 },
-            _ => unreachable!(),
+            "n" => Ok(Self { q: 0, r: -1 }),
+            "ne" => Ok(Self { q: 1, r: -1 }),
+            "se" => Ok(Self { q: 1, r: 0 }),
+            "s" => Ok(Self { q: 0, r: 1 }),
+            "sw" => Ok(Self { q: -1, r: 1 }),
+            "nw" => Ok(Self { q: -1, r: 0 }),
+            _ => Err(ParseError::new("expected a direction", input)),
         }
     }
 
@@ -56,69 +79,60 @@ impl AddAssign for Hexpoint {
     }
 }
 
-struct InputData<'a> {
-    moves: &'a str,
+pub struct InputData {
+    moves: Vec<Hexpoint>,
 }
 
-impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self { moves: s })
+impl TryFrom<&str> for InputData {
+    type Error = ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            moves: s
+                .split(',')
+                .map(Hexpoint::direction_from_str)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
-impl InputData<'_> {
-    fn solve(&self) -> (usize, usize) {
-        let start_point = Hexpoint::new(0, 0);
-        let mut current_location = Hexpoint::new(0, 0);
-        let mut current_distance = 0;
-        let mut max_distance = 0;
-        for m in self.moves.split(',') {
-            current_location += Hexpoint::direction_from_str(m);
-            current_distance = current_location.get_distance(&start_point);
-            max_distance = max_distance.max(current_distance);
-        }
-        (current_distance, max_distance)
+impl InputData {
+    /// Returns the locations along the path, after each move.
+    fn path(&self) -> impl Iterator<Item = Hexpoint> {
+        self.moves.iter().scan(Hexpoint::new(0, 0), |location, m| {
+            *location += *m;
+            Some(*location)
+        })
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "ne,ne,ne";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 3);
-        assert_eq!(p2, 3);
+    fn solve_part1(&self) -> usize {
+        self.path()
+            .last()
+            .map_or(0, |location| location.get_distance(&Hexpoint::new(0, 0)))
     }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "ne,ne,sw,sw";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 0);
-        assert_eq!(p2, 2);
+    fn solve_part2(&self) -> usize {
+        self.path()
+            .map(|location| location.get_distance(&Hexpoint::new(0, 0)))
+            .max()
+            .unwrap_or(0)
     }
+}
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "ne,ne,s,s";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 2);
-        assert_eq!(p2, 2);
-    }
+const EXAMPLE_1: &str = "ne,ne,ne";
+
+const EXAMPLE_2: &str = "ne,ne,sw,sw";
+
+const EXAMPLE_3: &str = "ne,ne,s,s";
+
+const EXAMPLE_4: &str = "se,sw,se,sw,sw";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_4() {
-        let testdata = "se,sw,se,sw,sw";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 3);
-        assert_eq!(p2, 3);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,45 +1,78 @@
 //! # 2017 day 12 - Digital Plumber
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("6").part2("2")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int} <-> {ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     numbers: Vec<Vec<usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            numbers: s
-                .lines()
-                .map(|line| {
-                    line.split_whitespace()
-                        .filter_map(|n| n.trim_end_matches(',').parse().ok())
-                        .skip(1)
-                        .collect()
-                })
-                .collect(),
-        })
+        let numbers: Vec<Vec<usize>> = s
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .filter_map(|n| n.trim_end_matches(',').parse().ok())
+                    .skip(1)
+                    .collect()
+            })
+            .collect();
+        if let Some(line) = s
+            .lines()
+            .zip(&numbers)
+            .find_map(|(line, n)| n.iter().any(|id| *id >= numbers.len()).then_some(line))
+        {
+            return Err(ParseError::new("unknown program", line));
+        }
+        if numbers.is_empty() {
+            return Err(ParseError::missing("missing programs", s));
+        }
+        Ok(Self { numbers })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
+    fn solve_part1(&self) -> usize {
+        let mut seen = vec![false; self.numbers.len()];
+        seen[0] = true;
+        self.search_programs(&mut seen, 0)
+    }
+
+    fn solve_part2(&self) -> usize {
         let size = self.numbers.len();
         let mut seen = vec![false; size];
-        let mut program_groups = Vec::new();
+        let mut groups = 0;
         (0..size).for_each(|i| {
             if !seen[i] {
                 seen[i] = true;
-                program_groups.push(self.search_programs(&mut seen, i));
+                self.search_programs(&mut seen, i);
+                groups += 1;
             }
         });
-        (program_groups[0], program_groups.len())
+        groups
     }
 
     fn search_programs(&self, seen: &mut [bool], index: usize) -> usize {
@@ -54,22 +87,21 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "0 <-> 2
+const EXAMPLE: &str = "0 <-> 2
 1 <-> 1
 2 <-> 0, 3, 4
 3 <-> 2, 4
 4 <-> 2, 3, 6
 5 <-> 6
 6 <-> 4, 5";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 6);
-        assert_eq!(p2, 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
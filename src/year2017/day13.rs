@@ -1,13 +1,29 @@
 //! # 2017 day 13 - Packet Scanners
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use crate::aoc_util::math;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("24").part2("10")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}: {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 struct Scanner {
@@ -17,12 +33,16 @@ struct Scanner {
 }
 
 impl FromStr for Scanner {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").unwrap();
-        let range = right.parse().unwrap();
+        let (left, right) = parse::split_once(s, ": ")?;
+        let range = parse::number(right)?;
+        if range < 2 {
+            // A scanner of range 1 never moves, so it would catch every packet
+            return Err(ParseError::new("expected a range of at least 2", right));
+        }
         Ok(Self {
-            depth: left.parse().unwrap(),
+            depth: parse::number(left)?,
             range,
             cycle: 2 * (range - 1),
         })
@@ -36,18 +56,15 @@ impl Scanner {
     }
 }
 
-struct InputData {
+pub struct InputData {
     scanners: Vec<Scanner>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            scanners: s
-                .lines()
-                .map(|line| Scanner::from_str(line).unwrap())
-                .collect(),
+            scanners: parse::lines(s, Scanner::from_str)?,
         })
     }
 }
@@ -61,7 +78,8 @@ impl InputData {
             .sum()
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if every delay gets the packet caught.
+    fn solve_part2(&self) -> Option<usize> {
         let mut current_lcm = 1;
         let mut delays = Vec::from([1]);
         for s in self.scanners.iter() {
@@ -76,28 +94,22 @@ impl InputData {
             current_lcm = new_lcm;
             delays = new_delays;
         }
-        delays[0]
+        delays.first().copied()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "0: 3
+const EXAMPLE: &str = "0: 3
 1: 2
 4: 4
 6: 4";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 24);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 10);
+    fn examples() {
+        check::<InputData>();
     }
 }
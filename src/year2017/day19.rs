@@ -1,75 +1,115 @@
 //! # 2017 day 19 - A Series of Tubes
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
-use std::{error::Error, str::FromStr};
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1, p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("ABCDEF").part2("38")];
+
+    const SHAPE: Shape = Shape::Grid(" |-+ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.follow_path()
+            .as_ref()
+            .map_or(Answer::NotApplicable, |(letters, _)| {
+                letters.as_str().into()
+            })
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.follow_path()
+            .as_ref()
+            .map_or(Answer::NotApplicable, |(_, steps)| (*steps).into())
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
+    start: Point,
+    path: OnceCell<Option<(String, usize)>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s)?;
+        let start = grid
+            .find(b'|')
+            .ok_or_else(|| ParseError::new("missing the start of the path", s))?;
         Ok(Self {
-            grid: Grid::parse(s),
+            grid,
+            start,
+            path: OnceCell::new(),
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (String, usize) {
-        let mut current = self.grid.find('|').unwrap();
-        let mut direction = DOWN;
-        let mut p1 = Vec::new();
-        let mut p2 = 0;
-        loop {
-            p2 += 1;
-            current += direction;
-            let v = self.grid.get_element(&current).unwrap();
-            match v {
-                '+' => {
-                    let left = current + direction.rotate_left();
-                    if let Some(c) = self.grid.get_element(&left) {
-                        if c != ' ' {
-                            direction = direction.rotate_left();
+    /// Returns the letters seen along the path and the number of steps taken, which are both found
+    /// by following the path once, or `None` if the path goes round in a loop.
+    fn follow_path(&self) -> &Option<(String, usize)> {
+        self.path.get_or_init(|| {
+            let mut current = self.start;
+            let mut direction = DOWN;
+            let mut letters = String::new();
+            let mut steps = 0;
+            loop {
+                // Without a loop, each tile is passed at most once in each direction
+                if steps > 4 * self.grid.elements.len() {
+                    return None;
+                }
+                steps += 1;
+                current += direction;
+                let Some(v) = self.grid.get_element(&current) else {
+                    break;
+                };
+                match v {
+                    b'+' => {
+                        let left = current + direction.rotate_left();
+                        if let Some(c) = self.grid.get_element(&left) {
+                            if c != b' ' {
+                                direction = direction.rotate_left();
+                            } else {
+                                direction = direction.rotate_right();
+                            }
                         } else {
                             direction = direction.rotate_right();
                         }
-                    } else {
-                        direction = direction.rotate_right();
                     }
-                }
-                ' ' => break,
-                '-' | '|' => continue,
-                _ => {
-                    p1.push(v);
+                    b' ' => break,
+                    b'-' | b'|' => continue,
+                    _ => {
+                        letters.push(char::from(v));
+                    }
                 }
             }
-        }
-        (p1.iter().collect(), p2)
+            Some((letters, steps))
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "     |          
+const EXAMPLE: &str = "     |          
      |  +--+    
      A  |  C    
  F---|----E|--+ 
      |  |  |  D 
      +B-+  +--+ ";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, "ABCDEF");
-        assert_eq!(p2, 38);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
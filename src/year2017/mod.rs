@@ -12,3 +12,22 @@ pub mod day11;
 pub mod day12;
 pub mod day13;
 pub mod day19;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2017:
+    1 => day01: "Inverse Captcha",
+    2 => day02: "Corruption Checksum",
+    3 => day03: "Spiral Memory",
+    4 => day04: "High-Entropy Passphrases",
+    5 => day05: "Maze of Twisty Trampolines, All Alike",
+    6 => day06: "Memory Reallocation",
+    7 => day07: "Recursive Circus",
+    8 => day08: "I Heard You Like Registers",
+    9 => day09: "Stream Processing",
+    10 => day10: "Knot Hash",
+    11 => day11: "Hex Ed",
+    12 => day12: "Digital Plumber",
+    13 => day13: "Packet Scanners",
+    19 => day19: "A Series of Tubes",
+}
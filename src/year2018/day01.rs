@@ -1,23 +1,48 @@
 //! # 2018 day 1 - Chronal Calibration
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("3").part2("2"),
+        Example::new(EXAMPLE_2).part1("3"),
+        Example::new(EXAMPLE_3).part1("0"),
+        Example::new(EXAMPLE_4).part1("-6"),
+        Example::new(EXAMPLE_5).part2("0"),
+        Example::new(EXAMPLE_6).part2("10"),
+        Example::new(EXAMPLE_7).part2("5"),
+        Example::new(EXAMPLE_8).part2("14"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["+{int}", "{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     changes: Vec<isize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            changes: s.lines().map(|line| line.parse().unwrap()).collect(),
+            changes: parse::lines(s, parse::number)?,
         })
     }
 }
@@ -27,7 +52,28 @@ impl InputData {
         self.changes.iter().sum()
     }
 
-    fn solve_part2(&self) -> isize {
+    /// Returns `None` if no frequency is ever reached twice.
+    fn solve_part2(&self) -> Option<isize> {
+        // Each pass through the changes shifts the frequencies of the first pass by the drift, so
+        // a frequency repeats only if two of the first pass are equal modulo the drift
+        if self.changes.is_empty() {
+            return None;
+        }
+        let drift: isize = self.changes.iter().sum();
+        if drift != 0 {
+            let residues: HashSet<_> = self
+                .changes
+                .iter()
+                .scan(0, |freq, change| {
+                    let before = *freq;
+                    *freq += change;
+                    Some(before.rem_euclid(drift))
+                })
+                .collect();
+            if residues.len() == self.changes.len() {
+                return None;
+            }
+        }
         let mut i: usize = 0;
         let mut freq: isize = 0;
         let mut seen: HashSet<isize> = HashSet::new();
@@ -40,74 +86,33 @@ impl InputData {
             seen.insert(freq);
             i = (i + 1) % self.changes.len();
         }
-        freq
+        Some(freq)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "+1\n-2\n+3\n+1";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "+1\n-2\n+3\n+1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_2: &str = "+1\n+1\n+1";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "+1\n+1\n+1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+const EXAMPLE_3: &str = "+1\n+1\n-2";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "+1\n+1\n-2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 0);
-    }
+const EXAMPLE_4: &str = "-1\n-2\n-3";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "-1\n-2\n-3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), -6);
-    }
+const EXAMPLE_5: &str = "+1\n-1";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "+1\n-2\n+3\n+1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2);
-    }
+const EXAMPLE_6: &str = "+3\n+3\n+4\n-2\n-4";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "+1\n-1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 0);
-    }
+const EXAMPLE_7: &str = "-6\n+3\n+8\n+5\n-6";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "+3\n+3\n+4\n-2\n-4";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 10);
-    }
+const EXAMPLE_8: &str = "+7\n+7\n-2\n-7\n-4";
 
-    #[test]
-    fn part2_example_4() {
-        let testdata = "-6\n+3\n+8\n+5\n-6";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 5);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_5() {
-        let testdata = "+7\n+7\n-2\n-7\n-4";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 14);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,20 +1,38 @@
 //! # 2018 day 2 - Inventory Management System
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("12"),
+        Example::new(EXAMPLE_2).part2("fgij"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     ids: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
             ids: s.lines().collect(),
@@ -73,21 +91,17 @@ impl InputData<'_> {
     }
 }
 
+const EXAMPLE_1: &str = "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab";
+
+const EXAMPLE_2: &str = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let testdata = "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 12);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let testdata = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), "fgij");
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,11 +1,28 @@
 //! # 2018 day 3 - No Matter How You Slice It
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::Point;
-use std::{collections::HashMap, error::Error, str::FromStr};
+use std::{cell::OnceCell, collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("4").part2("3")];
+
+    const SHAPE: Shape = Shape::Lines(&["#{int} @ {int},{int}: {int}x{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Claim {
@@ -16,93 +33,80 @@ struct Claim {
 }
 
 impl FromStr for Claim {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s
-            .strip_prefix('#')
-            .unwrap()
-            .split(&['@', ',', ':', 'x'])
-            .map(|n| n.trim().parse::<usize>().unwrap());
+        let (id, rest) = parse::split_once(parse::strip_prefix(s, "#")?, " @ ")?;
+        let (corner, size) = parse::split_once(rest, ": ")?;
+        let (x, y) = parse::split_once(corner, ",")?;
+        let (x_len, y_len) = parse::split_once(size, "x")?;
         Ok(Self {
-            id: parts.next().unwrap(),
-            corner: Point::new(parts.next().unwrap() as i32, parts.next().unwrap() as i32),
-            x_len: parts.next().unwrap(),
-            y_len: parts.next().unwrap(),
+            id: parse::number(id)?,
+            corner: Point::new(parse::number(x)?, parse::number(y)?),
+            x_len: parse::number(x_len)?,
+            y_len: parse::number(y_len)?,
         })
     }
 }
 
-struct InputData {
+impl Claim {
+    fn points(&self) -> impl Iterator<Item = Point> {
+        (0..self.x_len).flat_map(move |dx| {
+            (0..self.y_len).map(move |dy| Point::new(dx as i32, dy as i32) + self.corner)
+        })
+    }
+}
+
+pub struct InputData {
     claims: Vec<Claim>,
+    fabric: OnceCell<HashMap<Point, usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            claims: s
-                .lines()
-                .map(|line| Claim::from_str(line).unwrap())
-                .collect(),
+            claims: parse::lines(s, Claim::from_str)?,
+            fabric: OnceCell::new(),
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut seen = HashMap::new();
-        let mut possible = Vec::new();
-        for (i, c) in self.claims.iter().enumerate() {
-            let mut overlap = false;
-            for p in (0..c.x_len).flat_map(|dx| {
-                (0..c.y_len).map(move |dy| Point::new(dx as i32, dy as i32) + c.corner)
-            }) {
-                seen.entry(p)
-                    .and_modify(|v| {
-                        overlap = true;
-                        *v += 1
-                    })
-                    .or_insert(1);
-            }
-            if !overlap {
-                possible.push(i);
-            }
-        }
-        let p1 = seen.values().filter(|v| **v > 1).count();
-        let mut p2 = 0;
-
-        for c in possible.iter().map(|i| self.claims.get(*i).unwrap()) {
-            let mut overlap = false;
-            for p in (0..c.x_len).flat_map(|dx| {
-                (0..c.y_len).map(move |dy| Point::new(dx as i32, dy as i32) + c.corner)
-            }) {
-                if *seen.get(&p).unwrap() > 1 {
-                    overlap = true;
-                    break;
-                }
+    /// Returns the number of claims covering each square inch, which both parts look at.
+    fn fabric(&self) -> &HashMap<Point, usize> {
+        self.fabric.get_or_init(|| {
+            let mut fabric = HashMap::new();
+            for p in self.claims.iter().flat_map(Claim::points) {
+                *fabric.entry(p).or_insert(0) += 1;
             }
-            if !overlap {
-                p2 = c.id;
-                break;
-            }
-        }
-        (p1, p2)
+            fabric
+        })
+    }
+
+    fn solve_part1(&self) -> usize {
+        self.fabric().values().filter(|v| **v > 1).count()
+    }
+
+    fn solve_part2(&self) -> usize {
+        let fabric = self.fabric();
+        self.claims
+            .iter()
+            .find(|c| c.points().all(|p| fabric[&p] == 1))
+            .map_or(0, |c| c.id)
     }
 }
 
+const EXAMPLE: &str = "#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA_1: &str = "#1 @ 1,3: 4x4
-#2 @ 3,1: 4x4
-#3 @ 5,5: 2x2";
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn parts1_2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 4);
-        assert_eq!(p2, 3);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,3 +1,11 @@
 pub mod day01;
 pub mod day02;
 pub mod day03;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2018:
+    1 => day01: "Chronal Calibration",
+    2 => day02: "Inventory Management System",
+    3 => day03: "No Matter How You Slice It",
+}
@@ -1,12 +1,32 @@
 //! # 2019 day 1 - The Tyranny of the Rocket Equation
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::max, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2"),
+        Example::new(EXAMPLE_2).part1("2").part2("2"),
+        Example::new(EXAMPLE_3).part1("654").part2("966"),
+        Example::new(EXAMPLE_4).part1("33583").part2("50346"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 fn calc_mass(mass: &usize, countfuel: bool) -> usize {
@@ -18,15 +38,15 @@ fn calc_mass(mass: &usize, countfuel: bool) -> usize {
     }
 }
 
-struct InputData {
+pub struct InputData {
     masses: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            masses: s.lines().map(|line| line.parse().unwrap()).collect(),
+            masses: parse::lines(s, parse::number)?,
         })
     }
 }
@@ -41,56 +61,21 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "12";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "12";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+const EXAMPLE_2: &str = "14";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "14";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+const EXAMPLE_3: &str = "1969";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "1969";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 654);
-    }
+const EXAMPLE_4: &str = "100756";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "100756";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 33583);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let testdata = "14";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2);
-    }
-
-    #[test]
-    fn part2_example_2() {
-        let testdata = "1969";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 966);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_3() {
-        let testdata = "100756";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 50346);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,38 +1,58 @@
 //! # 2019 day 2 - 1202 Program Alarm
 use super::intcode::*;
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::Ordering, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     program: Vec<isize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            program: s.split(',').map(|n| n.parse().unwrap()).collect(),
+            program: s.split(',').map(parse::number).collect::<Result<_, _>>()?,
         })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> isize {
+    /// Returns the value left at position 0 by running the program with `noun` and `verb`, or
+    /// `None` if the program doesn't halt properly.
+    fn run_program(&self, noun: isize, verb: isize) -> Option<isize> {
         let mut cpu = Intcode::new(&self.program);
-        cpu.overwrite_pos(1, 12);
-        cpu.overwrite_pos(2, 2);
-        cpu.run();
-        cpu.read_pos(0)
+        cpu.overwrite_pos(1, noun);
+        cpu.overwrite_pos(2, verb);
+        matches!(cpu.run(), State::Halted).then(|| cpu.read_pos(0))
+    }
+
+    fn solve_part1(&self) -> Option<isize> {
+        self.run_program(12, 2)
     }
 
-    fn solve_part2(&self) -> isize {
+    /// Returns `None` if no noun and verb from 0 to 99 give the target value.
+    fn solve_part2(&self) -> Option<isize> {
         const TARGET: isize = 19690720;
         let mut noun_max = 99;
         let mut noun_min = 0;
@@ -43,12 +63,11 @@ impl InputData {
         loop {
             //Since we so far only have add and mul op-codes, changes in the noun/verb will have a linear impact on the resulting value.
             //I.e. noun/verb functions like arms on a clock and we can use binary search to dial in the answer rather than brute-forcing 0-99.
-            let mut cpu = Intcode::new(&self.program);
-            cpu.overwrite_pos(1, noun);
-            cpu.overwrite_pos(2, verb);
-            cpu.run();
-            match cpu.read_pos(0).cmp(&TARGET) {
-                Ordering::Equal => break 100 * noun + verb,
+            if !(0..=99).contains(&noun) || !(0..=99).contains(&verb) || verb_min > verb_max {
+                break None;
+            }
+            match self.run_program(noun, verb)?.cmp(&TARGET) {
+                Ordering::Equal => break Some(100 * noun + verb),
                 Ordering::Greater => {
                     noun_max = noun - 1;
                     if noun > noun_min {
@@ -1,21 +1,43 @@
 //! # 2019 day 3 - Crossed Wires
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
-use std::{collections::HashSet, error::Error, str::FromStr};
+use crate::aoc_util::sparse_grid::SparseGrid;
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("6"),
+        Example::new(EXAMPLE_2).part1("159").part2("610"),
+        Example::new(EXAMPLE_3).part1("135").part2("410"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[UDLR]{int}", "[UDLR]{int},[UDLR0123456789,]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Point {
-    fn dir_to_point(d: char) -> Self {
+    fn dir_to_point(d: &str) -> Result<Self, ParseError> {
         match d {
-            'R' => RIGHT,
-            'L' => LEFT,
-            'U' => UP,
-            'D' => DOWN,
-            _ => unreachable!(),
+            "R" => Ok(RIGHT),
+            "L" => Ok(LEFT),
+            "U" => Ok(UP),
+            "D" => Ok(DOWN),
+            _ => Err(ParseError::new("expected R, L, U or D", d)),
         }
     }
 }
@@ -25,18 +47,18 @@ struct Wire {
 }
 
 impl FromStr for Wire {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             instructions: s
                 .split(',')
                 .map(|s| {
-                    (
-                        Point::dir_to_point(s.chars().nth(0).unwrap()),
-                        s[1..].parse().unwrap(),
-                    )
+                    let (dir, steps) = s
+                        .split_at_checked(1)
+                        .ok_or_else(|| ParseError::new("expected a wire segment", s))?;
+                    Ok((Point::dir_to_point(dir)?, parse::number(steps)?))
                 })
-                .collect(),
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -57,73 +79,76 @@ impl Wire {
     }
 }
 
-struct InputData {
+pub struct InputData {
     wire_1: Wire,
     wire_2: Wire,
+    crossings: OnceCell<Vec<(Point, usize)>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once('\n').unwrap();
+        let (left, right) = parse::split_once(s, "\n")?;
         Ok(Self {
-            wire_1: Wire::from_str(left).unwrap(),
-            wire_2: Wire::from_str(right).unwrap(),
+            wire_1: Wire::from_str(left)?,
+            wire_2: Wire::from_str(right.trim_end())?,
+            crossings: OnceCell::new(),
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let w1_points = self.wire_1.walk();
-        let w2_points = self.wire_2.walk();
-        let mut w1_set: HashSet<Point> = HashSet::new();
-        w1_set.extend(&w1_points);
-        let mut w2_set: HashSet<Point> = HashSet::new();
-        w2_set.extend(&w2_points);
-        let intersections = w1_set.intersection(&w2_set);
-        let mut p1 = Vec::new();
-        let mut p2 = Vec::new();
-        for i in intersections {
-            p1.push(i.manhattan(&ORIGIN));
-            p2.push(
-                2 + w1_points.iter().position(|n| n == i).unwrap()
-                    + w2_points.iter().position(|n| n == i).unwrap(),
-            );
-        }
-        (*p1.iter().min().unwrap(), *p2.iter().min().unwrap())
+    /// Returns the points where the wires cross, with the combined number of steps of both wires
+    /// to get there. Both parts look at the same crossings.
+    fn crossings(&self) -> &[(Point, usize)] {
+        self.crossings.get_or_init(|| {
+            // The number of steps along the first wire to reach each of its points the first time
+            let mut w1_steps = SparseGrid::new(0);
+            for (steps, p) in self.wire_1.walk().into_iter().enumerate().rev() {
+                w1_steps.set_point(&p, steps + 1);
+            }
+            self.wire_2
+                .walk()
+                .into_iter()
+                .enumerate()
+                .filter(|(_, p)| w1_steps.contains(p))
+                .map(|(steps, p)| (p, w1_steps.get_element(&p) + steps + 1))
+                .collect()
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part1(&self) -> usize {
+        self.crossings()
+            .iter()
+            .map(|(p, _)| p.manhattan(&ORIGIN))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "R8,U5,L5,D3\nU7,R6,D4,L4";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 6);
+    fn solve_part2(&self) -> usize {
+        self.crossings()
+            .iter()
+            .map(|(_, steps)| *steps)
+            .min()
+            .unwrap_or(usize::MAX)
     }
+}
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "R75,D30,R83,U83,L12,D49,R71,U7,L72
+const EXAMPLE_1: &str = "R8,U5,L5,D3\nU7,R6,D4,L4";
+
+const EXAMPLE_2: &str = "R75,D30,R83,U83,L12,D49,R71,U7,L72
 U62,R66,U55,R34,D71,R55,D58,R83";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 159);
-        assert_eq!(p2, 610);
-    }
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+const EXAMPLE_3: &str = "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
 U98,R91,D20,R16,D67,R40,U7,R15,U6,R7";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 135);
-        assert_eq!(p2, 410);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,12 +1,24 @@
 //! # 2019 day 4 - Secure Container
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const SHAPE: Shape = Shape::Lines(&["{int}-{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -15,10 +27,10 @@ struct PwdNbr {
 }
 
 impl FromStr for PwdNbr {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            number: s.parse().unwrap(),
+            number: parse::number(s)?,
         })
     }
 }
@@ -43,18 +55,18 @@ impl PwdNbr {
     }
 }
 
-struct InputData {
+pub struct InputData {
     range_lower: PwdNbr,
     range_upper: PwdNbr,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (low, high) = s.split_once('-').unwrap();
+        let (low, high) = parse::split_once(s, "-")?;
         Ok(Self {
-            range_lower: PwdNbr::from_str(low).unwrap(),
-            range_upper: PwdNbr::from_str(high).unwrap(),
+            range_lower: PwdNbr::from_str(low)?,
+            range_upper: PwdNbr::from_str(high)?,
         })
     }
 }
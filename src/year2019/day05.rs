@@ -1,38 +1,64 @@
 //! # 2019 day 5 - Sunny with a Chance of Asteroids
+//!
+//! The system ID of part 2 is the parameter `system_id`, so the part 2 examples can be run with
+//! the inputs given in the puzzle description.
 use super::intcode::*;
-use std::{error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
-}
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("system_id", "5")];
 
-struct InputData {
-    program: Vec<isize>,
-}
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE).part2("999").params("system_id=7"),
+        Example::new(EXAMPLE).part2("1000").params("system_id=8"),
+        Example::new(EXAMPLE).part2("1001").params("system_id=9"),
+    ];
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            program: s.split(',').map(|c| c.parse().unwrap()).collect(),
-        })
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
     }
-}
 
-impl InputData {
-    fn solve_part1(&self) -> isize {
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("system_id")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
         self.get_diagnostic(1)
+            .map_or(Answer::NotApplicable, Answer::from)
     }
 
-    fn solve_part2(&self) -> isize {
-        self.get_diagnostic(5)
+    fn part2(&self) -> impl Into<Answer> {
+        self.get_diagnostic(self.system_id)
+            .map_or(Answer::NotApplicable, Answer::from)
     }
+}
+
+pub struct InputData {
+    program: Vec<isize>,
+    system_id: isize,
+}
 
-    fn get_diagnostic(&self, instruction: isize) -> isize {
+impl InputData {
+    fn parse_input(input: &str, system_id: isize) -> Result<Self, ParseError> {
+        Ok(Self {
+            program: input
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+            system_id,
+        })
+    }
+
+    /// Returns `None` if the program hits an invalid instruction.
+    fn get_diagnostic(&self, instruction: isize) -> Option<isize> {
         let mut cpu = Intcode::new(&self.program);
         loop {
             let state = cpu.run();
@@ -42,36 +68,25 @@ impl InputData {
                 }
                 State::Output(v) => {
                     if v != 0 {
-                        break v;
+                        break Some(v);
                     }
                 }
-                State::Halted => break 0,
+                State::Halted => break Some(0),
+                State::Fault => break None,
             }
         }
     }
 }
 
+const EXAMPLE: &str = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_diagnostic(7), 999);
-    }
-
-    #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_diagnostic(8), 1000);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_3() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_diagnostic(9), 1001);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,46 +1,88 @@
 //! # 2019 day 6 - Universal Orbit Map
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("42"),
+        Example::new(EXAMPLE_2).part2("4"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{word}){word}"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     orbits: HashMap<&'a str, Vec<&'a str>>,
     orbiting: HashMap<&'a str, &'a str>,
-    you: &'a str,
-    santa: &'a str,
+    you: Option<&'a str>,
+    santa: Option<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let mut orbits = HashMap::new();
         let mut orbiting = HashMap::new();
         let mut you = None;
         let mut santa = None;
         for line in s.lines() {
-            let (left, right) = line.split_once(')').unwrap();
+            let (left, right) = parse::split_once(line, ")")?;
             orbits
                 .entry(left)
                 .and_modify(|v: &mut Vec<&str>| v.push(right))
                 .or_insert(vec![right]);
-            orbiting.insert(right, left);
+            if right == "COM" {
+                return Err(ParseError::new("the center of mass orbits nothing", right));
+            }
+            if orbiting.insert(right, left).is_some() {
+                return Err(ParseError::new(
+                    "the object already orbits an object",
+                    right,
+                ));
+            }
             if right == "YOU" {
                 you = Some(left);
             } else if right == "SAN" {
                 santa = Some(left);
             }
         }
+        // Each object must be in a chain of orbits leading to the center of mass
+        for (object, center) in &orbiting {
+            let mut current = *center;
+            for steps in 0.. {
+                if current == "COM" {
+                    break;
+                }
+                if steps == orbiting.len() {
+                    return Err(ParseError::new("the object is on a loop of orbits", object));
+                }
+                current = orbiting
+                    .get(current)
+                    .ok_or_else(|| ParseError::new("unknown object", current))?;
+            }
+        }
         Ok(Self {
             orbits,
             orbiting,
-            you: you.unwrap_or_default(),
-            santa: santa.unwrap_or_default(),
+            you,
+            santa,
         })
     }
 }
@@ -50,10 +92,7 @@ impl<'a> InputData<'a> {
         if let Some(cache) = seen.get(obj) {
             *cache
         } else if self.orbits.contains_key(obj) {
-            let res = self
-                .orbits
-                .get(obj)
-                .unwrap()
+            let res = self.orbits[obj]
                 .iter()
                 .map(|v| 1 + self.get_all_orbits(v, seen))
                 .sum();
@@ -71,30 +110,28 @@ impl<'a> InputData<'a> {
             .sum()
     }
 
-    fn solve_part2(&self) -> usize {
-        let mut you_path = Vec::from([self.you]);
-        while you_path.last().unwrap().ne(&"COM") {
-            you_path.push(self.orbiting.get(you_path.last().unwrap()).unwrap());
-        }
-        let mut santa_path = Vec::from([self.santa]);
-        while santa_path.last().unwrap().ne(&"COM") {
-            santa_path.push(self.orbiting.get(santa_path.last().unwrap()).unwrap());
+    /// Returns the objects from `obj` down to the center of mass.
+    fn path_to_com(&self, obj: &'a str) -> Vec<&'a str> {
+        let mut path = Vec::from([obj]);
+        while let Some(center) = self.orbiting.get(path[path.len() - 1]) {
+            path.push(center);
         }
-        while you_path.last() == santa_path.last() {
+        path
+    }
+
+    /// Returns `None` if you or Santa are missing from the map.
+    fn solve_part2(&self) -> Option<usize> {
+        let mut you_path = self.path_to_com(self.you?);
+        let mut santa_path = self.path_to_com(self.santa?);
+        while !you_path.is_empty() && you_path.last() == santa_path.last() {
             you_path.pop();
             santa_path.pop();
         }
-        you_path.len() + santa_path.len()
+        Some(you_path.len() + santa_path.len())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "COM)B
+const EXAMPLE_1: &str = "COM)B
 B)C
 C)D
 D)E
@@ -105,13 +142,8 @@ D)I
 E)J
 J)K
 K)L";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 42);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "COM)B
+const EXAMPLE_2: &str = "COM)B
 B)C
 C)D
 D)E
@@ -124,7 +156,23 @@ J)K
 K)L
 K)YOU
 I)SAN";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
+    }
+
+    #[test]
+    fn invalid_input() {
+        let testdata = "COM)B\nC)D";
+        assert!(InputData::try_from(testdata).is_err());
+
+        let testdata = "COM)B\nB)C\nD)E\nE)D";
+        assert!(InputData::try_from(testdata).is_err());
     }
 }
@@ -9,6 +9,8 @@ pub enum State {
     Input,
     Output(isize),
     Halted,
+    /// The program hit an invalid instruction or memory address.
+    Fault,
 }
 
 pub struct Intcode {
@@ -17,17 +19,20 @@ pub struct Intcode {
     input_buffer: VecDeque<isize>,
 }
 
+/// An invalid instruction or memory access, which stops the program.
+struct Fault;
+
 enum ParameterMode {
     Position,
     Immediate,
 }
 
 impl ParameterMode {
-    fn from_int(n: isize) -> Self {
+    fn from_int(n: isize) -> Result<Self, Fault> {
         match n % 10 {
-            0 => Self::Position,
-            1 => Self::Immediate,
-            _ => unreachable!(),
+            0 => Ok(Self::Position),
+            1 => Ok(Self::Immediate),
+            _ => Err(Fault),
         }
     }
 }
@@ -46,113 +51,60 @@ impl Intcode {
 
     pub fn run(&mut self) -> State {
         loop {
-            let op_code = self.program[self.i_p];
-            match op_code % 100 {
-                1 => {
-                    // Add
-                    let a_vec: Vec<usize> = (1..=3)
-                        .map(|i| {
-                            self.get_address(
-                                ParameterMode::from_int(op_code / (10_isize.pow(i as u32 + 1))),
-                                i,
-                            )
-                        })
-                        .collect();
-                    self.program[a_vec[2]] = self.program[a_vec[0]] + self.program[a_vec[1]];
-                    self.i_p += 4;
-                }
-                2 => {
-                    // Mul
-                    let a_vec: Vec<usize> = (1..=3)
-                        .map(|i| {
-                            self.get_address(
-                                ParameterMode::from_int(op_code / (10_isize.pow(i as u32 + 1))),
-                                i,
-                            )
-                        })
-                        .collect();
-                    self.program[a_vec[2]] = self.program[a_vec[0]] * self.program[a_vec[1]];
-                    self.i_p += 4;
-                }
-                3 => {
-                    // Input
-                    if let Some(v) = self.input_buffer.pop_front() {
-                        let address = self.get_address(ParameterMode::from_int(op_code / 100), 1);
-                        self.program[address] = v;
-                        self.i_p += 2;
-                    } else {
-                        break State::Input;
-                    }
-                }
-                4 => {
-                    // Output
-                    let address = self.get_address(ParameterMode::from_int(op_code / 100), 1);
-                    let out = self.program[address];
-                    self.i_p += 2;
-                    break State::Output(out);
-                }
-                5 => {
-                    // Jump-if-true
-                    let first_address = self.get_address(ParameterMode::from_int(op_code / 100), 1);
-                    if self.program[first_address] != 0 {
-                        let second_address =
-                            self.get_address(ParameterMode::from_int(op_code / 1000), 2);
-                        self.i_p = self.program[second_address].try_into().unwrap();
-                    } else {
-                        self.i_p += 3;
-                    }
-                }
-                6 => {
-                    // Jump-if-false
-                    let first_address = self.get_address(ParameterMode::from_int(op_code / 100), 1);
-                    if self.program[first_address] == 0 {
-                        let second_address =
-                            self.get_address(ParameterMode::from_int(op_code / 1000), 2);
-                        self.i_p = self.program[second_address].try_into().unwrap();
-                    } else {
-                        self.i_p += 3;
-                    }
-                }
-                7 => {
-                    // Less-than
-                    let a_vec: Vec<usize> = (1..=3)
-                        .map(|i| {
-                            self.get_address(
-                                ParameterMode::from_int(op_code / (10_isize.pow(i as u32 + 1))),
-                                i,
-                            )
-                        })
-                        .collect();
-                    self.program[a_vec[2]] = if self.program[a_vec[0]] < self.program[a_vec[1]] {
-                        1
-                    } else {
-                        0
-                    };
-                    self.i_p += 4;
-                }
-                8 => {
-                    // Equals
-                    let a_vec: Vec<usize> = (1..=3)
-                        .map(|i| {
-                            self.get_address(
-                                ParameterMode::from_int(op_code / (10_isize.pow(i as u32 + 1))),
-                                i,
-                            )
-                        })
-                        .collect();
-                    self.program[a_vec[2]] = if self.program[a_vec[0]] == self.program[a_vec[1]] {
-                        1
-                    } else {
-                        0
-                    };
-                    self.i_p += 4;
-                }
-                _ => {
-                    // Halt
-                    break State::Halted;
+            match self.step() {
+                Ok(None) => {}
+                Ok(Some(state)) => break state,
+                Err(Fault) => break State::Fault,
+            }
+        }
+    }
+
+    /// Executes the instruction at the instruction pointer, returning the state to stop in, if
+    /// any.
+    fn step(&mut self) -> Result<Option<State>, Fault> {
+        let op_code = self.load(self.i_p)?;
+        match op_code % 100 {
+            1 | 2 | 7 | 8 => {
+                // Add, Mul, Less-than and Equals
+                let a = self.load(self.get_address(op_code, 1)?)?;
+                let b = self.load(self.get_address(op_code, 2)?)?;
+                let result = match op_code % 100 {
+                    1 => a.checked_add(b).ok_or(Fault)?,
+                    2 => a.checked_mul(b).ok_or(Fault)?,
+                    7 => isize::from(a < b),
+                    _ => isize::from(a == b),
+                };
+                self.store(self.get_address(op_code, 3)?, result)?;
+                self.i_p += 4;
+            }
+            3 => {
+                // Input
+                let Some(v) = self.input_buffer.pop_front() else {
+                    return Ok(Some(State::Input));
+                };
+                self.store(self.get_address(op_code, 1)?, v)?;
+                self.i_p += 2;
+            }
+            4 => {
+                // Output
+                let out = self.load(self.get_address(op_code, 1)?)?;
+                self.i_p += 2;
+                return Ok(Some(State::Output(out)));
+            }
+            5 | 6 => {
+                // Jump-if-true and Jump-if-false
+                let condition = self.load(self.get_address(op_code, 1)?)? != 0;
+                if condition == (op_code % 100 == 5) {
+                    let target = self.load(self.get_address(op_code, 2)?)?;
+                    self.i_p = usize::try_from(target).map_err(|_| Fault)?;
+                } else {
+                    self.i_p += 3;
                 }
             }
+            99 => return Ok(Some(State::Halted)),
+            _ => return Err(Fault),
         }
+        Ok(None)
     }
 
     pub fn input_value(&mut self, val: isize) {
@@ -167,11 +119,25 @@ impl Intcode {
         self.program[pos]
     }
 
+    fn load(&self, address: usize) -> Result<isize, Fault> {
+        self.program.get(address).copied().ok_or(Fault)
+    }
+
+    fn store(&mut self, address: usize, val: isize) -> Result<(), Fault> {
+        *self.program.get_mut(address).ok_or(Fault)? = val;
+        Ok(())
+    }
+
+    /// Returns the address of parameter `offset` of the instruction `op_code`, as given by its
+    /// parameter mode.
     #[inline]
-    fn get_address(&self, mode: ParameterMode, offset: usize) -> usize {
+    fn get_address(&self, op_code: isize, offset: usize) -> Result<usize, Fault> {
+        let mode = ParameterMode::from_int(op_code / 10_isize.pow(offset as u32 + 1))?;
         match mode {
-            ParameterMode::Position => self.program[self.i_p + offset] as usize,
-            ParameterMode::Immediate => self.i_p + offset,
+            ParameterMode::Position => {
+                usize::try_from(self.load(self.i_p + offset)?).map_err(|_| Fault)
+            }
+            ParameterMode::Immediate => Ok(self.i_p + offset),
         }
     }
 }
@@ -4,4 +4,16 @@ pub mod day03;
 pub mod day04;
 pub mod day05;
 pub mod day06;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2019:
+    1 => day01: "The Tyranny of the Rocket Equation",
+    2 => day02: "1202 Program Alarm",
+    3 => day03: "Crossed Wires",
+    4 => day04: "Secure Container",
+    5 => day05: "Sunny with a Chance of Asteroids",
+    6 => day06: "Universal Orbit Map",
+}
+
 pub mod intcode;
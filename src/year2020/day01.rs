@@ -1,75 +1,89 @@
 //! # 2020 day 1 - Report Repair
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(EXAMPLE).part1("514579").part2("241861950")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 const TARGET: usize = 2020;
 
-struct InputData {
+pub struct InputData {
     reports: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            reports: s.lines().map(|line| line.parse().unwrap()).collect(),
+            reports: parse::lines(s, parse::number)?,
         })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if no two entries sum to the target.
+    fn solve_part1(&self) -> Option<usize> {
         let nbr_reports = self.reports.len();
-        for i in 0..nbr_reports - 1 {
+        for i in 0..nbr_reports {
             for j in i + 1..nbr_reports {
                 if self.reports[i] + self.reports[j] == TARGET {
-                    return self.reports[i] * self.reports[j];
+                    return Some(self.reports[i] * self.reports[j]);
                 }
             }
         }
-        0
+        None
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if no three entries sum to the target.
+    fn solve_part2(&self) -> Option<usize> {
         let nbr_reports = self.reports.len();
-        for i in 0..nbr_reports - 2 {
-            for j in i + 1..nbr_reports - 1 {
+        for i in 0..nbr_reports {
+            for j in i + 1..nbr_reports {
                 let pairsum = self.reports[i] + self.reports[j];
                 if pairsum < TARGET {
                     for k in j + 1..nbr_reports {
                         if pairsum + self.reports[k] == TARGET {
-                            return self.reports[i] * self.reports[j] * self.reports[k];
+                            return Some(self.reports[i] * self.reports[j] * self.reports[k]);
                         }
                     }
                 }
             }
         }
-        0
+        None
     }
 }
 
+const EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "1721\n979\n366\n299\n675\n1456";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 514579);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 241861950);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,12 +1,27 @@
 //! # 2020 day 2 - Password Philosophy
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("2").part2("1")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}-{int} {word}: {word}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -17,14 +32,22 @@ struct Password {
 }
 
 impl FromStr for Password {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut words = s.split_whitespace();
-        let (r1, r2) = words.next().unwrap().split_once('-').unwrap();
+        let (range, rest) = parse::split_once(s, " ")?;
+        let (r1, r2) = parse::split_once(range, "-")?;
+        let (letter, password) = parse::split_once(rest, ": ")?;
+        let nbr_range = (parse::number(r1)?, parse::number(r2)?);
+        if nbr_range.0 == 0 || nbr_range.0 > nbr_range.1 {
+            return Err(ParseError::new(
+                "expected a range `A-B` with 1 <= A <= B",
+                range,
+            ));
+        }
         Ok(Self {
-            nbr_range: (r1.parse().unwrap(), r2.parse().unwrap()),
-            letter: words.next().unwrap().trim_matches(':').parse().unwrap(),
-            password: words.next().unwrap().parse().unwrap(),
+            nbr_range,
+            letter: parse::number(letter)?,
+            password: password.to_string(),
         })
     }
 }
@@ -36,29 +59,20 @@ impl Password {
     }
 
     fn is_valid_new_policy(&self) -> bool {
-        if self.password.len() < self.nbr_range.0 {
-            false
-        } else {
-            let first = self.letter == self.password.chars().nth(self.nbr_range.0 - 1).unwrap();
-            let second = self.password.len() >= self.nbr_range.1
-                && self.letter == self.password.chars().nth(self.nbr_range.1 - 1).unwrap();
-            first ^ second
-        }
+        let has_letter_at = |pos: usize| self.password.chars().nth(pos - 1) == Some(self.letter);
+        has_letter_at(self.nbr_range.0) ^ has_letter_at(self.nbr_range.1)
     }
 }
 
-struct InputData {
+pub struct InputData {
     passwords: Vec<Password>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            passwords: s
-                .lines()
-                .map(|line| Password::from_str(line).unwrap())
-                .collect(),
+            passwords: parse::lines(s, Password::from_str)?,
         })
     }
 }
@@ -79,23 +93,17 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "1-3 a: abcde
+const EXAMPLE: &str = "1-3 a: abcde
 1-3 b: cdefg
 2-9 c: ccccccccc";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,24 +1,39 @@
 //! # 2020 day 3 - Toboggan Trajectory
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("7").part2("336")];
+
+    const SHAPE: Shape = Shape::Grid(".#");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            grid: Grid::parse(s),
+            grid: Grid::parse(s)?,
         })
     }
 }
@@ -28,7 +43,7 @@ impl InputData {
         let mut pos = ORIGIN;
         let mut count = 0;
         while let Some(e) = self.grid.get_element(&pos) {
-            if e == '#' {
+            if e == b'#' {
                 count += 1;
             }
             pos += *step;
@@ -49,11 +64,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "..##.......
+const EXAMPLE: &str = "..##.......
 #...#...#..
 .#....#..#.
 ..#.#...#.#
@@ -65,15 +76,13 @@ mod tests {
 #...##....#
 .#..#...#.#";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 336);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,10 +1,31 @@
 //! # 2020 day 4 - Passport Processing
-use std::{collections::HashMap, error::Error, num::ParseIntError, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{collections::HashMap, error::Error, str::FromStr};
+
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2"),
+        Example::new(EXAMPLE_2).part2("0"),
+        Example::new(EXAMPLE_3).part2("4"),
+    ];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]:{any}"]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
@@ -20,7 +41,7 @@ enum Field {
 }
 
 impl FromStr for Field {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "byr" => Ok(Self::Byr),
@@ -31,7 +52,7 @@ impl FromStr for Field {
             "ecl" => Ok(Self::Ecl),
             "pid" => Ok(Self::Pid),
             "cid" => Ok(Self::Cid),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected a passport field", s)),
         }
     }
 }
@@ -91,85 +112,64 @@ struct Passport {
 }
 
 impl FromStr for Passport {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             fields: s
                 .split_whitespace()
                 .map(|f| {
-                    let (left, right) = f.split_once(':').unwrap();
-                    (Field::from_str(left).unwrap(), right.to_string())
+                    let (left, right) = parse::split_once(f, ":")?;
+                    Ok((Field::from_str(left)?, right.to_string()))
                 })
-                .collect(),
+                .collect::<Result<_, ParseError>>()?,
         })
     }
 }
 
-enum Validity {
-    Relaxed,
-    Strict,
-    Invalid,
-}
-
 impl Passport {
-    fn validity(&self) -> Validity {
-        if self.fields.len() == 8
-            || (self.fields.len() == 7 && !self.fields.contains_key(&Field::Cid))
-        {
-            for (field, value) in &self.fields {
-                if !field.is_valid(value) {
-                    return Validity::Relaxed;
-                }
-            }
-            return Validity::Strict;
-        }
-        Validity::Invalid
+    fn has_required_fields(&self) -> bool {
+        self.fields.len() == 8 || (self.fields.len() == 7 && !self.fields.contains_key(&Field::Cid))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.has_required_fields()
+            && self
+                .fields
+                .iter()
+                .all(|(field, value)| field.is_valid(value))
     }
 }
 
-struct InputData {
+pub struct InputData {
     passports: Vec<Passport>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             passports: s
                 .split("\n\n")
-                .map(|line| Passport::from_str(line).unwrap())
-                .collect(),
+                .map(Passport::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut p1 = 0;
-        let mut p2 = 0;
-        for p in &self.passports {
-            match p.validity() {
-                Validity::Relaxed => {
-                    p1 += 1;
-                }
-                Validity::Strict => {
-                    p1 += 1;
-                    p2 += 1;
-                }
-                Validity::Invalid => (),
-            }
-        }
-        (p1, p2)
+    fn solve_part1(&self) -> usize {
+        self.passports
+            .iter()
+            .filter(|p| p.has_required_fields())
+            .count()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part2(&self) -> usize {
+        self.passports.iter().filter(|p| p.is_valid()).count()
+    }
+}
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+const EXAMPLE_1: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
 byr:1937 iyr:2017 cid:147 hgt:183cm
 
 iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
@@ -182,14 +182,8 @@ hgt:179cm
 
 hcl:#cfa07d eyr:2025 pid:166559648
 iyr:2011 ecl:brn hgt:59in";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 2);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "eyr:1972 cid:100
+const EXAMPLE_2: &str = "eyr:1972 cid:100
 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
 
 iyr:2019
@@ -202,14 +196,8 @@ ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
 hgt:59cm ecl:zzz
 eyr:2038 hcl:74454a iyr:2023
 pid:3556412378 byr:2007";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 0);
-    }
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+const EXAMPLE_3: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
 hcl:#623a2f
 
 eyr:2029 ecl:blu cid:129 byr:1989
@@ -221,8 +209,14 @@ pid:545766238 ecl:hzl
 eyr:2022
 
 iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
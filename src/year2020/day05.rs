@@ -1,25 +1,56 @@
 //! # 2020 day 5 - Binary Boarding
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("567"),
+        Example::new(EXAMPLE_2).part1("119"),
+        Example::new(EXAMPLE_3).part1("820"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[FB][LR]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     boardingpasses: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut boardingpasses: Vec<_> = s
-            .lines()
-            .map(|line| decode_seat_id(&line[0..7], &line[7..10]))
-            .collect();
+        let mut boardingpasses = parse::lines(s, |line| {
+            let valid = line.len() == 10
+                && line[..7].bytes().all(|b| b == b'F' || b == b'B')
+                && line[7..].bytes().all(|b| b == b'L' || b == b'R');
+            if !valid {
+                return Err(ParseError::new(
+                    "expected 7 letters F or B followed by 3 letters L or R",
+                    line,
+                ));
+            }
+            Ok(decode_seat_id(&line[0..7], &line[7..10]))
+        })?;
+        if boardingpasses.is_empty() {
+            return Err(ParseError::missing("missing boarding passes", s));
+        }
         boardingpasses.sort_unstable();
         Ok(Self { boardingpasses })
     }
@@ -43,38 +74,31 @@ fn decode_seat_id(rows: &str, cols: &str) -> usize {
 
 impl InputData {
     fn solve_part1(&self) -> usize {
-        *self.boardingpasses.last().unwrap()
+        self.boardingpasses[self.boardingpasses.len() - 1]
     }
 
-    fn solve_part2(&self) -> usize {
-        for w in self.boardingpasses.windows(2) {
-            if w[1] == w[0] + 2 {
-                return w[0] + 1;
-            }
-        }
-        0
+    /// Returns `None` if no seat is missing between two others.
+    fn solve_part2(&self) -> Option<usize> {
+        self.boardingpasses
+            .windows(2)
+            .find(|w| w[1] == w[0] + 2)
+            .map(|w| w[0] + 1)
     }
 }
 
+const EXAMPLE_1: &str = "BFFFBBFRRR";
+
+const EXAMPLE_2: &str = "FFFBBBFRRR";
+
+const EXAMPLE_3: &str = "BBFFBBFRLL";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str("BFFFBBFRRR").unwrap();
-        assert_eq!(solution_data.solve_part1(), 567);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::from_str("FFFBBBFRRR").unwrap();
-        assert_eq!(solution_data.solve_part1(), 119);
-    }
-
-    #[test]
-    fn part1_example_3() {
-        let solution_data = InputData::from_str("BBFFBBFRLL").unwrap();
-        assert_eq!(solution_data.solve_part1(), 820);
+    fn examples() {
+        check::<InputData>();
     }
 }
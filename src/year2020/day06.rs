@@ -1,12 +1,30 @@
 //! # 2020 day 6 - Custom Customs
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("6"),
+        Example::new(EXAMPLE_2).part1("11").part2("6"),
+    ];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Group {
@@ -14,10 +32,19 @@ struct Group {
 }
 
 impl FromStr for Group {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::missing("missing answers", s));
+        }
         Ok(Self {
-            answers: s.lines().map(str::to_string).collect(),
+            answers: parse::lines(s, |line| {
+                match line.find(|c: char| !c.is_ascii_lowercase()) {
+                    Some(i) => Err(ParseError::new("expected a lowercase letter", &line[i..])),
+                    None if line.is_empty() => Err(ParseError::missing("missing answers", line)),
+                    None => Ok(line.to_string()),
+                }
+            })?,
         })
     }
 }
@@ -41,18 +68,18 @@ impl Group {
     }
 }
 
-struct InputData {
+pub struct InputData {
     forms: Vec<Group>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             forms: s
                 .split("\n\n")
-                .map(|g| Group::from_str(g).unwrap())
-                .collect(),
+                .map(Group::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -67,32 +94,21 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "abcx\nabcy\nabcz";
 
-    const TEST_DATA_1: &str = "abcx\nabcy\nabcz";
-    const TEST_DATA_2: &str = "abc\n
+const EXAMPLE_2: &str = "abc\n
 a\nb\nc\n
 ab\nac\n
 a\na\na\na\n
 b";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 6);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part1(), 11);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 6);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,42 +1,87 @@
 //! # 2020 day 7 - Handy Haversacks
-use std::{collections::HashMap, error::Error};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("4").part2("32"),
+        Example::new(EXAMPLE_2).part2("126"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{any} bags contain {any}."]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     bags: HashMap<&'a str, Vec<(&'a str, usize)>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let mut bags = HashMap::new();
         for line in s.lines() {
-            let (left, right) = line.split_once(" bags contain ").unwrap();
-            bags.insert(
-                left,
+            let (left, right) = parse::split_once(line, " bags contain ")?;
+            let contents = if right == "no other bags." {
+                Vec::new()
+            } else {
                 right
                     .split(", ")
-                    .filter_map(|item| {
-                        let (nbr, bagstr) = item.split_once(' ').unwrap();
-                        if let Ok(c) = nbr.parse() {
-                            let color = bagstr
-                                .trim_end_matches('.')
-                                .trim_end_matches('s')
-                                .trim_end_matches(" bag");
-                            Some((color, c))
-                        } else {
-                            None
-                        }
+                    .map(|item| {
+                        let (nbr, bagstr) = parse::split_once(item, " ")?;
+                        let color = bagstr
+                            .trim_end_matches('.')
+                            .trim_end_matches('s')
+                            .trim_end_matches(" bag");
+                        Ok((color, parse::number(nbr)?))
                     })
-                    .collect(),
-            );
+                    .collect::<Result<_, ParseError>>()?
+            };
+            bags.insert(left, contents);
+        }
+        for contents in bags.values() {
+            if let Some((color, _)) = contents.iter().find(|(c, _)| !bags.contains_key(c)) {
+                return Err(ParseError::new("unknown bag color", color));
+            }
+        }
+        // Resolve the bags from the inside out, which fails if a bag eventually contains itself
+        let mut resolved = HashSet::new();
+        loop {
+            let before = resolved.len();
+            for (bag, contents) in &bags {
+                if contents.iter().all(|(c, _)| resolved.contains(c)) {
+                    resolved.insert(*bag);
+                }
+            }
+            match bags.keys().find(|b| !resolved.contains(*b)) {
+                None => break,
+                Some(bag) if resolved.len() == before => {
+                    return Err(ParseError::new(
+                        "the bags contain each other in a loop",
+                        bag,
+                    ));
+                }
+                _ => {}
+            }
         }
         Ok(Self { bags })
     }
@@ -44,7 +89,7 @@ impl<'a> TryFrom<&'a str> for InputData<'a> {
 
 impl InputData<'_> {
     fn bag_contains_color(&self, bag: &str, target: &str) -> bool {
-        for &(content, _) in self.bags.get(bag).unwrap().iter() {
+        for &(content, _) in self.bags[bag].iter() {
             if content == target || self.bag_contains_color(content, target) {
                 return true;
             }
@@ -53,9 +98,7 @@ impl InputData<'_> {
     }
 
     fn count_bag(&self, bag: &str) -> usize {
-        self.bags
-            .get(bag)
-            .unwrap()
+        self.bags[bag]
             .iter()
             .map(|&(b, c)| c * (1 + self.count_bag(b)))
             .sum()
@@ -68,16 +111,15 @@ impl InputData<'_> {
             .count()
     }
 
-    fn solve_part2(&self) -> usize {
-        self.count_bag("shiny gold")
+    /// Returns `None` if there are no rules for the shiny gold bag.
+    fn solve_part2(&self) -> Option<usize> {
+        self.bags
+            .contains_key("shiny gold")
+            .then(|| self.count_bag("shiny gold"))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+const EXAMPLE_1: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
 dark orange bags contain 3 bright white bags, 4 muted yellow bags.
 bright white bags contain 1 shiny gold bag.
 muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
@@ -87,7 +129,7 @@ vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
 faded blue bags contain no other bags.
 dotted black bags contain no other bags.";
 
-    const TEST_DATA_2: &str = "shiny gold bags contain 2 dark red bags.
+const EXAMPLE_2: &str = "shiny gold bags contain 2 dark red bags.
 dark red bags contain 2 dark orange bags.
 dark orange bags contain 2 dark yellow bags.
 dark yellow bags contain 2 dark green bags.
@@ -95,21 +137,13 @@ dark green bags contain 2 dark blue bags.
 dark blue bags contain 2 dark violet bags.
 dark violet bags contain no other bags.";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part2(), 32);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::try_from(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 126);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -2,14 +2,32 @@
 //!
 //! Nothing too fancy, basically brute force that runs fast anyway.
 //! For part 2 just try swapping the instructions one by one until it works.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let mut solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("5").part2("8")];
+
+    const SHAPE: Shape = Shape::Lines(&["acc [+-]{int}", "jmp [+-]{int}", "nop [+-]{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.clone()
+            .solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Clone)]
@@ -20,15 +38,15 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(' ').unwrap();
-        let right: isize = right.parse().unwrap();
+        let (left, right) = parse::split_once(s, " ")?;
+        let right: isize = parse::number(right)?;
         match left {
             "jmp" => Ok(Self::Jmp(right)),
             "acc" => Ok(Self::Acc(right)),
             "nop" => Ok(Self::Nop(right)),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected `jmp`, `acc` or `nop`", left)),
         }
     }
 }
@@ -36,20 +54,20 @@ impl FromStr for Instruction {
 enum ProgramState {
     Loop(isize),
     Halted(isize),
+    /// The program jumped to an instruction outside of it, other than right after its end.
+    OutOfBounds,
 }
 
-struct InputData {
+#[derive(Clone)]
+pub struct InputData {
     program: Vec<Instruction>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            program: s
-                .lines()
-                .map(|line| Instruction::from_str(line).unwrap())
-                .collect(),
+            program: parse::lines(s, Instruction::from_str)?,
         })
     }
 }
@@ -61,6 +79,12 @@ impl InputData {
         let mut accumulator = 0;
         let mut pc: isize = 0;
         loop {
+            if pc == p_len {
+                return ProgramState::Halted(accumulator);
+            }
+            if !(0..p_len).contains(&pc) {
+                return ProgramState::OutOfBounds;
+            }
             if seen[pc as usize] {
                 return ProgramState::Loop(accumulator);
             }
@@ -77,23 +101,19 @@ impl InputData {
                     pc += 1;
                 }
             }
-            if pc == p_len {
-                return ProgramState::Halted(accumulator);
-            }
-            if !(0..p_len).contains(&pc) {
-                panic!(); // Should never happen with correct input
-            }
         }
     }
 
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if the program doesn't loop.
+    fn solve_part1(&self) -> Option<isize> {
         match self.run_program() {
-            ProgramState::Loop(v) => v as usize,
-            _ => 0,
+            ProgramState::Loop(v) => Some(v),
+            _ => None,
         }
     }
 
-    fn solve_part2(&mut self) -> usize {
+    /// Returns `None` if no single swap of `jmp` and `nop` makes the program halt.
+    fn solve_part2(&mut self) -> Option<isize> {
         for i in 0..self.program.len() {
             let instr = self.program[i].clone();
             match instr {
@@ -104,18 +124,14 @@ impl InputData {
             let result = self.run_program();
             self.program[i] = instr;
             if let ProgramState::Halted(a) = result {
-                return a as usize;
+                return Some(a);
             }
         }
-        2
+        None
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "nop +0
+const EXAMPLE: &str = "nop +0
 acc +1
 jmp +4
 acc +3
@@ -125,15 +141,13 @@ acc +1
 jmp -4
 acc +6";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let mut solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 8);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,30 +1,59 @@
 //! # 2020 day 9 - Encoding Error
-use std::{cmp::Ordering, error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{cmp::Ordering, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve(25);
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("preamble", "25")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("127")
+        .part2("62")
+        .params("preamble=5")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get_in("preamble", 2..)?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.find_invalid(self.preamble)
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2(self.preamble)
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     numbers: Vec<usize>,
+    preamble: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl InputData {
+    fn parse_input(input: &str, preamble: usize) -> Result<Self, ParseError> {
         Ok(Self {
-            numbers: s.lines().map(|line| line.parse().unwrap()).collect(),
+            numbers: parse::lines(input, parse::number)?,
+            preamble,
         })
     }
-}
 
-impl InputData {
-    fn solve(&self, preamble_len: usize) -> (usize, usize) {
-        let invalid_number = self
-            .numbers
-            .windows(preamble_len + 1)
+    /// Returns the first number which isn't the sum of two of the `preamble_len` numbers before it,
+    /// or `None` if there is no such number.
+    fn find_invalid(&self, preamble_len: usize) -> Option<usize> {
+        self.numbers
+            .windows(preamble_len.checked_add(1)?)
             .find(|preamble| {
                 for i in 0..preamble_len - 1 {
                     for j in i..preamble_len {
@@ -36,16 +65,21 @@ impl InputData {
                 true
             })
             .map(|preamble| preamble[preamble_len])
-            .unwrap();
+    }
+
+    /// Returns `None` if there is no invalid number, or no range of at least two numbers summing to
+    /// it.
+    fn solve_part2(&self, preamble_len: usize) -> Option<usize> {
+        let invalid_number = self.find_invalid(preamble_len)?;
 
-        let mut window_sum = self.numbers[0] + self.numbers[1];
+        let mut window_sum = 0;
         let mut window_low = 0;
-        let mut window_high = 2;
+        let mut window_high = 0;
         loop {
             match window_sum.cmp(&invalid_number) {
-                Ordering::Equal => break,
-                Ordering::Less => {
-                    window_sum += self.numbers[window_high];
+                Ordering::Equal if window_high - window_low >= 2 => break,
+                Ordering::Less | Ordering::Equal => {
+                    window_sum += self.numbers.get(window_high)?;
                     window_high += 1;
                 }
                 Ordering::Greater => {
@@ -55,26 +89,21 @@ impl InputData {
             }
         }
 
-        (
-            invalid_number,
-            self.numbers[window_low..window_high].iter().min().unwrap()
-                + self.numbers[window_low..window_high].iter().max().unwrap(),
-        )
+        let window = &self.numbers[window_low..window_high];
+        Some(window.iter().min()? + window.iter().max()?)
     }
 }
 
+const EXAMPLE: &str =
+    "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str =
-        "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576";
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        let (p1, p2) = solution_data.solve(5);
-        assert_eq!(p1, 127);
-        assert_eq!(p2, 62);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,26 +1,47 @@
 //! # 2020 day 10 - Adapter Array
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("35").part2("8"),
+        Example::new(EXAMPLE_2).part1("220").part2("19208"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     adapters: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut adapters: Vec<usize> = s.lines().map(|line| line.parse().unwrap()).collect();
+        let mut adapters: Vec<usize> = parse::lines(s, parse::number)?;
+        let largest = *adapters
+            .iter()
+            .max()
+            .ok_or_else(|| ParseError::missing("missing adapters", s))?;
         adapters.push(0);
+        adapters.push(largest + 3);
         adapters.sort_unstable();
-        let largest = adapters.last().unwrap();
-        adapters.push(*largest + 3);
         Ok(Self { adapters })
     }
 }
@@ -34,7 +55,7 @@ impl InputData {
                 .and_modify(|e| *e += 1)
                 .or_insert(1);
         });
-        diffs.get(&1).unwrap() * diffs.get(&3).unwrap()
+        diffs.get(&1).unwrap_or(&0) * diffs.get(&3).unwrap_or(&0)
     }
 
     fn solve_part2(&self) -> usize {
@@ -51,11 +72,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "16
+const EXAMPLE_1: &str = "16
 10
 15
 5
@@ -67,7 +84,7 @@ mod tests {
 12
 4";
 
-    const TEST_DATA_2: &str = "28
+const EXAMPLE_2: &str = "28
 33
 18
 42
@@ -99,27 +116,13 @@ mod tests {
 10
 3";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7 * 5);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part1(), 22 * 10);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part2(), 8);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 19208);
+    fn examples() {
+        check::<InputData>();
     }
 }
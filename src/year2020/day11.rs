@@ -7,26 +7,41 @@
 //! The scan loops have been combined as much as possible both to shrink the
 //! code and to minimize the number of loops, at the cost of making it a
 //! bit harder to understand the logic from reading the code.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("37").part2("26")];
+
+    const SHAPE: Shape = Shape::Grid("L.#");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            grid: Grid::parse(s),
+            grid: Grid::parse(s)?,
         })
     }
 }
@@ -38,13 +53,14 @@ impl InputData {
         let neighbor_limit = if only_adjacent { 4 } else { 5 };
         loop {
             let mut scan = |start: &Point, dir: &Point| {
-                let mut prev = 'L';
+                let mut prev = b'L';
                 let mut current_point = *start;
-                while let Some(c) = current.get_element(&current_point) {
-                    if prev == '#' {
-                        counts[current.get_index(&current_point)] += 1;
+                while let Some(i) = current.get_index(&current_point) {
+                    let c = current.elements[i];
+                    if prev == b'#' {
+                        counts[i] += 1;
                     }
-                    if c != '.' || only_adjacent {
+                    if c != b'.' || only_adjacent {
                         prev = c;
                     }
                     current_point += *dir;
@@ -75,25 +91,25 @@ impl InputData {
                 .iter()
                 .enumerate()
                 .map(|(i, v)| match current.elements[i] {
-                    '#' => {
+                    b'#' => {
                         if *v >= neighbor_limit {
-                            'L'
+                            b'L'
                         } else {
-                            '#'
+                            b'#'
                         }
                     }
-                    'L' => {
+                    b'L' => {
                         if *v == 0 {
-                            '#'
+                            b'#'
                         } else {
-                            'L'
+                            b'L'
                         }
                     }
-                    _ => '.',
+                    _ => b'.',
                 })
                 .collect();
             if next == current.elements {
-                return next.iter().filter(|c| **c == '#').count();
+                return next.iter().filter(|c| **c == b'#').count();
             }
             current.elements = next;
             counts.fill(0);
@@ -109,11 +125,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "L.LL.LL.LL
+const EXAMPLE: &str = "L.LL.LL.LL
 LLLLLLL.LL
 L.L.L..L..
 LLLL.LL.LL
@@ -124,15 +136,13 @@ LLLLLLLLLL
 L.LLLLLL.L
 L.LLLLL.LL";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 37);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 26);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,13 +1,28 @@
 //! # 2020 day 12 - Rain Risk
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("25").part2("286")];
+
+    const SHAPE: Shape = Shape::Lines(&["[NSEWLRF]{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -19,34 +34,37 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let value = s[1..].parse().unwrap();
-        match s.chars().nth(0).unwrap() {
-            'N' => Ok(Self::MoveDir(UP, value)),
-            'S' => Ok(Self::MoveDir(DOWN, value)),
-            'E' => Ok(Self::MoveDir(RIGHT, value)),
-            'W' => Ok(Self::MoveDir(LEFT, value)),
-            'L' => Ok(Self::TurnLeft(value)),
-            'R' => Ok(Self::TurnRight(value)),
-            'F' => Ok(Self::MoveForward(value)),
-            _ => Err(()),
+        let (action, value) = s
+            .split_at_checked(1)
+            .ok_or_else(|| ParseError::new("expected an instruction", s))?;
+        let value = parse::number(value)?;
+        match action {
+            "N" => Ok(Self::MoveDir(UP, value)),
+            "S" => Ok(Self::MoveDir(DOWN, value)),
+            "E" => Ok(Self::MoveDir(RIGHT, value)),
+            "W" => Ok(Self::MoveDir(LEFT, value)),
+            "L" => Ok(Self::TurnLeft(value)),
+            "R" => Ok(Self::TurnRight(value)),
+            "F" => Ok(Self::MoveForward(value)),
+            _ => Err(ParseError::new("expected N, S, E, W, L, R or F", action)),
         }
     }
 }
 
-struct InputData {
+pub struct InputData {
     instructions: Vec<Instruction>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             instructions: s
                 .lines()
-                .map(|line| Instruction::from_str(line).unwrap())
-                .collect(),
+                .map(Instruction::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -95,25 +113,19 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "F10
+const EXAMPLE: &str = "F10
 N3
 F7
 R90
 F11";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 25);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 286);
+    fn examples() {
+        check::<InputData>();
     }
 }
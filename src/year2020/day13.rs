@@ -1,79 +1,95 @@
 //! # 2020 day 13 - Shuttle Search
 //!
 //! Chinese remainder theorem solution.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("295").part2("1068781")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}", "[0123456789x,]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     estimate: usize,
     buslist: Vec<(usize, usize)>, // busid, offset-idx
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
-        let estimate = lines.next().unwrap().parse().unwrap();
-        let buslist = lines
-            .next()
-            .unwrap()
+        let estimate = parse::number(parse::next(&mut lines, "timestamp", s)?)?;
+        let buses = parse::next(&mut lines, "bus list", s)?;
+        let buslist: Vec<_> = buses
             .split(',')
             .enumerate()
             .filter(|(_, v)| *v != "x")
-            .map(|(i, v)| (v.parse().unwrap(), i))
-            .collect();
+            .map(|(i, v)| match parse::number(v)? {
+                0 => Err(ParseError::new("expected a positive bus ID", v)),
+                id => Ok((id, i)),
+            })
+            .collect::<Result<_, ParseError>>()?;
+        if buslist.is_empty() {
+            return Err(ParseError::new("missing bus IDs", buses));
+        }
         Ok(Self { estimate, buslist })
     }
 }
 
 impl InputData {
     fn solve_part1(&self) -> usize {
-        let best_bus = self
-            .buslist
+        self.buslist
             .iter()
             .map(|(id, _)| (id - (self.estimate % id), *id))
             .min()
-            .unwrap();
-        best_bus.0 * best_bus.1
+            .map_or(0, |(wait, id)| wait * id)
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if the buses never depart at the given offsets, which may happen when the
+    /// bus IDs aren't coprime.
+    fn solve_part2(&self) -> Option<usize> {
         let mut coefficient = self.buslist[0].0;
         let mut time = self.buslist[0].1;
         for (busid, idx) in &self.buslist[1..] {
-            let remainder = busid - idx % busid;
-            while time % busid != remainder {
-                time += coefficient;
-            }
-            coefficient *= busid;
+            let remainder = (busid - idx % busid) % busid;
+            // The remainders of the candidate times repeat after at most `busid` steps
+            time = (0..*busid)
+                .map(|step| time + step * coefficient)
+                .find(|t| t % busid == remainder)?;
+            coefficient = coefficient.checked_mul(*busid)?;
         }
-        time
+        Some(time)
     }
 }
 
+const EXAMPLE: &str = "939
+7,13,x,x,59,x,31,19";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "939
-7,13,x,x,59,x,31,19";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 295);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1068781);
+    fn examples() {
+        check::<InputData>();
     }
 }
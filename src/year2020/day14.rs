@@ -1,16 +1,34 @@
 //! # 2020 day 14 - Docking Data
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     collections::{HashMap, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("165"),
+        Example::new(EXAMPLE_2).part2("208"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["mask = [01X]", "mem[[]{int}] = {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum Instruction {
@@ -19,51 +37,47 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match &s[..3] {
-            "mas" => {
-                let (_, right) = s.split_once(" = ").unwrap();
-                let mut ones_mask = 0;
-                let mut x_mask = 0;
-                for (i, c) in right.chars().rev().enumerate() {
-                    match c {
-                        '1' => {
-                            ones_mask += 1 << i;
-                        }
-                        'X' => {
-                            x_mask += 1 << i;
-                        }
-                        _ => (),
+        if let Some(right) = s.strip_prefix("mask = ") {
+            let mut ones_mask = 0;
+            let mut x_mask = 0;
+            for (i, c) in right.chars().rev().enumerate() {
+                match c {
+                    '1' => {
+                        ones_mask += 1 << i;
                     }
+                    'X' => {
+                        x_mask += 1 << i;
+                    }
+                    _ => (),
                 }
-                Ok(Self::Mask { ones_mask, x_mask })
-            }
-            "mem" => {
-                let (left, right) = s.split_once(" = ").unwrap();
-                let left = left.trim_end_matches(']').trim_start_matches("mem[");
-                Ok(Self::Mem {
-                    address: left.parse().unwrap(),
-                    value: right.parse().unwrap(),
-                })
             }
-            _ => Err(()),
+            Ok(Self::Mask { ones_mask, x_mask })
+        } else if let Some(mem) = s.strip_prefix("mem[") {
+            let (left, right) = parse::split_once(mem, "] = ")?;
+            Ok(Self::Mem {
+                address: parse::number(left)?,
+                value: parse::number(right)?,
+            })
+        } else {
+            Err(ParseError::new("expected `mask` or `mem`", s))
         }
     }
 }
 
-struct InputData {
+pub struct InputData {
     instructions: Vec<Instruction>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             instructions: s
                 .lines()
-                .map(|line| Instruction::from_str(line).unwrap())
-                .collect(),
+                .map(Instruction::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -123,27 +137,23 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+const EXAMPLE_1: &str = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
 mem[8] = 11
 mem[7] = 101
 mem[8] = 0";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 165);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "mask = 000000000000000000000000000000X1001X
+const EXAMPLE_2: &str = "mask = 000000000000000000000000000000X1001X
 mem[42] = 100
 mask = 00000000000000000000000000000000X0XX
 mem[26] = 1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 208);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -3,25 +3,50 @@
 //! Basically the Van Eck sequence. Seems to require brute-forcing, so part 2
 //! is really slow and uses a lot of RAM to keep track of the counters for each
 //! number. Use u32 rather than usize to at least keep it somewhat under control.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    // The part 2 examples are skipped, since they're really slow and anyway just a lot more
+    // iterations of the same function.
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("436"),
+        Example::new(EXAMPLE_2).part1("1"),
+        Example::new(EXAMPLE_3).part1("10"),
+        Example::new(EXAMPLE_4).part1("27"),
+        Example::new(EXAMPLE_5).part1("78"),
+        Example::new(EXAMPLE_6).part1("438"),
+        Example::new(EXAMPLE_7).part1("1836"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     startlist: Vec<u32>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            startlist: s.split(',').map(|line| line.parse().unwrap()).collect(),
+            startlist: s.split(',').map(parse::number).collect::<Result<_, _>>()?,
         })
     }
 }
@@ -58,59 +83,27 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "0,3,6";
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "0,3,6";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 436);
-    }
+const EXAMPLE_2: &str = "1,3,2";
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "1,3,2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
+const EXAMPLE_3: &str = "2,1,3";
 
-    #[test]
-    fn part1_example_3() {
-        let testdata = "2,1,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 10);
-    }
+const EXAMPLE_4: &str = "1,2,3";
 
-    #[test]
-    fn part1_example_4() {
-        let testdata = "1,2,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 27);
-    }
+const EXAMPLE_5: &str = "2,3,1";
 
-    #[test]
-    fn part1_example_5() {
-        let testdata = "2,3,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 78);
-    }
+const EXAMPLE_6: &str = "3,2,1";
 
-    #[test]
-    fn part1_example_6() {
-        let testdata = "3,2,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 438);
-    }
+const EXAMPLE_7: &str = "3,1,2";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_7() {
-        let testdata = "3,1,2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1836);
+    fn examples() {
+        check::<InputData>();
     }
-
-    // Skip part 2 test, since it's really slow and anyway just a lot more iterations
-    // of the same function.
 }
@@ -1,12 +1,34 @@
 //! # 2020 day 16 - Ticket Translation
 //!
 //! Kind of like Sudoku.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::VecDeque, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("71").part2("1")];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{any}: {int}-{int} or {int}-{int}"]),
+        Shape::Lines(&["your ticket:", "{ints}"]),
+        Shape::Lines(&["nearby tickets:", "{ints}"]),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.scan_tickets().0
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Debug)]
@@ -17,17 +39,17 @@ struct FieldRule {
 }
 
 impl FromStr for FieldRule {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").unwrap();
+        let (left, right) = parse::split_once(s, ": ")?;
         let is_departure = left.starts_with("departure");
-        let (r1, r2) = right.split_once(" or ").unwrap();
-        let r1 = r1.split_once('-').unwrap();
-        let r2 = r2.split_once('-').unwrap();
+        let (r1, r2) = parse::split_once(right, " or ")?;
+        let r1 = parse::split_once(r1, "-")?;
+        let r2 = parse::split_once(r2, "-")?;
         Ok(Self {
             is_departure,
-            range1: (r1.0.parse().unwrap(), r1.1.parse().unwrap()),
-            range2: (r2.0.parse().unwrap(), r2.1.parse().unwrap()),
+            range1: (parse::number(r1.0)?, parse::number(r1.1)?),
+            range2: (parse::number(r2.0)?, parse::number(r2.1)?),
         })
     }
 }
@@ -38,38 +60,34 @@ impl FieldRule {
     }
 }
 
-struct InputData {
+pub struct InputData {
     fieldrules: Vec<FieldRule>,
     your: Vec<usize>,
     nearby: Vec<Vec<usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split("\n\n");
-        let fields = parts
-            .next()
-            .unwrap()
-            .lines()
-            .map(|line| FieldRule::from_str(line).unwrap())
-            .collect();
-        let your = parts
-            .next()
-            .unwrap()
-            .lines()
-            .nth(1)
-            .unwrap()
-            .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
-        let nearby = parts
-            .next()
-            .unwrap()
-            .lines()
-            .skip(1)
-            .map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
-            .collect();
+        let ticket = |line: &str| {
+            line.split(',')
+                .map(parse::number)
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let (fields, rest) = parse::split_once(s, "\n\n")?;
+        let (your, nearby) = parse::split_once(rest, "\n\n")?;
+        let fields = parse::lines(fields, FieldRule::from_str)?;
+        let your = ticket(parse::strip_prefix(your, "your ticket:\n")?)?;
+        let nearby = parse::lines(parse::strip_prefix(nearby, "nearby tickets:\n")?, |line| {
+            let values = ticket(line)?;
+            if values.len() != your.len() {
+                return Err(ParseError::new(
+                    "expected as many values as your ticket",
+                    line,
+                ));
+            }
+            Ok(values)
+        })?;
         Ok(Self {
             fieldrules: fields,
             your,
@@ -79,7 +97,9 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
+    /// Returns the error rate, i.e. the sum of all values not valid for any field, and the nearby
+    /// tickets without such values.
+    fn scan_tickets(&self) -> (usize, Vec<&Vec<usize>>) {
         let mut invalid = 0;
         let mut valid = Vec::new();
         'outer: for ticket in &self.nearby {
@@ -91,8 +111,13 @@ impl InputData {
             }
             valid.push(ticket);
         }
+        (invalid, valid)
+    }
 
-        let nbrcols = self.nearby.first().unwrap().len();
+    /// Returns `None` if the fields can't be matched to the positions of the tickets.
+    fn solve_part2(&self) -> Option<usize> {
+        let (_, valid) = self.scan_tickets();
+        let nbrcols = self.your.len();
         // Create vec of possible rule indices for each position, wrapped with the index of the position to keep track after sorting
         let mut possible: Vec<(usize, Vec<usize>)> = (0..nbrcols)
             .map(|i| {
@@ -101,7 +126,7 @@ impl InputData {
                     self.fieldrules
                         .iter()
                         .enumerate()
-                        .filter(|(_, f)| valid.iter().all(|n| f.is_in_range(n.get(i).unwrap())))
+                        .filter(|(_, f)| valid.iter().all(|n| f.is_in_range(&n[i])))
                         .map(|(idx, _)| idx)
                         .collect(),
                 )
@@ -110,36 +135,32 @@ impl InputData {
         // Sort the vec by number of possible values, to minimize the number of possible paths when traversing
         possible.sort_unstable_by_key(|a| a.1.len());
         // Ugly! - start with a vector of some invalid value to avoid getting false matches on 'contains' check later in the search
-        let mut path = vec![nbrcols + 1; nbrcols];
-        let mut queue = VecDeque::from([(path.clone(), 0)]);
-        while let Some((current, nbr)) = queue.pop_front() {
+        let mut queue = VecDeque::from([(vec![nbrcols + 1; nbrcols], 0)]);
+        let path = loop {
+            let (current, nbr) = queue.pop_front()?;
             if nbr == nbrcols {
-                path = current;
-                break;
+                break current;
             }
-            for nxt in possible.get(nbr).unwrap().1.iter() {
+            let (col, rules) = &possible[nbr];
+            for nxt in rules {
                 if !current.contains(nxt) {
                     let mut n = current.clone();
-                    n[possible.get(nbr).unwrap().0] = *nxt;
+                    n[*col] = *nxt;
                     queue.push_back((n, nbr + 1));
                 }
             }
-        }
-        let departures = path
-            .iter()
-            .enumerate()
-            .filter(|(_, p)| self.fieldrules.get(**p).unwrap().is_departure)
-            .map(|(i, _)| self.your.get(i).unwrap())
-            .product();
-        (invalid, departures)
+        };
+        Some(
+            path.iter()
+                .zip(&self.your)
+                .filter(|(p, _)| self.fieldrules[**p].is_departure)
+                .map(|(_, value)| value)
+                .product(),
+        )
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "class: 1-3 or 5-7
+const EXAMPLE: &str = "class: 1-3 or 5-7
 row: 6-11 or 33-44
 seat: 13-40 or 45-50
 
@@ -152,11 +173,13 @@ nearby tickets:
 55,2,20
 38,6,12";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 71);
-        assert_eq!(p2, 1);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,44 +1,106 @@
 //! # 2020 day 18 - Operation Order
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::VecDeque, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("71").part2("231"),
+        Example::new(EXAMPLE_2).part1("51").part2("51"),
+        Example::new(EXAMPLE_3).part1("26").part2("46"),
+        Example::new(EXAMPLE_4).part1("437").part2("1445"),
+        Example::new(EXAMPLE_5).part1("12240").part2("669060"),
+        Example::new(EXAMPLE_6).part1("13632").part2("23340"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789() ] [+*] [0123456789+*() ]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     lines: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            lines: s.lines().collect(),
-        })
+        let lines = s.lines().collect::<Vec<_>>();
+        if lines.is_empty() {
+            return Err(ParseError::missing("missing expressions", s));
+        }
+        for line in &lines {
+            check_expression(line)?;
+        }
+        Ok(Self { lines })
+    }
+}
+
+/// Checks that the line is an expression of single digits, `+`, `*` and balanced parentheses
+fn check_expression(line: &str) -> Result<(), ParseError> {
+    let mut expect_operand = true;
+    let mut depth = 0;
+    for (i, c) in line.char_indices() {
+        let at = &line[i..i + c.len_utf8()];
+        match c {
+            ' ' => (),
+            '0'..='9' | '(' if expect_operand => {
+                if c == '(' {
+                    depth += 1;
+                } else {
+                    expect_operand = false;
+                }
+            }
+            '+' | '*' if !expect_operand => expect_operand = true,
+            ')' if !expect_operand && depth > 0 => depth -= 1,
+            '0'..='9' | '(' => return Err(ParseError::new("expected an operator", at)),
+            '+' | '*' | ')' => return Err(ParseError::new("expected a number", at)),
+            _ => return Err(ParseError::new("expected a number or an operator", at)),
+        }
+    }
+    if expect_operand {
+        Err(ParseError::missing("missing number", line))
+    } else if depth > 0 {
+        Err(ParseError::missing("missing closing parenthesis", line))
+    } else {
+        Ok(())
     }
 }
 
 impl InputData<'_> {
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if the sum overflows.
+    fn solve_part1(&self) -> Option<usize> {
         self.lines
             .iter()
             .map(|line| shunting_yard(line, false))
-            .sum()
+            .try_fold(0usize, |acc, n| acc.checked_add(n?))
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if the sum overflows.
+    fn solve_part2(&self) -> Option<usize> {
         self.lines
             .iter()
             .map(|line| shunting_yard(line, true))
-            .sum()
+            .try_fold(0usize, |acc, n| acc.checked_add(n?))
     }
 }
 
-fn shunting_yard(s: &str, is_advanced: bool) -> usize {
+fn shunting_yard(s: &str, is_advanced: bool) -> Option<usize> {
     let mut output = VecDeque::new();
     let mut opstack = VecDeque::new();
     for c in s.chars() {
@@ -77,13 +139,17 @@ fn shunting_yard(s: &str, is_advanced: bool) -> usize {
         output.push_back(o);
     }
 
-    let mut evaluated = VecDeque::new();
+    let mut evaluated: VecDeque<usize> = VecDeque::new();
     for c in &output {
         match c {
             '+' | '*' => {
                 let v1 = evaluated.pop_back().unwrap();
                 let v2 = evaluated.pop_back().unwrap();
-                let n = if *c == '+' { v1 + v2 } else { v1 * v2 };
+                let n = if *c == '+' {
+                    v1.checked_add(v2)?
+                } else {
+                    v1.checked_mul(v2)?
+                };
                 evaluated.push_back(n);
             }
             _ => {
@@ -91,89 +157,35 @@ fn shunting_yard(s: &str, is_advanced: bool) -> usize {
             }
         }
     }
-    *evaluated.front().unwrap()
+    evaluated.front().copied()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "1 + 2 * 3 + 4 * 5 + 6";
 
-    const TEST_DATA_1: &str = "1 + 2 * 3 + 4 * 5 + 6";
-    const TEST_DATA_2: &str = "1 + (2 * 3) + (4 * (5 + 6))";
-    const TEST_DATA_3: &str = "2 * 3 + (4 * 5)";
-    const TEST_DATA_4: &str = "5 + (8 * 3 + 9 + 3 * 4 * 3)";
-    const TEST_DATA_5: &str = "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))";
-    const TEST_DATA_6: &str = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+const EXAMPLE_2: &str = "1 + (2 * 3) + (4 * (5 + 6))";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 71);
-    }
+const EXAMPLE_3: &str = "2 * 3 + (4 * 5)";
 
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::try_from(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part1(), 51);
-    }
+const EXAMPLE_4: &str = "5 + (8 * 3 + 9 + 3 * 4 * 3)";
 
-    #[test]
-    fn part1_example_3() {
-        let solution_data = InputData::try_from(TEST_DATA_3).unwrap();
-        assert_eq!(solution_data.solve_part1(), 26);
-    }
-
-    #[test]
-    fn part1_example_4() {
-        let solution_data = InputData::try_from(TEST_DATA_4).unwrap();
-        assert_eq!(solution_data.solve_part1(), 437);
-    }
-
-    #[test]
-    fn part1_example_5() {
-        let solution_data = InputData::try_from(TEST_DATA_5).unwrap();
-        assert_eq!(solution_data.solve_part1(), 12240);
-    }
-
-    #[test]
-    fn part1_example_6() {
-        let solution_data = InputData::try_from(TEST_DATA_6).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13632);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part2(), 231);
-    }
-
-    #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::try_from(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 51);
-    }
+const EXAMPLE_5: &str = "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))";
 
-    #[test]
-    fn part2_example_3() {
-        let solution_data = InputData::try_from(TEST_DATA_3).unwrap();
-        assert_eq!(solution_data.solve_part2(), 46);
-    }
+const EXAMPLE_6: &str = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
 
-    #[test]
-    fn part2_example_4() {
-        let solution_data = InputData::try_from(TEST_DATA_4).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1445);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_5() {
-        let solution_data = InputData::try_from(TEST_DATA_5).unwrap();
-        assert_eq!(solution_data.solve_part2(), 669060);
+    fn examples() {
+        check::<InputData>();
     }
 
     #[test]
-    fn part2_example_6() {
-        let solution_data = InputData::try_from(TEST_DATA_6).unwrap();
-        assert_eq!(solution_data.solve_part2(), 23340);
+    fn invalid_input() {
+        for testdata in ["1 +", "(1 + 2", "1 + 2)", "1 2", "1 - 2", ""] {
+            assert!(InputData::try_from(testdata).is_err());
+        }
     }
 }
@@ -16,3 +16,26 @@ pub mod day15;
 pub mod day16;
 pub mod day17;
 pub mod day18;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2020:
+    1 => day01: "Report Repair",
+    2 => day02: "Password Philosophy",
+    3 => day03: "Toboggan Trajectory",
+    4 => day04: "Passport Processing",
+    5 => day05: "Binary Boarding",
+    6 => day06: "Custom Customs",
+    7 => day07: "Handy Haversacks",
+    8 => day08: "Handheld Halting",
+    9 => day09: "Encoding Error",
+    10 => day10: "Adapter Array",
+    11 => day11: "Seating System",
+    12 => day12: "Rain Risk",
+    13 => day13: "Shuttle Search",
+    14 => day14: "Docking Data",
+    15 => day15: "Rambunctious Recitation",
+    16 => day16: "Ticket Translation",
+    17 => day17: "Conway Cubes",
+    18 => day18: "Operation Order",
+}
@@ -1,15 +1,30 @@
 //! # 2021 day 2 - Dive!
 //!
 //! Some practice with rust enums.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("150").part2("900")];
+
+    const SHAPE: Shape = Shape::Lines(&["forward {int}", "down {int}", "up {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum Command {
@@ -19,15 +34,15 @@ enum Command {
 }
 
 impl FromStr for Command {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, right) = s.split_once(' ').unwrap();
-        let val: usize = right.parse().unwrap();
-        match s.chars().nth(0).unwrap() {
-            'u' => Ok(Self::Up(val)),
-            'd' => Ok(Self::Down(val)),
-            'f' => Ok(Self::Forward(val)),
-            _ => Err(()),
+        let (left, right) = parse::split_once(s, " ")?;
+        let val: usize = parse::number(right)?;
+        match left {
+            "up" => Ok(Self::Up(val)),
+            "down" => Ok(Self::Down(val)),
+            "forward" => Ok(Self::Forward(val)),
+            _ => Err(ParseError::new("expected `up`, `down` or `forward`", left)),
         }
     }
 }
@@ -57,18 +72,15 @@ enum MoveResult {
     Aim(i32),
 }
 
-struct InputData {
+pub struct InputData {
     commands: Vec<Command>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            commands: s
-                .lines()
-                .map(|line| Command::from_str(line).unwrap())
-                .collect(),
+            commands: parse::lines(s, Command::from_str)?,
         })
     }
 }
@@ -95,21 +107,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 150);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 900);
+    fn examples() {
+        check::<InputData>();
     }
 }
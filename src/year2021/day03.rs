@@ -1,29 +1,62 @@
 //! # 2021 day 3 - Binary Diagnostic
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("198").part2("230")];
+
+    const SHAPE: Shape = Shape::Grid("01");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     width: usize,
     numbers: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let nbrs: Vec<&str> = s.lines().collect();
+        let width = nbrs
+            .first()
+            .map(|n| n.len())
+            .filter(|&width| width > 0)
+            .ok_or_else(|| ParseError::missing("missing numbers", s))?;
         Ok(Self {
-            width: nbrs[0].len(),
+            width,
             numbers: nbrs
                 .iter()
-                .map(|n| usize::from_str_radix(n, 2).unwrap())
-                .collect(),
+                .map(|n| {
+                    if width >= usize::BITS as usize {
+                        Err(ParseError::new("expected fewer bits", n))
+                    } else if n.len() != width {
+                        Err(ParseError::new(
+                            "expected all numbers to have the same number of bits",
+                            n,
+                        ))
+                    } else if !n.bytes().all(|b| b == b'0' || b == b'1') {
+                        Err(ParseError::new("expected a binary number", n))
+                    } else {
+                        Ok(usize::from_str_radix(n, 2).expect("the number is binary"))
+                    }
+                })
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -88,11 +121,12 @@ impl InputData {
                         zeroes.insert(*c);
                     }
                 }
-                if zeroes.len() > ones.len() {
+                // Keep the candidates if they all have the same bit
+                if zeroes.len() > ones.len() && !ones.is_empty() {
                     for z in zeroes {
                         scrubber_candidates.remove(&z);
                     }
-                } else {
+                } else if !zeroes.is_empty() {
                     for o in ones {
                         scrubber_candidates.remove(&o);
                     }
@@ -104,22 +138,16 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str =
-        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 198);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 230);
+    fn examples() {
+        check::<InputData>();
     }
 }
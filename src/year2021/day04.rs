@@ -1,10 +1,30 @@
 //! # 2021 day 4 - Giant Squid
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve_parts1_2();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("4512").part2("1924")];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{ints}"]),
+        Shape::Blocks(&Shape::Lines(&["{ints}", " {ints}"])),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Board {
@@ -13,18 +33,24 @@ struct Board {
 }
 
 impl FromStr for Board {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let rows: Vec<Vec<usize>> = s
             .lines()
             .map(|line| {
                 line.split_whitespace()
-                    .map(|n| n.parse().unwrap())
-                    .collect()
+                    .map(parse::number)
+                    .collect::<Result<_, _>>()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         let nbr_rows = rows.len();
-        let nbr_cols = rows[0].len();
+        let nbr_cols = rows
+            .first()
+            .ok_or_else(|| ParseError::missing("missing board", s))?
+            .len();
+        if rows.iter().any(|row| row.len() != nbr_cols) {
+            return Err(ParseError::new("expected a square board", s));
+        }
         let cols: Vec<Vec<usize>> = (0..nbr_cols)
             .map(|col| (0..nbr_rows).map(|row| rows[row][col]).collect())
             .collect();
@@ -53,27 +79,35 @@ impl Board {
     }
 }
 
-struct InputData {
+pub struct InputData {
     draw: Vec<usize>,
     drawmap: HashMap<usize, usize>,
     boards: Vec<Board>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut blocks = s.split("\n\n");
-        let draw: Vec<usize> = blocks
-            .next()
-            .unwrap()
+        let draw: Vec<usize> = parse::next(&mut blocks, "numbers to draw", s)?
             .split(',')
-            .map(|c| c.parse().unwrap())
-            .collect();
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
         let mut drawmap: HashMap<usize, usize> = HashMap::new();
         for (i, d) in draw.iter().enumerate() {
             drawmap.insert(*d, i);
         }
-        let boards: Vec<Board> = blocks.map(|b| Board::from_str(b).unwrap()).collect();
+        let boards: Vec<Board> = blocks.map(Board::from_str).collect::<Result<_, _>>()?;
+        if boards.is_empty() {
+            return Err(ParseError::missing("missing boards", s));
+        }
+        if let Some(n) = boards
+            .iter()
+            .flat_map(|board| board.rows.iter().flatten())
+            .find(|n| !drawmap.contains_key(n))
+        {
+            return Err(ParseError::missing(format!("{n} is never drawn"), s));
+        }
         Ok(Self {
             draw,
             drawmap,
@@ -83,27 +117,27 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve_parts1_2(&self) -> (usize, usize) {
-        let mut results: Vec<(usize, usize)> = self
-            .boards
+    /// Returns the round in which each board wins, and its score without the winning number.
+    fn results(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.boards
             .iter()
             .map(|board| board.get_bingoround_and_score(&self.drawmap))
-            .collect();
-        results.sort_unstable_by(|round, score| round.partial_cmp(score).unwrap());
-        (
-            self.draw[results.first().unwrap().0] * results.first().unwrap().1,
-            self.draw[results.last().unwrap().0] * results.last().unwrap().1,
-        )
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part1(&self) -> usize {
+        self.results()
+            .min()
+            .map_or(0, |(round, score)| self.draw[round] * score)
+    }
 
-    #[test]
-    fn part1_2_example_1() {
-        let testdata = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+    fn solve_part2(&self) -> usize {
+        self.results()
+            .max()
+            .map_or(0, |(round, score)| self.draw[round] * score)
+    }
+}
+
+const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
 22 13 17 11  0
  8  2 23  4 24
@@ -122,9 +156,14 @@ mod tests {
 18  8 23 26 20
 22 11 13  6  5
  2  0 12  3  7";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve_parts1_2();
-        assert_eq!(p1, 4512);
-        assert_eq!(p2, 1924);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,11 +1,28 @@
 //! # 2021 day 5 - Hydrothermal Venture
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::{grid::*, point::*};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("5").part2("12")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int},{int} -> {int},{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Point {
@@ -36,7 +53,7 @@ struct OceanFloor {
 impl OceanFloor {
     fn new(x: usize, y: usize) -> Self {
         Self {
-            points: Grid::new(x, y, '0'),
+            points: Grid::new(x, y, 0),
             nbr_dangerous_points: 0,
         }
     }
@@ -46,10 +63,10 @@ impl OceanFloor {
         let mut p = *p1;
         loop {
             match self.points.get_element(&p) {
-                Some('0') => self.points.set_point(&p, '1'),
-                Some('1') => {
+                Some(0) => self.points.set_point(&p, 1),
+                Some(1) => {
                     self.nbr_dangerous_points += 1;
-                    self.points.set_point(&p, '2');
+                    self.points.set_point(&p, 2);
                 }
                 _ => (),
             };
@@ -61,7 +78,7 @@ impl OceanFloor {
     }
 }
 
-struct InputData {
+pub struct InputData {
     straight_lines: Vec<(Point, Point)>,
     diagonal_lines: Vec<(Point, Point)>,
     x_max: usize,
@@ -69,16 +86,25 @@ struct InputData {
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut x_max = 0;
         let mut y_max = 0;
         let mut straight_lines = Vec::new();
         let mut diagonal_lines = Vec::new();
-        s.lines().for_each(|line| {
-            let (p1, p2) = line.split_once(" -> ").unwrap();
-            let p1 = Point::from_str(p1).unwrap();
-            let p2 = Point::from_str(p2).unwrap();
+        for line in s.lines() {
+            let (p1, p2) = parse::split_once(line, " -> ")?;
+            let p1 = Point::from_str(p1)?;
+            let p2 = Point::from_str(p2)?;
+            if p1.x.min(p1.y).min(p2.x).min(p2.y) < 0 {
+                return Err(ParseError::new("expected coordinates of at least 0", line));
+            }
+            if p1.is_diagonal(&p2) && (p2.x - p1.x).abs() != (p2.y - p1.y).abs() {
+                return Err(ParseError::new(
+                    "expected a horizontal, vertical or diagonal line",
+                    line,
+                ));
+            }
             if p1.is_diagonal(&p2) {
                 diagonal_lines.push((p1, p2));
             } else {
@@ -86,7 +112,7 @@ impl FromStr for InputData {
             }
             x_max = x_max.max(p1.x as usize).max(p2.x as usize);
             y_max = y_max.max(p1.y as usize).max(p2.y as usize);
-        });
+        }
         Ok(Self {
             straight_lines,
             diagonal_lines,
@@ -97,27 +123,25 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
+    fn solve_part1(&self) -> usize {
         let mut ocean_floor = OceanFloor::new(self.x_max + 1, self.y_max + 1);
         self.straight_lines
             .iter()
             .for_each(|(p1, p2)| ocean_floor.process_line(p1, p2));
-        let part1 = ocean_floor.nbr_dangerous_points;
+        ocean_floor.nbr_dangerous_points
+    }
 
-        self.diagonal_lines
+    fn solve_part2(&self) -> usize {
+        let mut ocean_floor = OceanFloor::new(self.x_max + 1, self.y_max + 1);
+        self.straight_lines
             .iter()
+            .chain(&self.diagonal_lines)
             .for_each(|(p1, p2)| ocean_floor.process_line(p1, p2));
-        (part1, ocean_floor.nbr_dangerous_points)
+        ocean_floor.nbr_dangerous_points
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "0,9 -> 5,9
+const EXAMPLE: &str = "0,9 -> 5,9
 8,0 -> 0,8
 9,4 -> 3,4
 2,2 -> 2,1
@@ -127,9 +151,14 @@ mod tests {
 3,4 -> 1,4
 0,0 -> 8,8
 5,5 -> 8,2";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 5);
-        assert_eq!(p2, 12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
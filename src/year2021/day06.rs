@@ -1,25 +1,44 @@
 //! # 2021 day 6 - Lanternfish
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(EXAMPLE).part1("5934").part2("26984457539")];
+
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     fish_states: [usize; 9],
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut states = [0_usize; 9];
-        s.split(',')
-            .map(|c| c.parse::<usize>().unwrap())
-            .for_each(|n| states[n] += 1);
+        for c in s.split(',') {
+            let n: usize = parse::number(c)?;
+            *states
+                .get_mut(n)
+                .ok_or_else(|| ParseError::new("expected a timer from 0 to 8", c))? += 1;
+        }
         Ok(Self {
             fish_states: states,
         })
@@ -44,21 +63,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "3,4,3,1,2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "3,4,3,1,2";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5934);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 26984457539);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -5,3 +5,15 @@ pub mod day04;
 pub mod day05;
 pub mod day06;
 pub mod day07;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2021:
+    1 => day01: "Sonar Sweep",
+    2 => day02: "Dive!",
+    3 => day03: "Binary Diagnostic",
+    4 => day04: "Giant Squid",
+    5 => day05: "Hydrothermal Venture",
+    6 => day06: "Lanternfish",
+    7 => day07: "The Treachery of Whales",
+}
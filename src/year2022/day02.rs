@@ -1,12 +1,27 @@
 //! # 2022 day 2 - Rock Paper Scissors
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("15").part2("12")];
+
+    const SHAPE: Shape = Shape::Lines(&["[ABC] [XYZ]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -17,13 +32,13 @@ enum Hand {
 }
 
 impl FromStr for Hand {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" => Ok(Self::Rock),
             "B" => Ok(Self::Paper),
             "C" => Ok(Self::Scissors),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected A, B or C", s)),
         }
     }
 }
@@ -72,13 +87,13 @@ enum Guide {
 }
 
 impl FromStr for Guide {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(Self::Lose),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Win),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected X, Y or Z", s)),
         }
     }
 }
@@ -93,24 +108,18 @@ impl Guide {
     }
 }
 
-struct InputData {
+pub struct InputData {
     rounds: Vec<(Hand, Guide)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            rounds: s
-                .lines()
-                .map(|line| {
-                    let hands: Vec<&str> = line.split_whitespace().collect();
-                    (
-                        Hand::from_str(hands[0]).unwrap(),
-                        Guide::from_str(hands[1]).unwrap(),
-                    )
-                })
-                .collect(),
+            rounds: parse::lines(s, |line| {
+                let (hand, guide) = parse::split_once(line, " ")?;
+                Ok((Hand::from_str(hand)?, Guide::from_str(guide)?))
+            })?,
         })
     }
 }
@@ -131,21 +140,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "A Y\nB X\nC Z";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "A Y\nB X\nC Z";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 15);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 12);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,87 +1,93 @@
 //! # 2022 day 3 - Rucksack Reorganization
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("157").part2("70")];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     rucksacks: Vec<Vec<usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            rucksacks: s
-                .lines()
-                .map(|line| {
-                    line.chars()
-                        .map(|c| {
-                            if c.is_uppercase() {
-                                27 + (c as usize - 'A' as usize)
-                            } else {
-                                1 + (c as usize - 'a' as usize)
-                            }
-                        })
-                        .collect()
-                })
-                .collect(),
+            rucksacks: parse::lines(s, |line| {
+                line.char_indices()
+                    .map(|(i, c)| match c {
+                        'a'..='z' => Ok(1 + (c as usize - 'a' as usize)),
+                        'A'..='Z' => Ok(27 + (c as usize - 'A' as usize)),
+                        _ => Err(ParseError::new("expected a letter", &line[i..])),
+                    })
+                    .collect()
+            })?,
         })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if the compartments of a rucksack have no item in common.
+    fn solve_part1(&self) -> Option<usize> {
         self.rucksacks
             .iter()
             .map(|rucksack| {
                 let (left, right) = rucksack.split_at(rucksack.len() / 2);
                 let a: HashSet<usize> = HashSet::from_iter(left.to_vec());
                 let b: HashSet<usize> = HashSet::from_iter(right.to_vec());
-                let common = a.intersection(&b).next().unwrap();
-                *common
+                a.intersection(&b).next().copied()
             })
             .sum()
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if the rucksacks can't be split into groups of three with a common item.
+    fn solve_part2(&self) -> Option<usize> {
         self.rucksacks
             .chunks(3)
             .map(|group| {
+                let [first, second, third] = group else {
+                    return None;
+                };
                 // Can't figure out how to instersect more than two sets - brute force looping for now...
-                let mut badge: usize = 0;
-                for a in group[0].clone() {
-                    if group[1].contains(&a) && group[2].contains(&a) {
-                        badge = a;
-                        break;
-                    }
-                }
-                badge
+                first
+                    .iter()
+                    .find(|a| second.contains(a) && third.contains(a))
+                    .copied()
             })
             .sum()
     }
 }
 
+const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 157);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 70);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,30 +1,48 @@
 //! # 2022 day 4 - Camp Cleanup
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("2").part2("4")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}-{int},{int}-{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
-    assignments: Vec<Vec<usize>>,
+pub struct InputData {
+    assignments: Vec<[usize; 4]>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            assignments: s
-                .lines()
-                .map(|line| {
-                    line.split([',', '-'])
-                        .map(|nbr| nbr.parse().unwrap())
-                        .collect()
-                })
-                .collect(),
+            assignments: parse::lines(s, |line| {
+                let (first, second) = parse::split_once(line, ",")?;
+                let (a, b) = parse::split_once(first, "-")?;
+                let (c, d) = parse::split_once(second, "-")?;
+                Ok([
+                    parse::number(a)?,
+                    parse::number(b)?,
+                    parse::number(c)?,
+                    parse::number(d)?,
+                ])
+            })?,
         })
     }
 }
@@ -53,21 +71,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -6,11 +6,34 @@
 //! to get the correct index with a stack stored in regular 0-indexing double-vector.
 //! After parsing, running the procedures is straightforward, just some vector
 //! trickery.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((solution_data.solve_part1(), solution_data.solve_part2()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("CMZ").part2("MCD")];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{any}[[]{word}]{any}", " {ints}", " {ints} "]),
+        Shape::Lines(&["move {int} from {int} to {int}"]),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 struct Procedure {
@@ -20,32 +43,36 @@ struct Procedure {
 }
 
 impl FromStr for Procedure {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        tokens.next();
-        let amount = tokens.next().unwrap().parse().unwrap();
-        tokens.next();
-        let from = tokens.next().unwrap().parse::<usize>().unwrap() - 1;
-        tokens.next();
-        let to = tokens.next().unwrap().parse::<usize>().unwrap() - 1;
-        Ok(Self { amount, from, to })
+        let s = parse::strip_prefix(s, "move ")?;
+        let (amount, s) = parse::split_once(s, " from ")?;
+        let (from, to) = parse::split_once(s, " to ")?;
+        let stack = |s| match parse::number::<usize>(s)? {
+            0 => Err(ParseError::new("expected a stack number", s)),
+            n => Ok(n - 1),
+        };
+        Ok(Self {
+            amount: parse::number(amount)?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
     }
 }
 
-struct InputData {
+pub struct InputData {
     crates: Vec<Vec<char>>,
     procedures: Vec<Procedure>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (c, p) = s.split_once("\n\n").unwrap();
+        let (c, p) = parse::split_once(s, "\n\n")?;
         let c_lines: Vec<_> = c.lines().collect();
         let c_indices: Vec<usize> = c_lines
             .last()
-            .unwrap()
+            .ok_or_else(|| ParseError::missing("missing stack numbers", c))?
             .chars()
             .enumerate()
             .filter(|(_, c)| *c != ' ')
@@ -61,30 +88,33 @@ impl FromStr for InputData {
                 }
             }
         }
-        Ok(Self {
-            crates,
-            procedures: p
-                .lines()
-                .map(|line| Procedure::from_str(line).unwrap())
-                .collect(),
-        })
+        let procedures = parse::lines(p, |line| {
+            let procedure = Procedure::from_str(line)?;
+            if procedure.from.max(procedure.to) >= crates.len() {
+                return Err(ParseError::new("unknown stack", line));
+            }
+            Ok(procedure)
+        })?;
+        Ok(Self { crates, procedures })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> String {
+    fn solve_part1(&self) -> Option<String> {
         self.run_procedures(false)
     }
 
-    fn solve_part2(&self) -> String {
+    fn solve_part2(&self) -> Option<String> {
         self.run_procedures(true)
     }
 
-    fn run_procedures(&self, multi_crates: bool) -> String {
+    /// Returns `None` if a procedure moves more crates than there are on the stack, or if a stack
+    /// ends up empty.
+    fn run_procedures(&self, multi_crates: bool) -> Option<String> {
         let mut crates = self.crates.clone();
         let mut buffer = Vec::new();
         for p in self.procedures.iter() {
-            let i = crates[p.from].len() - p.amount;
+            let i = crates[p.from].len().checked_sub(p.amount)?;
             buffer.extend(crates[p.from].drain(i..));
             if !multi_crates {
                 crates[p.to].extend(buffer.iter().rev());
@@ -93,15 +123,11 @@ impl InputData {
             }
             buffer.clear();
         }
-        crates.iter().map(|c| c.last().unwrap()).collect()
+        crates.iter().map(|c| c.last()).collect()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "    [D]    
+const EXAMPLE: &str = "    [D]    
 [N] [C]    
 [Z] [M] [P]
  1   2   3 
@@ -111,15 +137,13 @@ move 3 from 1 to 3
 move 2 from 2 to 1
 move 1 from 1 to 2";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), "CMZ");
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), "MCD");
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -2,22 +2,45 @@
 //!
 //! Use the 'windows' feature of Rust to scan the input, and throw every
 //! such slice into a hashset to see if the length equals the slice length.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("7").part2("19"),
+        Example::new(EXAMPLE_2).part1("5").part2("23"),
+        Example::new(EXAMPLE_3).part1("6").part2("23"),
+        Example::new(EXAMPLE_4).part1("10").part2("29"),
+        Example::new(EXAMPLE_5).part1("11").part2("26"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     datastream: Vec<char>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             datastream: (s.chars().collect()),
@@ -26,14 +49,15 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
-        self.get_processed_characters(4).unwrap()
+    fn solve_part1(&self) -> Option<usize> {
+        self.get_processed_characters(4)
     }
 
-    fn solve_part2(&self) -> usize {
-        self.get_processed_characters(14).unwrap()
+    fn solve_part2(&self) -> Option<usize> {
+        self.get_processed_characters(14)
     }
 
+    /// Returns `None` if no `start` consecutive characters are all different.
     fn get_processed_characters(&self, start: usize) -> Option<usize> {
         let mut set: HashSet<char> = HashSet::new();
         for (i, part) in self.datastream.windows(start).enumerate() {
@@ -47,47 +71,23 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE_1: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
 
-    #[test]
-    fn part1_2_example_1() {
-        let testdata = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
-        assert_eq!(solution_data.solve_part2(), 19);
-    }
+const EXAMPLE_2: &str = "bvwbjplbgvbhsrlpgdmjqwftvncz";
 
-    #[test]
-    fn part1_2_example_2() {
-        let testdata = "bvwbjplbgvbhsrlpgdmjqwftvncz";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5);
-        assert_eq!(solution_data.solve_part2(), 23);
-    }
+const EXAMPLE_3: &str = "nppdvjthqldpwncqszvftbrmjlhg";
 
-    #[test]
-    fn part1_2_example_3() {
-        let testdata = "nppdvjthqldpwncqszvftbrmjlhg";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 6);
-        assert_eq!(solution_data.solve_part2(), 23);
-    }
+const EXAMPLE_4: &str = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
 
-    #[test]
-    fn part1_2_example_4() {
-        let testdata = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 10);
-        assert_eq!(solution_data.solve_part2(), 29);
-    }
+const EXAMPLE_5: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_2_example_5() {
-        let testdata = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 11);
-        assert_eq!(solution_data.solve_part2(), 26);
+    fn examples() {
+        check::<InputData>();
     }
 }
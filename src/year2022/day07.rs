@@ -1,56 +1,68 @@
 //! # 2022 day 7 - No Space Left On Device
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
-}
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("95437").part2("24933642")];
 
-struct InputData<'a> {
-    command_blocks: Vec<&'a str>,
-}
+    const SHAPE: Shape = Shape::Lines(&[
+        "$ cd {any}",
+        "$ ls",
+        "dir {word}",
+        "{int} [abcdefghijklmnopqrstuvwxyz.]",
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
 
-impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            command_blocks: s
-                .split("$ ")
-                .filter(|s| !s.is_empty())
-                .map(|s| s.trim_end())
-                .collect(),
-        })
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
     }
 }
 
-impl InputData<'_> {
-    fn solve(&self) -> (usize, usize) {
+/// The total sizes of the directories, with the outermost directory last.
+pub struct InputData {
+    dir_sizes: Vec<usize>,
+}
+
+impl TryFrom<&str> for InputData {
+    type Error = ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         let mut dir_sizes = Vec::new();
         let mut buffer = Vec::new();
         let mut total = 0;
-        for &block in self.command_blocks.iter() {
+        for block in s.split("$ ").filter(|s| !s.is_empty()).map(str::trim_end) {
             let mut lines = block.lines();
-            let commandline = lines.next().unwrap();
-            match &commandline[0..2] {
-                "cd" => {
-                    if commandline.ends_with("..") {
-                        dir_sizes.push(total);
-                        total += buffer.pop().unwrap();
-                    } else {
-                        buffer.push(total);
-                        total = 0;
-                    }
+            let commandline = parse::next(&mut lines, "command", block)?;
+            if commandline.starts_with("cd") {
+                if commandline.ends_with("..") {
+                    dir_sizes.push(total);
+                    total += buffer.pop().ok_or_else(|| {
+                        ParseError::new("already in the outermost directory", block)
+                    })?;
+                } else {
+                    buffer.push(total);
+                    total = 0;
                 }
-                "ls" => {
-                    for line in lines {
-                        let (first, _) = line.split_once(' ').unwrap();
-                        if first.as_bytes()[0].is_ascii_digit() {
-                            total += first.parse::<usize>().unwrap();
-                        }
+            } else if commandline.starts_with("ls") {
+                for line in lines {
+                    let (first, _) = parse::split_once(line, " ")?;
+                    if first != "dir" {
+                        total += parse::number::<usize>(first)?;
                     }
                 }
-                _ => panic!("Unexpected command in input"),
+            } else {
+                return Err(ParseError::new("expected `cd` or `ls`", commandline));
             }
         }
 
@@ -58,27 +70,31 @@ impl InputData<'_> {
             dir_sizes.push(total);
             total += n;
         }
+        if dir_sizes.is_empty() {
+            return Err(ParseError::missing("missing directories", s));
+        }
+        Ok(Self { dir_sizes })
+    }
+}
 
-        let p1 = dir_sizes.iter().filter(|&&n| n < 100_000).sum();
+impl InputData {
+    fn solve_part1(&self) -> usize {
+        self.dir_sizes.iter().filter(|&&n| n < 100_000).sum()
+    }
 
-        let space_required = 30_000_000 - (70_000_000 - dir_sizes.last().unwrap());
-        let p2 = *dir_sizes
+    /// Returns `None` if the files don't fit on the disk.
+    fn solve_part2(&self) -> Option<usize> {
+        let space_free = 70_000_000usize.checked_sub(*self.dir_sizes.last()?)?;
+        let space_required = 30_000_000usize.saturating_sub(space_free);
+        self.dir_sizes
             .iter()
             .filter(|&&n| n >= space_required)
             .min()
-            .unwrap();
-
-        (p1, p2)
+            .copied()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "$ cd /
+const EXAMPLE: &str = "$ cd /
 $ ls
 dir a
 14848514 b.txt
@@ -101,9 +117,14 @@ $ ls
 8033020 d.log
 5626152 d.ext
 7214296 k";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 95437);
-        assert_eq!(p2, 24933642);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
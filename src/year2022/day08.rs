@@ -2,26 +2,41 @@
 //!
 //! TBD - Figure out some way to compress duplicated code for all directions
 //! with iterators.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("21").part2("8")];
+
+    const SHAPE: Shape = Shape::Grid("0123456789");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            grid: Grid::parse(s),
+            grid: Grid::parse_digits(s)?,
         })
     }
 }
@@ -34,7 +49,7 @@ impl InputData {
             tallest = -1;
             for x in 0..self.grid.x_max {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as i8;
+                let v = self.grid.get_element(&p).unwrap() as i8;
                 if v > tallest {
                     visible_trees.insert(p);
                     if v == 9 {
@@ -46,7 +61,7 @@ impl InputData {
             tallest = -1;
             for x in (0..self.grid.x_max).rev() {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as i8;
+                let v = self.grid.get_element(&p).unwrap() as i8;
                 if v > tallest {
                     visible_trees.insert(p);
                     if v == 9 {
@@ -60,7 +75,7 @@ impl InputData {
             tallest = -1;
             for y in 0..self.grid.y_max {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as i8;
+                let v = self.grid.get_element(&p).unwrap() as i8;
                 if v > tallest {
                     visible_trees.insert(p);
                     if v == 9 {
@@ -72,7 +87,7 @@ impl InputData {
             tallest = -1;
             for y in (0..self.grid.y_max).rev() {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as i8;
+                let v = self.grid.get_element(&p).unwrap() as i8;
                 if v > tallest {
                     visible_trees.insert(p);
                     if v == 9 {
@@ -93,8 +108,8 @@ impl InputData {
             scorelist.fill(0);
             for x in 0..self.grid.x_max {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as usize;
-                let idx = self.grid.get_index(&p);
+                let v = self.grid.get_element(&p).unwrap() as usize;
+                let idx = self.grid.get_index(&p).unwrap();
                 gridscore[idx] *= scorelist[v];
                 for (i, s) in &mut scorelist.iter_mut().enumerate() {
                     if i > v {
@@ -108,8 +123,8 @@ impl InputData {
             scorelist.fill(0);
             for x in (0..self.grid.x_max).rev() {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as usize;
-                let idx = self.grid.get_index(&p);
+                let v = self.grid.get_element(&p).unwrap() as usize;
+                let idx = self.grid.get_index(&p).unwrap();
                 gridscore[idx] *= scorelist[v];
                 for (i, s) in &mut scorelist.iter_mut().enumerate() {
                     if i > v {
@@ -126,8 +141,8 @@ impl InputData {
             scorelist.fill(0);
             for y in 0..self.grid.y_max {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as usize;
-                let idx = self.grid.get_index(&p);
+                let v = self.grid.get_element(&p).unwrap() as usize;
+                let idx = self.grid.get_index(&p).unwrap();
                 gridscore[idx] *= scorelist[v];
                 for (i, s) in &mut scorelist.iter_mut().enumerate() {
                     if i > v {
@@ -141,8 +156,8 @@ impl InputData {
             scorelist.fill(0);
             for y in (0..self.grid.y_max).rev() {
                 let p = Point::new(x as i32, y as i32);
-                let v = self.grid.get_uint_element(&p).unwrap() as usize;
-                let idx = self.grid.get_index(&p);
+                let v = self.grid.get_element(&p).unwrap() as usize;
+                let idx = self.grid.get_index(&p).unwrap();
                 gridscore[idx] *= scorelist[v];
                 largest = largest.max(gridscore[idx]);
                 for (i, s) in &mut scorelist.iter_mut().enumerate() {
@@ -158,25 +173,19 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "30373
+const EXAMPLE: &str = "30373
 25512
 65332
 33549
 35390";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 21);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 8);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,23 +1,41 @@
 //! # 2022 day 9 - Rope Bridge
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("13").part2("1"),
+        Example::new(EXAMPLE_2).part2("36"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[UDLR] {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Point {
-    fn parse_dir(input: &str) -> Self {
+    fn parse_dir(input: &str) -> Result<Self, ParseError> {
         match input {
-            "R" => RIGHT,
-            "L" => LEFT,
-            "U" => UP,
-            "D" => DOWN,
-            _ => unreachable!(),
+            "R" => Ok(RIGHT),
+            "L" => Ok(LEFT),
+            "U" => Ok(UP),
+            "D" => Ok(DOWN),
+            _ => Err(ParseError::new("expected R, L, U or D", input)),
         }
     }
 
@@ -29,21 +47,18 @@ impl Point {
     }
 }
 
-struct InputData {
+pub struct InputData {
     motions: Vec<(Point, usize)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            motions: s
-                .lines()
-                .map(|line| {
-                    let (dir, len) = line.split_once(' ').unwrap();
-                    (Point::parse_dir(dir), len.parse().unwrap())
-                })
-                .collect(),
+            motions: parse::lines(s, |line| {
+                let (dir, len) = parse::split_once(line, " ")?;
+                Ok((Point::parse_dir(dir)?, parse::number(len)?))
+            })?,
         })
     }
 }
@@ -76,28 +91,17 @@ impl InputData {
     }
 }
 
+const EXAMPLE_1: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+
+const EXAMPLE_2: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA_1: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
-    const TEST_DATA_2: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 36);
+    fn examples() {
+        check::<InputData>();
     }
 }
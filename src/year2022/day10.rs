@@ -1,13 +1,29 @@
 //! # 2022 day 10 - Cathode-Ray Tube
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::{grid::*, point::*};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(EXAMPLE).part1("13140").part2(EXAMPLE_SCREEN)];
+
+    const SHAPE: Shape = Shape::Lines(&["noop", "addx {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum Opcode {
@@ -16,31 +32,25 @@ enum Opcode {
 }
 
 impl FromStr for Opcode {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.chars().nth(0) {
-            Some('n') => Ok(Self::Noop),
-            Some('a') => {
-                let (_, v) = s.split_once(' ').unwrap();
-                Ok(Self::Addx(v.parse().unwrap()))
-            }
-            _ => Err(()),
+        if s == "noop" {
+            Ok(Self::Noop)
+        } else {
+            Ok(Self::Addx(parse::number(parse::strip_prefix(s, "addx ")?)?))
         }
     }
 }
 
-struct InputData {
+pub struct InputData {
     program: Vec<Opcode>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            program: s
-                .lines()
-                .map(|line| Opcode::from_str(line).unwrap())
-                .collect(),
+            program: parse::lines(s, Opcode::from_str)?,
         })
     }
 }
@@ -73,15 +83,15 @@ impl InputData {
         total as usize
     }
 
-    fn solve_part2(&self) -> String {
-        let mut crt = Grid::new(40, 6, ' ');
+    fn solve_part2(&self) -> Answer {
+        let mut crt = Grid::new(40, 6, b' ');
         let mut reg_x: isize = 1;
         let mut cyclenbr: usize = 0;
         let mut tick = |x: isize| {
             if (x - (cyclenbr % crt.x_max) as isize).abs() <= 1 {
                 crt.set_point(
                     &Point::new((cyclenbr % 40) as i32, (cyclenbr / 40) as i32),
-                    '#',
+                    b'#',
                 );
             }
             cyclenbr += 1;
@@ -99,22 +109,21 @@ impl InputData {
                 }
             }
         }
-        let mut result = Vec::with_capacity(crt.elements.len() + crt.y_max + 1);
-        for y in 0..crt.y_max {
-            result.push('\n');
-            for x in 0..crt.x_max {
-                result.push(crt.get_element(&Point::new(x as i32, y as i32)).unwrap());
-            }
-        }
-        result.iter().collect()
+        Answer::Screen(
+            (0..crt.y_max)
+                .map(|y| {
+                    (0..crt.x_max)
+                        .map(|x| {
+                            char::from(crt.get_element(&Point::new(x as i32, y as i32)).unwrap())
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "addx 15
+const EXAMPLE: &str = "addx 15
 addx -11
 addx 6
 addx -3
@@ -261,23 +270,21 @@ noop
 noop
 noop";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13140);
-    }
+/// The screen drawn by the example in part 2, with the dark pixels as spaces.
+const EXAMPLE_SCREEN: &str = "##  ##  ##  ##  ##  ##  ##  ##  ##  ##
+###   ###   ###   ###   ###   ###   ###
+####    ####    ####    ####    ####
+#####     #####     #####     #####
+######      ######      ######      ####
+#######       #######       #######";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(
-            solution_data.solve_part2(),
-            "\n##  ##  ##  ##  ##  ##  ##  ##  ##  ##  
-###   ###   ###   ###   ###   ###   ### 
-####    ####    ####    ####    ####    
-#####     #####     #####     #####     
-######      ######      ######      ####
-#######       #######       #######     "
-        );
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,13 +1,38 @@
 //! # 2022 day 11 - Monkey in the Middle
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use crate::aoc_util::math;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::VecDeque, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(EXAMPLE).part1("10605").part2("2713310158")];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&[
+        "Monkey {int}:",
+        " Starting items: {ints}",
+        " Operation: new = old [*+] {word}",
+        " Test: divisible by {int}",
+        " If true: throw to monkey {int}",
+        " If false: throw to monkey {int}",
+    ]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Clone)]
@@ -18,30 +43,26 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, right) = s.split_once(" = ").unwrap();
-        let right: Vec<_> = right.split_whitespace().take(3).collect();
-        match right[1] {
-            "+" => Ok(Self::Add(right[2].parse().unwrap())),
-            "*" => {
-                if right[2] == "old" {
-                    Ok(Self::Square)
-                } else {
-                    Ok(Self::Mul(right[2].parse().unwrap()))
-                }
-            }
-            _ => unreachable!(),
+        let right = parse::strip_prefix(s, "new = old ")?;
+        let (op, value) = parse::split_once(right, " ")?;
+        match op {
+            "+" => Ok(Self::Add(parse::number(value)?)),
+            "*" if value == "old" => Ok(Self::Square),
+            "*" => Ok(Self::Mul(parse::number(value)?)),
+            _ => Err(ParseError::new("expected `+` or `*`", op)),
         }
     }
 }
 
 impl Operation {
-    fn calculate_new(&self, old: usize) -> usize {
+    /// Returns `None` if the worry level overflows.
+    fn calculate_new(&self, old: usize) -> Option<usize> {
         match self {
-            Self::Add(n) => old + n,
-            Self::Mul(n) => old * n,
-            Self::Square => old * old,
+            Self::Add(n) => old.checked_add(*n),
+            Self::Mul(n) => old.checked_mul(*n),
+            Self::Square => old.checked_mul(old),
         }
     }
 }
@@ -57,39 +78,25 @@ struct Monkey {
 }
 
 impl FromStr for Monkey {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines();
-        lines.next();
-        let (_, nbrs) = lines.next().unwrap().split_once(": ").unwrap();
-        let start_items = nbrs.split(", ").map(|n| n.parse().unwrap()).collect();
-        let operation = Operation::from_str(lines.next().unwrap()).unwrap();
-        let div_test = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse()
-            .unwrap();
-        let true_monkey = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse()
-            .unwrap();
-        let false_monkey = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse()
-            .unwrap();
+        let mut lines = s.lines().map(|line| line.trim_start());
+        let mut line = |prefix| parse::strip_prefix(parse::next(&mut lines, "line", s)?, prefix);
+        line("Monkey ")?;
+        let items = line("Starting items: ")?
+            .split(", ")
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let operation = Operation::from_str(line("Operation: ")?)?;
+        let div_test = line("Test: divisible by ")?;
+        let div_test = match parse::number(div_test)? {
+            0 => return Err(ParseError::new("expected a positive number", div_test)),
+            n => n,
+        };
+        let true_monkey = parse::number(line("If true: throw to monkey ")?)?;
+        let false_monkey = parse::number(line("If false: throw to monkey ")?)?;
         Ok(Self {
-            items: start_items,
+            items,
             operation,
             div_test,
             true_monkey,
@@ -100,12 +107,12 @@ impl FromStr for Monkey {
 }
 
 impl Monkey {
-    fn inspect(&mut self, apply_relief: bool, r: usize) -> Vec<(usize, usize)> {
+    /// Returns `None` if a worry level overflows.
+    fn inspect(&mut self, apply_relief: bool, r: usize) -> Option<Vec<(usize, usize)>> {
         let mut result = Vec::new();
-        while !self.items.is_empty() {
-            let mut current_item = self.items.pop_front().unwrap();
+        while let Some(mut current_item) = self.items.pop_front() {
             self.inspect_count += 1;
-            current_item = self.operation.calculate_new(current_item);
+            current_item = self.operation.calculate_new(current_item)?;
             if apply_relief {
                 current_item /= 3;
             } else {
@@ -117,37 +124,50 @@ impl Monkey {
                 result.push((self.false_monkey, current_item));
             }
         }
-        result
+        Some(result)
     }
 }
 
-struct InputData {
+pub struct InputData {
     monkeys: Vec<Monkey>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            monkeys: s
-                .split("\n\n")
-                .map(|m| Monkey::from_str(m).unwrap())
-                .collect(),
-        })
+        let monkeys: Vec<Monkey> = s
+            .split("\n\n")
+            .map(Monkey::from_str)
+            .collect::<Result<_, _>>()?;
+        if monkeys.len() < 2 {
+            return Err(ParseError::missing("missing monkeys", s));
+        }
+        for (block, monkey) in s.split("\n\n").zip(&monkeys) {
+            if monkey.true_monkey.max(monkey.false_monkey) >= monkeys.len() {
+                return Err(ParseError::new(
+                    "unknown monkey",
+                    block.lines().last().unwrap_or(block),
+                ));
+            }
+        }
+        Ok(Self { monkeys })
     }
 }
 
 impl InputData {
-    fn play(&self, rounds: usize, apply_relief: bool) -> usize {
+    /// Returns `None` if a worry level overflows.
+    fn play(&self, rounds: usize, apply_relief: bool) -> Option<usize> {
         let mut monkeys = self.monkeys.clone();
         let r = if apply_relief {
             1
         } else {
-            math::vec_lcm(&(monkeys.iter().map(|m| m.div_test).collect::<Vec<usize>>()))
+            monkeys.iter().try_fold(1usize, |r, m| {
+                r.checked_mul(m.div_test / math::gcd(r, m.div_test))
+            })?
         };
         for _ in 0..rounds {
             for i in 0..monkeys.len() {
-                for (destination, item) in &monkeys[i].inspect(apply_relief, r) {
+                for (destination, item) in &monkeys[i].inspect(apply_relief, r)? {
                     monkeys[*destination].items.push_back(*item);
                 }
             }
@@ -155,23 +175,19 @@ impl InputData {
         let mut inspectcounts: Vec<usize> = monkeys.iter().map(|m| m.inspect_count).collect();
         inspectcounts.sort_unstable();
         inspectcounts.reverse();
-        inspectcounts[0] * inspectcounts[1]
+        inspectcounts[0].checked_mul(inspectcounts[1])
     }
 
-    fn solve_part1(&self) -> usize {
+    fn solve_part1(&self) -> Option<usize> {
         self.play(20, true)
     }
 
-    fn solve_part2(&self) -> usize {
+    fn solve_part2(&self) -> Option<usize> {
         self.play(10_000, false)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "Monkey 0:
+const EXAMPLE: &str = "Monkey 0:
   Starting items: 79, 98
   Operation: new = old * 19
   Test: divisible by 23
@@ -199,15 +215,13 @@ Monkey 3:
     If true: throw to monkey 0
     If false: throw to monkey 1";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 10605);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2713310158);
+    fn examples() {
+        check::<InputData>();
     }
 }
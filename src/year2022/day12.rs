@@ -1,33 +1,49 @@
 //! # 2022 day 12 - Hill Climbing Algorithm
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use crate::aoc_util::search::grid_bfs;
 use crate::aoc_util::{grid::*, point::*};
-use std::{
-    collections::{HashSet, VecDeque},
-    error::Error,
-    str::FromStr,
-};
+use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("31").part2("29")];
+
+    const SHAPE: Shape = Shape::Grid("abcdefghijklmnopqrstuvwxyzSE");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
     start: Point,
     end: Point,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut grid = Grid::parse(s);
-        let start = grid.find('S').unwrap();
-        let end = grid.find('E').unwrap();
-        grid.set_point(&start, 'a');
-        grid.set_point(&end, 'z');
+        let mut grid = Grid::parse(s)?;
+        let start = grid
+            .find(b'S')
+            .ok_or_else(|| ParseError::missing("missing start `S`", s))?;
+        let end = grid
+            .find(b'E')
+            .ok_or_else(|| ParseError::missing("missing end `E`", s))?;
+        grid.set_point(&start, b'a');
+        grid.set_point(&end, b'z');
         Ok(Self { grid, start, end })
     }
 }
@@ -42,59 +58,38 @@ impl InputData {
     }
 
     fn bfs(&self, backwards: bool) -> usize {
-        let mut seen = HashSet::new();
-        let mut queue = VecDeque::new();
-        if !backwards {
-            queue.push_back((self.start, 0, Point::new(-1, -1)));
+        let search = if backwards {
+            grid_bfs(
+                &self.grid,
+                self.end,
+                |from, to| from as isize - to as isize <= 1,
+                |p| self.grid.get_element(p) == Some(b'a'),
+            )
         } else {
-            queue.push_back((self.end, 0, Point::new(-1, -1)));
-        }
-        while let Some((current, steps, prev)) = queue.pop_front() {
-            if (!backwards && current == self.end)
-                || (backwards && self.grid.get_element(&current).unwrap() == 'a')
-            {
-                return steps;
-            }
-            if seen.contains(&current) {
-                continue;
-            }
-            seen.insert(current);
-            let v = self.grid.get_element(&current).unwrap();
-            for neighbor in NEIGHBORS_STRAIGHT.iter().map(|&n| n + current) {
-                if neighbor == prev {
-                    continue;
-                }
-                if let Some(e) = self.grid.get_element(&neighbor)
-                    && ((!backwards && e as isize - v as isize <= 1)
-                        || (backwards && v as isize - e as isize <= 1))
-                {
-                    queue.push_back((neighbor, steps + 1, current));
-                }
-            }
-        }
-        0
+            grid_bfs(
+                &self.grid,
+                self.start,
+                |from, to| to as isize - from as isize <= 1,
+                |p| *p == self.end,
+            )
+        };
+        search.cost().unwrap_or(0)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "Sabqponm
+const EXAMPLE: &str = "Sabqponm
 abcryxxl
 accszExk
 acctuvwj
 abdefghi";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 31);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 29);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -2,14 +2,29 @@
 //!
 //! Taking the opportunity to work with Box / Cons list and implementing
 //! the ordering trait.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::Ordering, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("13").part2("140")];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&["[[]{any}]"]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -25,32 +40,41 @@ enum Packet {
 }
 
 impl FromStr for Packet {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn parse_chars(c_input: &mut impl Iterator<Item = char>) -> Packet {
+        fn parse_chars(
+            s: &str,
+            c_input: &mut impl Iterator<Item = (usize, char)>,
+        ) -> Result<Packet, ParseError> {
             let mut int_buffer: Option<u8> = None;
-            while let Some(c) = c_input.next() {
+            while let Some((i, c)) = c_input.next() {
                 match c {
                     '[' => {
-                        return Packet::Cons(
-                            Value::List(Box::new(parse_chars(c_input))),
-                            Box::new(parse_chars(c_input)),
-                        );
+                        return Ok(Packet::Cons(
+                            Value::List(Box::new(parse_chars(s, c_input)?)),
+                            Box::new(parse_chars(s, c_input)?),
+                        ));
                     }
                     ']' => {
                         if let Some(v) = int_buffer {
-                            return Packet::Cons(Value::Integer(v), Box::new(Packet::Empty));
+                            return Ok(Packet::Cons(Value::Integer(v), Box::new(Packet::Empty)));
                         } else {
-                            return Packet::Empty;
+                            return Ok(Packet::Empty);
                         }
                     }
                     ',' => {
                         if let Some(v) = int_buffer {
-                            return Packet::Cons(Value::Integer(v), Box::new(parse_chars(c_input)));
+                            return Ok(Packet::Cons(
+                                Value::Integer(v),
+                                Box::new(parse_chars(s, c_input)?),
+                            ));
                         }
                     }
                     _ => {
-                        let v = c.to_digit(10).unwrap() as u8;
+                        let v = c
+                            .to_digit(10)
+                            .ok_or_else(|| ParseError::new("expected a digit", &s[i..]))?
+                            as u8;
                         if let Some(n) = int_buffer {
                             int_buffer = Some(10 * n + v);
                         } else {
@@ -59,10 +83,11 @@ impl FromStr for Packet {
                     }
                 }
             }
-            Packet::Empty
+            Ok(Packet::Empty)
         }
-        let mut chars = s.chars().skip(1);
-        Ok(parse_chars(&mut chars))
+        parse::strip_prefix(s, "[")?;
+        let mut chars = s.char_indices().skip(1);
+        parse_chars(s, &mut chars)
     }
 }
 
@@ -106,23 +131,20 @@ impl PartialOrd for Packet {
     }
 }
 
-struct InputData {
+pub struct InputData {
     pairs: Vec<(Packet, Packet)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let pairs = s
             .split("\n\n")
             .map(|pair| {
-                let (left, right) = pair.split_once('\n').unwrap();
-                (
-                    Packet::from_str(left).unwrap(),
-                    Packet::from_str(right).unwrap(),
-                )
+                let (left, right) = parse::split_once(pair, "\n")?;
+                Ok((Packet::from_str(left)?, Packet::from_str(right)?))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(Self { pairs })
     }
 }
@@ -154,11 +176,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "[1,1,3,1,1]
+const EXAMPLE: &str = "[1,1,3,1,1]
 [1,1,5,1,1]
 
 [[1],[2,3,4]]
@@ -182,15 +200,13 @@ mod tests {
 [1,[2,[3,[4,[5,6,7]]]],8,9]
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 140);
+    fn examples() {
+        check::<InputData>();
     }
 }
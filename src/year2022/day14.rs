@@ -7,62 +7,88 @@
 //! locked in place or falls off the map into the abyss. This makes it so
 //! that we don't need to start over from the start point for each grain,
 //! which greatly reduces the number of iterations required.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
-use std::{
-    collections::{HashSet, VecDeque},
-    error::Error,
-    str::FromStr,
-};
+use crate::aoc_util::sparse_grid::SparseGrid;
+use std::{collections::VecDeque, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("24").part2("93")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int},{int}", "{int},{int} -> [0123456789,> -]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.pour_sand(false)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.pour_sand(true)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     rock_joints: Vec<Vec<Point>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            rock_joints: s
-                .lines()
-                .map(|line| {
-                    line.split(" -> ")
-                        .map(|s| Point::from_str(s).unwrap())
-                        .collect()
-                })
-                .collect(),
-        })
+        let rock_joints: Vec<Vec<Point>> = parse::lines(s, |line| {
+            let joints: Vec<Point> = line
+                .split(" -> ")
+                .map(Point::from_str)
+                .collect::<Result<_, _>>()?;
+            if joints
+                .windows(2)
+                .any(|j| j[0].x != j[1].x && j[0].y != j[1].y)
+            {
+                return Err(ParseError::new(
+                    "expected horizontal and vertical lines of rock",
+                    line,
+                ));
+            }
+            Ok(joints)
+        })?;
+        if rock_joints.is_empty() {
+            return Err(ParseError::missing("missing rocks", s));
+        }
+        Ok(Self { rock_joints })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut rocks = HashSet::new();
+    /// Pours sand onto the rocks, and returns the number of grains at rest when the sand starts
+    /// falling below the lowest rock, or with a `floor` below the rocks, when the source gets
+    /// blocked.
+    fn pour_sand(&self, floor: bool) -> usize {
+        let mut cave = SparseGrid::new(b'.');
         for joints in &self.rock_joints {
             for (j1, j2) in joints.iter().zip(joints.iter().skip(1)) {
                 let delta = Point::new((j2.x - j1.x).signum(), (j2.y - j1.y).signum());
                 let mut p = *j1;
                 while p != *j2 {
-                    rocks.insert(p);
+                    cave.set_point(&p, b'#');
                     p += delta;
                 }
-                rocks.insert(p);
+                cave.set_point(&p, b'#');
             }
         }
-        let max_y = rocks.iter().map(|p| p.y).max().unwrap();
+        let max_y = cave.bounds().map_or(0, |(_, max)| max.y);
         let start = Point::new(500, 0);
         let mut current = start;
-        let mut p1 = None;
         let mut count = 0;
         let mut trail = VecDeque::new();
 
         loop {
-            if rocks.contains(&current) {
+            if cave.contains(&current) {
                 if let Some(p) = trail.pop_back() {
                     current = p;
                 } else {
@@ -70,41 +96,40 @@ impl InputData {
                     break;
                 }
             }
-            if current.y > max_y && p1.is_none() {
-                p1 = Some(count);
+            if current.y > max_y && !floor {
+                break;
             }
-            if !rocks.contains(&(current + DOWN)) && current.y < max_y + 1 {
+            if !cave.contains(&(current + DOWN)) && current.y < max_y + 1 {
                 trail.push_back(current);
                 current += DOWN;
-            } else if !rocks.contains(&(current + DOWN + LEFT)) && current.y < max_y + 1 {
+            } else if !cave.contains(&(current + DOWN + LEFT)) && current.y < max_y + 1 {
                 trail.push_back(current);
                 current += DOWN + LEFT;
-            } else if !rocks.contains(&(current + DOWN + RIGHT)) && current.y < max_y + 1 {
+            } else if !cave.contains(&(current + DOWN + RIGHT)) && current.y < max_y + 1 {
                 trail.push_back(current);
                 current += DOWN + RIGHT;
             } else {
                 count += 1;
-                rocks.insert(current);
+                cave.set_point(&current, b'o');
             }
             if current == start {
                 break;
             }
         }
-        (p1.unwrap(), count)
+        count
     }
 }
 
+const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_2_example_1() {
-        let testdata = "498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 24);
-        assert_eq!(p2, 93);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,6 +1,15 @@
 //! # 2022 day 15 - Beacon Exclusion Zone
 //!
 //! Transfered straight from python solution; probably room for improvement.
+//!
+//! The row of part 1 and the size of the search area of part 2 are the parameters `row` and
+//! `max`, as the example uses a smaller area than the puzzle input.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{
     collections::{HashMap, HashSet},
@@ -8,14 +17,43 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("row", "2000000"), Param::new("max", "4000000")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("26")
+        .part2("56000011")
+        .params("row=10,max=20")];
+
+    const SHAPE: Shape =
+        Shape::Lines(&["Sensor at x={int}, y={int}: closest beacon is at x={int}, y={int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(
+            input,
+            params.get("row")?,
+            params.get("max")?,
+        )?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.get_coverage(self.row)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.get_darkpointfreq(self.max)
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
+/// The largest distance of a sensor or beacon from the origin along either axis, which keeps the
+/// arithmetic on the areas of the sensors within `i32`.
+const MAX_COORDINATE: i32 = 100_000_000;
+
 #[derive(Debug)]
 struct Sensor {
     position: Point,
@@ -24,16 +62,21 @@ struct Sensor {
 }
 
 impl FromStr for Sensor {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut nbrs: Vec<i32> = Vec::new();
-        for w in s.split(&['=', ',', ':'][..]) {
-            if let Ok(n) = w.parse() {
-                nbrs.push(n);
-            }
-        }
-        let position = Point::new(nbrs[0], nbrs[1]);
-        let beacon = Point::new(nbrs[2], nbrs[3]);
+        let coordinate = |s| match parse::number::<i32>(s)? {
+            n if n.abs() <= MAX_COORDINATE => Ok(n),
+            _ => Err(ParseError::new(
+                format!("expected a coordinate between -{MAX_COORDINATE} and {MAX_COORDINATE}"),
+                s,
+            )),
+        };
+        let s = parse::strip_prefix(s, "Sensor at x=")?;
+        let (x, s) = parse::split_once(s, ", y=")?;
+        let (y, s) = parse::split_once(s, ": closest beacon is at x=")?;
+        let position = Point::new(coordinate(x)?, coordinate(y)?);
+        let (x, y) = parse::split_once(s, ", y=")?;
+        let beacon = Point::new(coordinate(x)?, coordinate(y)?);
         let range = position.manhattan(&beacon);
         Ok(Self {
             position,
@@ -49,45 +92,41 @@ impl Sensor {
     }
 }
 
-struct InputData {
+pub struct InputData {
     sensors: Vec<Sensor>,
+    row: usize,
+    max: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl InputData {
+    fn parse_input(input: &str, row: usize, max: usize) -> Result<Self, ParseError> {
         Ok(Self {
-            sensors: s
-                .lines()
-                .map(|line| Sensor::from_str(line).unwrap())
-                .collect(),
+            sensors: parse::lines(input, Sensor::from_str)?,
+            row,
+            max,
         })
     }
-}
 
-impl InputData {
     fn get_coverage(&self, row: usize) -> usize {
         let mut x_ranges = Vec::new();
         for sensor in &self.sensors {
-            let x_at_row = sensor.range as i32 - (row as i32 - sensor.position.y).abs();
+            let distance = (row as i64).saturating_sub(sensor.position.y as i64).abs();
+            let x_at_row = (sensor.range as i64).saturating_sub(distance);
             if x_at_row > 0 {
-                x_ranges.push((sensor.position.x - x_at_row, sensor.position.x + x_at_row));
+                let x = sensor.position.x as i64;
+                x_ranges.push((x - x_at_row, x + x_at_row));
             }
         }
         x_ranges.sort();
         // Merge overlapping ranges
-        let mut merged_x_ranges = Vec::from([*x_ranges.first().unwrap()]);
-        for (r_low, r_high) in x_ranges.iter().skip(1) {
-            let (vx, vy) = merged_x_ranges.last_mut().unwrap();
-            if (*vx..=*vy).contains(r_low) {
-                if !(*vx..=*vy).contains(r_high) {
-                    *vy = *r_high;
-                }
-            } else {
-                merged_x_ranges.push((*r_low, *r_high));
+        let mut merged_x_ranges: Vec<(i64, i64)> = Vec::new();
+        for (r_low, r_high) in x_ranges {
+            match merged_x_ranges.last_mut() {
+                Some((vx, vy)) if (*vx..=*vy).contains(&r_low) => *vy = r_high.max(*vy),
+                _ => merged_x_ranges.push((r_low, r_high)),
             }
         }
-        let mut total: i32 = merged_x_ranges
+        let mut total: i64 = merged_x_ranges
             .iter()
             .map(|(low, high)| 1 + high - low)
             .sum();
@@ -95,12 +134,12 @@ impl InputData {
         let beacon_positions: HashSet<Point> = self
             .sensors
             .iter()
-            .filter(|s| s.beacon.y == row as i32)
+            .filter(|s| s.beacon.y as i64 == row as i64)
             .map(|s| s.beacon)
             .collect();
         for beacon in beacon_positions.iter() {
             for (r1, r2) in &merged_x_ranges {
-                if (*r1..=*r2).contains(&beacon.x) {
+                if (*r1..=*r2).contains(&(beacon.x as i64)) {
                     total -= 1;
                 }
             }
@@ -117,7 +156,8 @@ impl InputData {
         true
     }
 
-    fn get_darkpointfreq(&self, maxsize: usize) -> usize {
+    /// Returns `None` if there is no position within the search area that no sensor covers.
+    fn get_darkpointfreq(&self, maxsize: usize) -> Option<usize> {
         let mut lines = HashMap::new();
         for sensor in self.sensors.iter() {
             // Create 4 lines representing the outsides of the sensor's area, y = ax + b, a = [1, -1]
@@ -151,31 +191,19 @@ impl InputData {
             }
         }
 
-        for p in &positions {
-            if (0..maxsize as i32).contains(&p.x)
-                && (0..maxsize as i32).contains(&p.y)
-                && self.position_is_dark(p)
-            {
-                return p.x as usize * 4_000_000 + p.y as usize;
-            }
-        }
-        0
-    }
-
-    fn solve_part1(&self) -> usize {
-        self.get_coverage(2_000_000)
-    }
-
-    fn solve_part2(&self) -> usize {
-        self.get_darkpointfreq(4_000_000)
+        let maxsize = maxsize.min(i32::MAX as usize) as i32;
+        positions
+            .iter()
+            .find(|p| {
+                (0..maxsize).contains(&p.x)
+                    && (0..maxsize).contains(&p.y)
+                    && self.position_is_dark(p)
+            })
+            .map(|p| p.x as usize * 4_000_000 + p.y as usize)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
 Sensor at x=13, y=2: closest beacon is at x=15, y=3
 Sensor at x=12, y=14: closest beacon is at x=10, y=16
@@ -190,15 +218,13 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_coverage(10), 26);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_darkpointfreq(20), 56000011);
+    fn examples() {
+        check::<InputData>();
     }
 }
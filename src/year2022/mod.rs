@@ -13,3 +13,23 @@ pub mod day12;
 pub mod day13;
 pub mod day14;
 pub mod day15;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2022:
+    1 => day01: "Calorie Counting",
+    2 => day02: "Rock Paper Scissors",
+    3 => day03: "Rucksack Reorganization",
+    4 => day04: "Camp Cleanup",
+    5 => day05: "Supply Stacks",
+    6 => day06: "Tuning Trouble",
+    7 => day07: "No Space Left On Device",
+    8 => day08: "Treetop Tree House",
+    9 => day09: "Rope Bridge",
+    10 => day10: "Cathode-Ray Tube",
+    11 => day11: "Monkey in the Middle",
+    12 => day12: "Hill Climbing Algorithm",
+    13 => day13: "Distress Signal",
+    14 => day14: "Regolith Reservoir",
+    15 => day15: "Beacon Exclusion Zone",
+}
@@ -1,19 +1,36 @@
 //! # 2023 day 1 - Trebuchet?!
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::parse_input(input);
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("142"),
+        Example::new(EXAMPLE_2).part2("281"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{word}"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input))
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 const SPELLED_OUT: [&[u8]; 9] = [
     b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
 ];
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     data: Vec<&'a str>,
 }
 
@@ -78,18 +95,27 @@ impl<'a> InputData<'a> {
     }
 }
 
+const EXAMPLE_1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+const EXAMPLE_2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        const TEST_DATA: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        let solution_data = InputData::parse_input(TEST_DATA);
-        assert_eq!(solution_data.solve_part1(), 142);
+    fn examples() {
+        check::<InputData>();
     }
 
     #[test]
@@ -100,19 +126,6 @@ treb7uchet";
         assert_eq!(solution_data.solve_part1(), 0);
     }
 
-    #[test]
-    fn part2_example_1() {
-        const TEST_DATA: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-        let solution_data = InputData::parse_input(TEST_DATA);
-        assert_eq!(solution_data.solve_part2(), 281);
-    }
-
     #[test]
     fn part2_example_2() {
         // Custom test to verify input with either no numbers, or only one spelled out number
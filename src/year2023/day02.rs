@@ -1,12 +1,27 @@
 //! # 2023 day 2 - Cube Conundrum
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("8").part2("2286")];
+
+    const SHAPE: Shape = Shape::Lines(&["Game {int}: {any}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Hand {
@@ -16,18 +31,19 @@ struct Hand {
 }
 
 impl FromStr for Hand {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut red = 0;
         let mut blue = 0;
         let mut green = 0;
         for n in s.split(", ") {
-            let (nbr, color) = n.split_once(' ').unwrap();
-            match color.chars().nth(0) {
-                Some('r') => red += nbr.parse::<usize>().unwrap(),
-                Some('b') => blue += nbr.parse::<usize>().unwrap(),
-                Some('g') => green += nbr.parse::<usize>().unwrap(),
-                _ => return Err(()),
+            let (nbr, color) = parse::split_once(n, " ")?;
+            let nbr: usize = parse::number(nbr)?;
+            match color {
+                "red" => red += nbr,
+                "blue" => blue += nbr,
+                "green" => green += nbr,
+                _ => return Err(ParseError::new("expected a color", color)),
             }
         }
         Ok(Self { red, green, blue })
@@ -58,16 +74,16 @@ struct Game {
 }
 
 impl FromStr for Game {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").unwrap();
-        let (_, gid) = left.split_once(' ').unwrap();
+        let (left, right) = parse::split_once(s, ": ")?;
+        let gid = parse::strip_prefix(left, "Game ")?;
         Ok(Self {
-            game_id: gid.parse().unwrap(),
+            game_id: parse::number(gid)?,
             hands: right
                 .split("; ")
-                .map(|h| Hand::from_str(h).unwrap())
-                .collect(),
+                .map(Hand::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -90,18 +106,15 @@ impl Game {
     }
 }
 
-struct InputData {
+pub struct InputData {
     games: Vec<Game>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            games: s
-                .lines()
-                .map(|line| Game::from_str(line).unwrap())
-                .collect(),
+            games: parse::lines(s, Game::from_str)?,
         })
     }
 }
@@ -120,25 +133,19 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 8);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2286);
+    fn examples() {
+        check::<InputData>();
     }
 }
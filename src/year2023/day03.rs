@@ -1,4 +1,9 @@
 //! # 2023 day 3 - Gear Ratios
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
 use std::{
     collections::{HashMap, HashSet},
@@ -6,12 +11,22 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("4361").part2("467835")];
+
+    const SHAPE: Shape = Shape::Grid("0123456789.*#+$/=%@&-");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
@@ -33,13 +48,13 @@ impl PartItem {
     }
 }
 
-struct InputData {
+pub struct InputData {
     parts: HashMap<PartItem, HashSet<Point>>,
     symbols: HashMap<Point, char>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut number: usize = 0;
         let mut numberpoint = Point::new(0, 0);
@@ -122,11 +137,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "467..114..
+const EXAMPLE: &str = "467..114..
 ...*......
 ..35..633.
 ......#...
@@ -137,6 +148,11 @@ mod tests {
 ...$.*....
 .664.598..";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
     // Home made test to cover items in the rightmost column
     const TEST_DATA_CUSTOM: &str = ".23+..4.
 .......*
@@ -145,9 +161,8 @@ mod tests {
 3..2..*6";
 
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4361);
+    fn examples() {
+        check::<InputData>();
     }
 
     #[test]
@@ -156,12 +171,6 @@ mod tests {
         assert_eq!(solution_data.solve_part1(), 54);
     }
 
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 467835);
-    }
-
     #[test]
     fn part2_example_2() {
         let solution_data = InputData::from_str(TEST_DATA_CUSTOM).unwrap();
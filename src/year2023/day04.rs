@@ -1,86 +1,114 @@
 //! # 2023 day 4 - Scratchcards
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error, str::FromStr, vec};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("13").part2("30")];
+
+    const SHAPE: Shape = Shape::Lines(&["Card {int}: {ints} | {ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Debug)]
 struct Card {
     wincount: usize,
-    score: usize,
 }
 
 impl FromStr for Card {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, nbrs) = s.split_once(": ").unwrap();
-        let (left, right) = nbrs.split_once(" | ").unwrap();
-        let winning_nbrs = left.split_whitespace().map(|v| v.parse().unwrap());
-        let winning_nbrs: HashSet<usize> = HashSet::from_iter(winning_nbrs);
-        let draw_nbrs = right.split_whitespace().map(|v| v.parse().unwrap());
-        let draw_nbrs: HashSet<usize> = HashSet::from_iter(draw_nbrs);
+        let (_, nbrs) = parse::split_once(s, ": ")?;
+        let (left, right) = parse::split_once(nbrs, " | ")?;
+        let winning_nbrs: HashSet<usize> = left
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let draw_nbrs: HashSet<usize> = right
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
         let wincount = winning_nbrs.intersection(&draw_nbrs).count();
-        let score = if wincount > 0 {
-            2_usize.pow((wincount - 1).try_into().unwrap())
-        } else {
-            0
-        };
-        Ok(Self { wincount, score })
+        Ok(Self { wincount })
     }
 }
 
-struct InputData {
+pub struct InputData {
     scratchcards: Vec<Card>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            scratchcards: s.lines().map(|c| Card::from_str(c).unwrap()).collect(),
-        })
+        let scratchcards = parse::lines(s, Card::from_str)?;
+        for ((i, card), line) in scratchcards.iter().enumerate().zip(s.lines()) {
+            if i + card.wincount >= scratchcards.len() {
+                return Err(ParseError::new(
+                    "the card wins copies of cards past the end of the table",
+                    line,
+                ));
+            }
+        }
+        Ok(Self { scratchcards })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
-        self.scratchcards.iter().map(|c| c.score).sum()
+    /// Returns `None` if the total score overflows.
+    fn solve_part1(&self) -> Option<usize> {
+        self.scratchcards.iter().try_fold(0usize, |total, card| {
+            let score = match card.wincount {
+                0 => 0,
+                n => 2_usize.checked_pow((n - 1).try_into().ok()?)?,
+            };
+            total.checked_add(score)
+        })
     }
 
-    fn solve_part2(&self) -> usize {
-        let mut copylist = vec![1; self.scratchcards.len()];
+    /// Returns `None` if the number of cards overflows.
+    fn solve_part2(&self) -> Option<usize> {
+        let mut copylist = vec![1usize; self.scratchcards.len()];
         for (i, card) in self.scratchcards.iter().enumerate() {
-            (1..=card.wincount).for_each(|j| copylist[i + j] += copylist[i]);
+            for j in 1..=card.wincount {
+                copylist[i + j] = copylist[i + j].checked_add(copylist[i])?;
+            }
         }
-        copylist.iter().sum()
+        copylist
+            .iter()
+            .try_fold(0usize, |total, n| total.checked_add(*n))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 30);
+    fn examples() {
+        check::<InputData>();
     }
 }
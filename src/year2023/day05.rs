@@ -2,14 +2,32 @@
 //!
 //! No need to actually create any hashmap or anything between different text identifiers.
 //! Just dump all the maps in a vector in the parsed order.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("35").part2("46")];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["seeds: {ints}"]),
+        Shape::Blocks(&Shape::Lines(&["{word}-to-{word} map:", "{ints}"])),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct MapRange {
@@ -19,12 +37,12 @@ struct MapRange {
 }
 
 impl FromStr for MapRange {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut numbers = s.split_whitespace();
-        let destination = numbers.next().unwrap().parse().unwrap();
-        let source = numbers.next().unwrap().parse().unwrap();
-        let range = numbers.next().unwrap().parse().unwrap();
+        let destination = parse::number(parse::next(&mut numbers, "destination", s)?)?;
+        let source = parse::number(parse::next(&mut numbers, "source", s)?)?;
+        let range = parse::number(parse::next(&mut numbers, "range length", s)?)?;
         Ok(Self {
             destination,
             source,
@@ -38,13 +56,13 @@ struct CategoryMap {
 }
 
 impl FromStr for CategoryMap {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ranges = s
             .lines()
             .skip(1)
-            .map(|line| MapRange::from_str(line).unwrap())
-            .collect();
+            .map(MapRange::from_str)
+            .collect::<Result<_, _>>()?;
         Ok(Self { ranges })
     }
 }
@@ -99,22 +117,23 @@ impl CategoryMap {
     }
 }
 
-struct InputData {
+pub struct InputData {
     seeds: Vec<usize>,
     maps: Vec<CategoryMap>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut blocks = s.split("\n\n");
-        let seedline = blocks.next().unwrap();
-        let seeds: Vec<usize> = seedline
+        let seedline = parse::next(&mut blocks, "seeds", s)?;
+        let seeds: Vec<usize> = parse::strip_prefix(seedline, "seeds:")?
             .split_whitespace()
-            .skip(1)
-            .map(|i| i.parse().unwrap())
-            .collect();
-        let maps = blocks.map(|b| CategoryMap::from_str(b).unwrap()).collect();
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let maps = blocks
+            .map(CategoryMap::from_str)
+            .collect::<Result<_, _>>()?;
         Ok(Self { seeds, maps })
     }
 }
@@ -140,11 +159,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "seeds: 79 14 55 13
+const EXAMPLE: &str = "seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -178,15 +193,13 @@ humidity-to-location map:
 60 56 37
 56 93 4";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 35);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 46);
+    fn examples() {
+        check::<InputData>();
     }
 }
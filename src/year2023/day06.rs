@@ -1,12 +1,27 @@
 //! # 2023 day 6 - Wait For It
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("288").part2("71503")];
+
+    const SHAPE: Shape = Shape::Lines(&["Time: {ints}", "Distance: {ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -36,21 +51,25 @@ impl Race {
     }
 }
 
-struct InputData {
+pub struct InputData {
     races: Vec<Race>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (times, distances) = s.split_once('\n').unwrap();
-        let (_, times) = times.split_once(':').unwrap();
-        let (_, distances) = distances.split_once(':').unwrap();
+        let (times, distances) = parse::split_once(s, "\n")?;
+        let times = parse::strip_prefix(times, "Time:")?;
+        let distances = parse::strip_prefix(distances, "Distance:")?;
+        let numbers = |s: &str| {
+            s.split_whitespace()
+                .map(parse::number)
+                .collect::<Result<Vec<_>, _>>()
+        };
         Ok(Self {
-            races: times
-                .split_whitespace()
-                .map(|v| v.parse().unwrap())
-                .zip(distances.split_whitespace().map(|v| v.parse().unwrap()))
+            races: numbers(times)?
+                .into_iter()
+                .zip(numbers(distances)?)
                 .map(Race::from)
                 .collect(),
         })
@@ -79,22 +98,16 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "Time:      7  15   30
-Distance:  9  40  200";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 288);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 71503);
+    fn examples() {
+        check::<InputData>();
     }
 }
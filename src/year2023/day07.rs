@@ -1,12 +1,27 @@
 //! # 2023 day 7 - Camel Cards
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("6440").part2("5905")];
+
+    const SHAPE: Shape = Shape::Lines(&["{word} {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 enum HandResult {
@@ -54,27 +69,22 @@ struct Hand {
 }
 
 impl FromStr for Hand {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(' ').unwrap();
-        let bid = right.parse().unwrap();
+        let (left, right) = parse::split_once(s, " ")?;
+        let bid = parse::number(right)?;
         let cards: Vec<u8> = left
-            .chars()
-            .map(|c| {
-                if c.is_numeric() {
-                    c.to_digit(10).unwrap() as u8
-                } else {
-                    match c {
-                        'T' => 10,
-                        'J' => 11,
-                        'Q' => 12,
-                        'K' => 13,
-                        'A' => 14,
-                        _ => unreachable!(),
-                    }
-                }
+            .char_indices()
+            .map(|(i, c)| match c {
+                '2'..='9' => Ok(c as u8 - b'0'),
+                'T' => Ok(10),
+                'J' => Ok(11),
+                'Q' => Ok(12),
+                'K' => Ok(13),
+                'A' => Ok(14),
+                _ => Err(ParseError::new("expected a card", &left[i..])),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         let mut count = [0; 15];
         for c in &cards {
             count[*c as usize] += 1;
@@ -121,18 +131,15 @@ impl Hand {
     }
 }
 
-struct InputData {
+pub struct InputData {
     hands: Vec<Hand>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            hands: s
-                .lines()
-                .map(|line| Hand::from_str(line).unwrap())
-                .collect(),
+            hands: parse::lines(s, Hand::from_str)?,
         })
     }
 }
@@ -164,35 +171,39 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "32T3K 765
+const EXAMPLE: &str = "32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
+    }
+
     #[test]
     fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
+        let solution_data = InputData::from_str(EXAMPLE).unwrap();
         assert_eq!(solution_data.hands[0].get_power(), 0x232a3d);
         assert_eq!(solution_data.hands[1].get_power(), 0x4a55b5);
         assert_eq!(solution_data.hands[2].get_power(), 0x3dd677);
         assert_eq!(solution_data.hands[3].get_power(), 0x3dabba);
         assert_eq!(solution_data.hands[4].get_power(), 0x4cccbe);
-        assert_eq!(solution_data.solve_part1(), 6440);
     }
 
     #[test]
     fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
+        let solution_data = InputData::from_str(EXAMPLE).unwrap();
         assert_eq!(solution_data.hands[0].get_joker_power(), 0x232a3d);
         assert_eq!(solution_data.hands[1].get_joker_power(), 0x6a5515);
         assert_eq!(solution_data.hands[2].get_joker_power(), 0x3dd677);
         assert_eq!(solution_data.hands[3].get_joker_power(), 0x6da11a);
         assert_eq!(solution_data.hands[4].get_joker_power(), 0x6ccc1e);
-        assert_eq!(solution_data.solve_part2(), 5905);
     }
 }
@@ -1,13 +1,37 @@
 //! # 2023 day 8 - Haunted Wasteland
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use crate::aoc_util::math;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2"),
+        Example::new(EXAMPLE_2).part1("6"),
+        Example::new(EXAMPLE_3).part2("6"),
+    ];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["[LR]"]),
+        Shape::Lines(&["{word} = ({word}, {word})"]),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(PartialEq)]
@@ -17,58 +41,78 @@ enum Direction {
 }
 
 impl Direction {
-    fn new(c: &char) -> Self {
+    fn new(c: &char) -> Option<Self> {
         match *c {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => unreachable!(),
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
         }
     }
 }
 
 /// The node identifiers are converted from the 3-character string in the input into an integer,
 /// using 36 as base (0-9 + A-Z = 36 chars), and then the tree is stored in a hashmap.
-struct InputData {
+pub struct InputData {
     sequence: Vec<Direction>,
     nodetree: HashMap<u32, (u32, u32)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut nodetree = HashMap::new();
-        let (block1, block2) = s.split_once("\n\n").unwrap();
-        let sequence = block1.chars().map(|c| Direction::new(&c)).collect();
+        let node = |s: &str| {
+            if s.len() == 3
+                && s.bytes()
+                    .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            {
+                Ok(u32::from_str_radix(s, 36).expect("the node is alphanumeric"))
+            } else {
+                Err(ParseError::new("expected a node", s))
+            }
+        };
+        let (block1, block2) = parse::split_once(s, "\n\n")?;
+        let sequence = block1
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::new(&c).ok_or_else(|| ParseError::new("expected L or R", &block1[i..]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if sequence.is_empty() {
+            return Err(ParseError::missing("missing directions", block1));
+        }
+        let mut targets = Vec::new();
         for line in block2.lines() {
-            let (from_side, to_side) = line.split_once(" = (").unwrap();
-            let from = u32::from_str_radix(from_side, 36).unwrap();
-            let (left, right) = to_side.split_once(", ").unwrap();
-            let right = right.strip_suffix(')').unwrap();
-            let left = u32::from_str_radix(left, 36).unwrap();
-            let right = u32::from_str_radix(right, 36).unwrap();
-            nodetree.insert(from, (left, right));
+            let (from_side, to_side) = parse::split_once(line, " = (")?;
+            let (left, right) = parse::split_once(to_side, ", ")?;
+            let (right, _) = parse::split_once(right, ")")?;
+            nodetree.insert(node(from_side)?, (node(left)?, node(right)?));
+            targets.extend([left, right]);
+        }
+        if let Some(unknown) = targets
+            .iter()
+            .find(|t| !nodetree.contains_key(&node(t).expect("the node was parsed")))
+        {
+            return Err(ParseError::new("unknown node", unknown));
         }
         Ok(Self { sequence, nodetree })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
-        let mut current_element = u32::from_str_radix("AAA", 36).unwrap();
-        let target_element = u32::from_str_radix("ZZZ", 36).unwrap();
-        let mut stepcount = 0;
-        while current_element != target_element {
-            current_element = if self.sequence[stepcount % self.sequence.len()] == Direction::Left {
-                self.nodetree.get(&current_element).unwrap().0
-            } else {
-                self.nodetree.get(&current_element).unwrap().1
-            };
-            stepcount += 1;
+    /// Returns `None` if there is no node `AAA`, or if `ZZZ` can't be reached from it.
+    fn solve_part1(&self) -> Option<usize> {
+        let start = u32::from_str_radix("AAA", 36).unwrap();
+        let target = u32::from_str_radix("ZZZ", 36).unwrap();
+        if !self.nodetree.contains_key(&start) {
+            return None;
         }
-        stepcount
+        self.count_steps(start, |node| node == target)
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if there are no nodes ending in `A`, if a node ending in `Z` can't be
+    /// reached from one of them, or if the number of steps overflows.
+    fn solve_part2(&self) -> Option<usize> {
         let start = u32::from_str_radix("A", 36).unwrap();
         let target = u32::from_str_radix("Z", 36).unwrap();
         let start_elements: Vec<_> = self
@@ -77,31 +121,38 @@ impl InputData {
             .filter(|k| *k % 36 == start)
             .copied()
             .collect();
+        if start_elements.is_empty() {
+            return None;
+        }
         let mut steps = self.sequence.len();
         for element in &start_elements {
-            let mut stepcount = 0;
-            let mut current = *element;
-            while current % 36 != target {
-                current = if self.sequence[stepcount % self.sequence.len()] == Direction::Left {
-                    self.nodetree.get(&current).unwrap().0
-                } else {
-                    self.nodetree.get(&current).unwrap().1
-                };
-                stepcount += 1;
+            let stepcount = self.count_steps(*element, |node| node % 36 == target)?;
+            steps = steps.checked_mul(stepcount / math::gcd(steps, stepcount))?;
+        }
+        Some(steps)
+    }
+
+    /// Returns the number of steps from `start` until `is_target` holds, or `None` if it never
+    /// does, which is certain once every node has been visited at every position of the sequence.
+    fn count_steps(&self, start: u32, is_target: impl Fn(u32) -> bool) -> Option<usize> {
+        let max_steps = self.nodetree.len().checked_mul(self.sequence.len())?;
+        let mut current = start;
+        for stepcount in 0..=max_steps {
+            if is_target(current) {
+                return Some(stepcount);
             }
-            steps = math::lcm(steps, stepcount);
+            let (left, right) = self.nodetree[&current];
+            current = if self.sequence[stepcount % self.sequence.len()] == Direction::Left {
+                left
+            } else {
+                right
+            };
         }
-        steps
+        None
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "RL
+const EXAMPLE_1: &str = "RL
 
 AAA = (BBB, CCC)
 BBB = (DDD, EEE)
@@ -110,24 +161,14 @@ DDD = (DDD, DDD)
 EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2);
-    }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "LLR
+const EXAMPLE_2: &str = "LLR
 
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 6);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "LR
+const EXAMPLE_3: &str = "LR
 
 11A = (11B, XXX)
 11B = (XXX, 11Z)
@@ -137,7 +178,14 @@ ZZZ = (ZZZ, ZZZ)";
 22C = (22Z, 22Z)
 22Z = (22B, 22B)
 XXX = (XXX, XXX)";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
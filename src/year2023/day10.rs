@@ -1,43 +1,68 @@
 //! # 2023 day 10 - Pipe Maze
-use std::{error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
 use crate::aoc_util::{
     grid::Grid,
     point::{self, Point},
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("4"),
+        Example::new(EXAMPLE_2).part1("8"),
+        Example::new(EXAMPLE_3).part2("4"),
+        Example::new(EXAMPLE_4).part2("8"),
+    ];
+
+    const SHAPE: Shape = Shape::Grid("|-LJ7F.S");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.pipe_path()
+            .map_or(Answer::NotApplicable, |path| Answer::from(path.len() / 2))
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.pipe_path().map_or(Answer::NotApplicable, |path| {
+            Answer::from(enclosed_tiles(path))
+        })
+    }
 }
 
-fn get_connection_directions(c: &char, outgoing: bool) -> Vec<Point> {
+fn get_connection_directions(c: &u8, outgoing: bool) -> Vec<Point> {
     match c {
-        '|' => vec![point::UP, point::DOWN],
-        '-' => vec![point::LEFT, point::RIGHT],
-        'L' => {
+        b'|' => vec![point::UP, point::DOWN],
+        b'-' => vec![point::LEFT, point::RIGHT],
+        b'L' => {
             if outgoing {
                 vec![point::UP, point::RIGHT]
             } else {
                 vec![point::DOWN, point::LEFT]
             }
         }
-        'J' => {
+        b'J' => {
             if outgoing {
                 vec![point::UP, point::LEFT]
             } else {
                 vec![point::DOWN, point::RIGHT]
             }
         }
-        '7' => {
+        b'7' => {
             if outgoing {
                 vec![point::DOWN, point::LEFT]
             } else {
                 vec![point::UP, point::RIGHT]
             }
         }
-        'F' => {
+        b'F' => {
             if outgoing {
                 vec![point::DOWN, point::RIGHT]
             } else {
@@ -48,21 +73,36 @@ fn get_connection_directions(c: &char, outgoing: bool) -> Vec<Point> {
     }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
+    start_pos: Point,
+    pipe_path: OnceCell<Option<Vec<Point>>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid = Grid::parse(s);
-        Ok(Self { grid })
+        let grid = Grid::parse(s)?;
+        let start_pos = grid
+            .find(b'S')
+            .ok_or_else(|| ParseError::missing("missing the start tile `S`", s))?;
+        Ok(Self {
+            grid,
+            start_pos,
+            pipe_path: OnceCell::new(),
+        })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let start_pos = self.grid.find('S').unwrap();
+    /// Returns the points of the loop, starting from `S`, or `None` if the loop is broken. Both
+    /// parts need the loop, so it's only traced once.
+    fn pipe_path(&self) -> Option<&[Point]> {
+        self.pipe_path.get_or_init(|| self.trace_loop()).as_deref()
+    }
+
+    fn trace_loop(&self) -> Option<Vec<Point>> {
+        let start_pos = self.start_pos;
         let mut current_dir = point::UP;
         for d in &point::NEIGHBORS_STRAIGHT {
             if let Some(c) = self.grid.get_element(&(start_pos + *d))
@@ -76,8 +116,12 @@ impl InputData {
         let mut current_pos = start_pos + current_dir;
         while current_pos != start_pos {
             pipe_path.push(current_pos);
-            for d in &get_connection_directions(&self.grid.get_element(&current_pos).unwrap(), true)
-            {
+            let connections =
+                get_connection_directions(&self.grid.get_element(&current_pos)?, true);
+            if connections.is_empty() || pipe_path.len() > self.grid.elements.len() {
+                return None;
+            }
+            for d in &connections {
                 // Every point has two connections - make sure we don't go back the way we came in
                 if !(d.x == -current_dir.x && d.y == -current_dir.y) {
                     current_dir = *d;
@@ -86,53 +130,37 @@ impl InputData {
             }
             current_pos += current_dir;
         }
-        let pipelen = pipe_path.len();
-
-        // Calculate shoelace area
-        // Add start point to the end of the path to connect also the last entry
-        pipe_path.push(start_pos);
-        let mut area = 0;
-        for p in pipe_path.windows(2) {
-            area += (p[0].x * p[1].y) - (p[1].x * p[0].y);
-        }
-        let area = (area.unsigned_abs() / 2) as usize;
-        // Use Pick's theorem to calculate the contained area
-        let p2 = area + 1 - (pipelen / 2);
-        (pipelen / 2, p2)
+        Some(pipe_path)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Returns the number of tiles enclosed by the loop `pipe_path`.
+fn enclosed_tiles(pipe_path: &[Point]) -> usize {
+    let pipelen = pipe_path.len();
+    // Calculate shoelace area
+    // Chain the start point to the end of the path to connect also the last entry
+    let mut area = 0;
+    for (a, b) in pipe_path.iter().zip(pipe_path.iter().cycle().skip(1)) {
+        area += (a.x * b.y) - (b.x * a.y);
+    }
+    let area = (area.unsigned_abs() / 2) as usize;
+    // Use Pick's theorem to calculate the contained area
+    (area + 1).saturating_sub(pipelen / 2)
+}
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = ".....
+const EXAMPLE_1: &str = ".....
 .S-7.
 .|.|.
 .L-J.
 .....";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 4);
-    }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "..F7.
+const EXAMPLE_2: &str = "..F7.
 .FJ|.
 SJ.L7
 |F--J
 LJ...";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 8);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "...........
+const EXAMPLE_3: &str = "...........
 .S-------7.
 .|F-----7|.
 .||.....||.
@@ -141,14 +169,8 @@ LJ...";
 .|..|.|..|.
 .L--J.L--J.
 ...........";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 4);
-    }
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = ".F----7F7F7F7F-7....
+const EXAMPLE_4: &str = ".F----7F7F7F7F-7....
 .|F--7||||||||FJ....
 .||.FJ||||||||L7....
 FJL7L7LJLJ||LJ.L-7..
@@ -158,8 +180,14 @@ L--J.L7...LJS7F-7L7.
 .....|FJLJ|FJ|F7|.LJ
 ....FJL-7.||.||||...
 ....L---J.LJ.LJLJ...";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
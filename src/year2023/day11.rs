@@ -1,17 +1,48 @@
 //! # 2023 day 11 - Cosmic Expansion
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
-use std::{collections::HashSet, error::Error, str::FromStr};
+use std::{collections::HashSet, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve(2, 1_000_000);
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("expansion", "1000000")];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE)
+            .part1("374")
+            .part2("1030")
+            .params("expansion=10"),
+        Example::new(EXAMPLE).part2("8410").params("expansion=100"),
+    ];
+
+    const SHAPE: Shape = Shape::Grid(".#");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("expansion")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve(2)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve(self.expansion)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     galaxies: Vec<Point>,
     empty_x: HashSet<usize>,
     empty_y: HashSet<usize>,
+    expansion: usize,
 }
 
 impl Point {
@@ -30,9 +61,8 @@ impl Point {
     }
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl InputData {
+    fn parse_input(s: &str, expansion: usize) -> Result<Self, ParseError> {
         let mut gal = Vec::new();
         let mut x_points = HashSet::new();
         let mut y_points = HashSet::new();
@@ -70,12 +100,11 @@ impl FromStr for InputData {
             galaxies: gal,
             empty_x,
             empty_y,
+            expansion,
         })
     }
-}
 
-impl InputData {
-    fn solve(&self, small_exp_rate: usize, large_exp_rate: usize) -> (usize, usize) {
+    fn solve(&self, exp_rate: usize) -> usize {
         let mut total_steps: usize = 0;
         let mut total_empty_space: usize = 0;
         for (i, g1) in self.galaxies.iter().enumerate() {
@@ -95,20 +124,11 @@ impl InputData {
                 total_steps += g1.manhattan(g2);
             }
         }
-        (
-            total_steps + total_empty_space * (small_exp_rate - 1),
-            total_steps + total_empty_space * (large_exp_rate - 1),
-        )
+        total_steps + total_empty_space * (exp_rate - 1)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parts1_2_example_1() {
-        let testdata = "...#......
+const EXAMPLE: &str = "...#......
 .......#..
 #.........
 ..........
@@ -118,12 +138,14 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve(2, 10);
-        assert_eq!(p1, 374);
-        assert_eq!(p2, 1030);
-        let (p1, p2) = solution_data.solve(2, 100);
-        assert_eq!(p1, 374);
-        assert_eq!(p2, 8410);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,14 +1,36 @@
 //! # 2023 day 12 - Hot Springs
 //!
 //! Pretty much a straight transfer of my ugly old Python solution.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1").part2("1"),
+        Example::new(EXAMPLE_2).part1("4").part2("16384"),
+        Example::new(EXAMPLE_3).part1("1").part2("1"),
+        Example::new(EXAMPLE_4).part1("1").part2("16"),
+        Example::new(EXAMPLE_5).part1("4").part2("2500"),
+        Example::new(EXAMPLE_6).part1("10").part2("506250"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["[.#?] {ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Hash, Eq, PartialEq, Clone)]
@@ -18,12 +40,15 @@ struct SpringRow {
 }
 
 impl FromStr for SpringRow {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (springs, group_sizes) = s.split_once(' ').unwrap();
+        let (springs, group_sizes) = parse::split_once(s, " ")?;
         Ok(Self {
             springs: springs.to_string(),
-            group_sizes: group_sizes.split(',').map(|c| c.parse().unwrap()).collect(),
+            group_sizes: group_sizes
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -95,18 +120,15 @@ impl SpringRow {
     }
 }
 
-struct InputData {
+pub struct InputData {
     rows: Vec<SpringRow>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            rows: s
-                .lines()
-                .map(|line| SpringRow::from_str(line).unwrap())
-                .collect(),
+            rows: parse::lines(s, SpringRow::from_str)?,
         })
     }
 }
@@ -137,91 +159,25 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "???.### 1,1,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let testdata = ".??..??...?##. 1,1,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
-
-    #[test]
-    fn part1_example_3() {
-        let testdata = "?#?#?#?#?#?#?#? 1,3,1,6";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
-
-    #[test]
-    fn part1_example_4() {
-        let testdata = "????.#...#... 4,1,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1);
-    }
-
-    #[test]
-    fn part1_example_5() {
-        let testdata = "????.######..#####. 1,6,5";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
+const EXAMPLE_1: &str = "???.### 1,1,3";
 
-    #[test]
-    fn part1_example_6() {
-        let testdata = "?###???????? 3,2,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 10);
-    }
+const EXAMPLE_2: &str = ".??..??...?##. 1,1,3";
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "???.### 1,1,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_3: &str = "?#?#?#?#?#?#?#? 1,3,1,6";
 
-    #[test]
-    fn part2_example_2() {
-        let testdata = ".??..??...?##. 1,1,3";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 16384);
-    }
+const EXAMPLE_4: &str = "????.#...#... 4,1,1";
 
-    #[test]
-    fn part2_example_3() {
-        let testdata = "?#?#?#?#?#?#?#? 1,3,1,6";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 1);
-    }
+const EXAMPLE_5: &str = "????.######..#####. 1,6,5";
 
-    #[test]
-    fn part2_example_4() {
-        let testdata = "????.#...#... 4,1,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 16);
-    }
+const EXAMPLE_6: &str = "?###???????? 3,2,1";
 
-    #[test]
-    fn part2_example_5() {
-        let testdata = "????.######..#####. 1,6,5";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2500);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_6() {
-        let testdata = "?###???????? 3,2,1";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 506250);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,12 +1,28 @@
 //! # 2023 day 13 - Point of Incidence
-use std::{error::Error, str::FromStr, vec};
-
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::grid::Grid;
+use crate::aoc_util::parse::ParseError;
+use std::{error::Error, str::FromStr};
+
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("405").part2("400")];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Grid(".#"));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 /// If [wildcard] == [true], the wild card for part 2 is still not used
@@ -55,21 +71,24 @@ struct Pattern {
 }
 
 impl FromStr for Pattern {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nbr_rows = s.lines().count();
-        let nbr_cols = s.lines().last().unwrap().len();
-        let mut rows = vec![0; nbr_rows];
-        let mut cols = vec![0; nbr_cols];
-        for (row, line) in s.lines().enumerate() {
-            for (col, c) in line.chars().enumerate() {
-                if c == '#' {
-                    rows[row] += 1 << (nbr_cols - col - 1);
-                    cols[col] += 1 << (nbr_rows - row - 1);
-                }
-            }
+        let grid = Grid::parse(s)?;
+        if grid.elements.is_empty() {
+            return Err(ParseError::new("expected a pattern", s));
         }
-        Ok(Self { rows, cols })
+        let encode = |grid: &Grid| {
+            grid.rows()
+                .map(|row| {
+                    row.iter()
+                        .fold(0, |acc, c| acc << 1 | usize::from(*c == b'#'))
+                })
+                .collect()
+        };
+        Ok(Self {
+            rows: encode(&grid),
+            cols: encode(&grid.transpose()),
+        })
     }
 }
 
@@ -79,18 +98,18 @@ impl Pattern {
     }
 }
 
-struct InputData {
+pub struct InputData {
     patterns: Vec<Pattern>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             patterns: s
                 .split("\n\n")
-                .map(|block| Pattern::from_str(block).unwrap())
-                .collect(),
+                .map(Pattern::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -105,11 +124,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "#.##..##.
+const EXAMPLE: &str = "#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -125,15 +140,13 @@ mod tests {
 ..##..###
 #....#..#";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 405);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 400);
+    fn examples() {
+        check::<InputData>();
     }
 }
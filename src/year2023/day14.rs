@@ -1,14 +1,29 @@
 //! # 2023 day 14 - Parabolic Reflector Dish
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::HashMap, error::Error, str::FromStr};
 
 use crate::aoc_util::{grid::Grid, point::Point};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("136").part2("64")];
+
+    const SHAPE: Shape = Shape::Grid(".#O");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Grid {
@@ -19,11 +34,11 @@ impl Grid {
                 let current_point = Point::new(x, y);
                 if let Some(e) = self.get_element(&current_point) {
                     match e {
-                        '#' => floor = y + 1,
-                        'O' => {
+                        b'#' => floor = y + 1,
+                        b'O' => {
                             if y > floor {
-                                self.set_point(&Point::new(x, floor), 'O');
-                                self.set_point(&current_point, '.');
+                                self.set_point(&Point::new(x, floor), b'O');
+                                self.set_point(&current_point, b'.');
                             }
                             floor += 1;
                         }
@@ -41,11 +56,11 @@ impl Grid {
                 let current_point = Point::new(x, y);
                 if let Some(e) = self.get_element(&current_point) {
                     match e {
-                        '#' => floor = y - 1,
-                        'O' => {
+                        b'#' => floor = y - 1,
+                        b'O' => {
                             if y < floor {
-                                self.set_point(&Point::new(x, floor), 'O');
-                                self.set_point(&current_point, '.');
+                                self.set_point(&Point::new(x, floor), b'O');
+                                self.set_point(&current_point, b'.');
                             }
                             floor -= 1;
                         }
@@ -63,11 +78,11 @@ impl Grid {
                 let current_point = Point::new(x, y);
                 if let Some(e) = self.get_element(&current_point) {
                     match e {
-                        '#' => floor = x - 1,
-                        'O' => {
+                        b'#' => floor = x - 1,
+                        b'O' => {
                             if x < floor {
-                                self.set_point(&Point::new(floor, y), 'O');
-                                self.set_point(&current_point, '.');
+                                self.set_point(&Point::new(floor, y), b'O');
+                                self.set_point(&current_point, b'.');
                             }
                             floor -= 1;
                         }
@@ -85,11 +100,11 @@ impl Grid {
                 let current_point = Point::new(x, y);
                 if let Some(e) = self.get_element(&current_point) {
                     match e {
-                        '#' => floor = x + 1,
-                        'O' => {
+                        b'#' => floor = x + 1,
+                        b'O' => {
                             if x > floor {
-                                self.set_point(&Point::new(floor, y), 'O');
-                                self.set_point(&current_point, '.');
+                                self.set_point(&Point::new(floor, y), b'O');
+                                self.set_point(&current_point, b'.');
                             }
                             floor += 1;
                         }
@@ -113,21 +128,21 @@ impl Grid {
         self.elements
             .iter()
             .enumerate()
-            .filter(|(_, c)| **c == 'O')
+            .filter(|(_, c)| **c == b'O')
             .map(|(i, _)| self.y_max - (i / self.x_max))
             .sum()
     }
 }
 
-struct InputData {
+pub struct InputData {
     dish: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            dish: Grid::parse(s),
+            dish: Grid::parse(s)?,
         })
     }
 }
@@ -159,11 +174,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "O....#....
+const EXAMPLE: &str = "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -174,15 +185,13 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 136);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 64);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,12 +1,27 @@
 //! # 2023 day 15 - Lens Library
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("1320").part2("145")];
+
+    const SHAPE: Shape = Shape::Lines(&["[abcdefghijklmnopqrstuvwxyz0123456789=,-]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 fn hash_algorithm(input: &str) -> usize {
@@ -20,16 +35,47 @@ struct Lens<'b> {
     strength: usize,
 }
 
-struct InputData<'a> {
+enum Operation<'a> {
+    Insert(Lens<'a>),
+    Remove(&'a str),
+}
+
+impl<'a> TryFrom<&'a str> for Operation<'a> {
+    type Error = ParseError;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        if let Some((label, strength)) = s.split_once('=') {
+            let strength = match parse::number(strength)? {
+                n @ 1..=9 => n,
+                _ => {
+                    return Err(ParseError::new(
+                        "expected a focal length from 1 to 9",
+                        strength,
+                    ));
+                }
+            };
+            Ok(Self::Insert(Lens { label, strength }))
+        } else if let Some(label) = s.strip_suffix('-') {
+            Ok(Self::Remove(label))
+        } else {
+            Err(ParseError::new("expected `=` or `-`", s))
+        }
+    }
+}
+
+pub struct InputData<'a> {
     steps: Vec<&'a str>,
+    operations: Vec<Operation<'a>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            steps: s.split(",").collect(),
-        })
+        let steps: Vec<_> = s.split(",").collect();
+        let operations = steps
+            .iter()
+            .map(|step| Operation::try_from(*step))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { steps, operations })
     }
 }
 
@@ -42,20 +88,21 @@ impl InputData<'_> {
         let mut boxes: Vec<Vec<Lens<'_>>> = Vec::new();
         boxes.resize_with(256, Vec::new);
 
-        for step in self.steps.iter() {
-            if let Some((label, strength)) = step.split_once('=') {
-                let strength: usize = strength.parse().unwrap();
-                let hashed = hash_algorithm(label);
-                if let Some(idx) = boxes[hashed].iter().position(|lens| lens.label == label) {
-                    boxes[hashed][idx].strength = strength;
-                } else {
-                    boxes[hashed].push(Lens { label, strength });
+        for operation in self.operations.iter() {
+            match *operation {
+                Operation::Insert(Lens { label, strength }) => {
+                    let hashed = hash_algorithm(label);
+                    if let Some(idx) = boxes[hashed].iter().position(|lens| lens.label == label) {
+                        boxes[hashed][idx].strength = strength;
+                    } else {
+                        boxes[hashed].push(Lens { label, strength });
+                    }
                 }
-            } else {
-                let label = step.strip_suffix('-').unwrap();
-                let hashed = hash_algorithm(label);
-                if let Some(idx) = boxes[hashed].iter().position(|lens| lens.label == label) {
-                    boxes[hashed].remove(idx);
+                Operation::Remove(label) => {
+                    let hashed = hash_algorithm(label);
+                    if let Some(idx) = boxes[hashed].iter().position(|lens| lens.label == label) {
+                        boxes[hashed].remove(idx);
+                    }
                 }
             }
         }
@@ -73,27 +120,21 @@ impl InputData<'_> {
     }
 }
 
+const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "HASH";
-        assert_eq!(hash_algorithm(&testdata), 52);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1320);
+    fn examples() {
+        check::<InputData>();
     }
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 145);
+    fn part1_example_1() {
+        let testdata = "HASH";
+        assert_eq!(hash_algorithm(testdata), 52);
     }
 }
@@ -1,7 +1,12 @@
-//! # 20xx day 16 - The Floor Will Be Lava
+//! # 2023 day 16 - The Floor Will Be Lava
 //!
 //! Creates a map of connections between all splitters/mirrors to avoid having to walk step by step every time.
 //! Slightly messy code, can probably be made prettier.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
     error::Error,
@@ -10,54 +15,64 @@ use std::{
 
 use crate::aoc_util::{grid::Grid, point::*};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("46").part2("51")];
+
+    const SHAPE: Shape = Shape::Grid(".|-/\\");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 type NextPoint = Option<(Point, Vec<Point>)>; // <Next point, new directions>
 
-struct InputData {
+pub struct InputData {
     nodes: HashMap<Point, HashMap<Point, NextPoint>>, // From point: {out_direction: next_point}
     x_max: i32,
     y_max: i32,
 }
 
-fn bounces(c: &char, direction: &Point) -> bool {
+fn bounces(c: &u8, direction: &Point) -> bool {
     match *direction {
-        UP | DOWN => *c == '/' || *c == '\\' || *c == '-',
-        LEFT | RIGHT => *c == '/' || *c == '\\' || *c == '|',
+        UP | DOWN => *c == b'/' || *c == b'\\' || *c == b'-',
+        LEFT | RIGHT => *c == b'/' || *c == b'\\' || *c == b'|',
         _ => false,
     }
 }
 
-fn get_next_directions(c: &char, direction: &Point) -> Vec<Point> {
+fn get_next_directions(c: &u8, direction: &Point) -> Vec<Point> {
     match *direction {
         UP => match *c {
-            '-' => vec![LEFT, RIGHT],
-            '/' => vec![RIGHT],
-            '\\' => vec![LEFT],
+            b'-' => vec![LEFT, RIGHT],
+            b'/' => vec![RIGHT],
+            b'\\' => vec![LEFT],
             _ => vec![],
         },
         DOWN => match *c {
-            '-' => vec![LEFT, RIGHT],
-            '/' => vec![LEFT],
-            '\\' => vec![RIGHT],
+            b'-' => vec![LEFT, RIGHT],
+            b'/' => vec![LEFT],
+            b'\\' => vec![RIGHT],
             _ => vec![],
         },
         LEFT => match *c {
-            '|' => vec![UP, DOWN],
-            '/' => vec![DOWN],
-            '\\' => vec![UP],
+            b'|' => vec![UP, DOWN],
+            b'/' => vec![DOWN],
+            b'\\' => vec![UP],
             _ => vec![],
         },
         RIGHT => match *c {
-            '|' => vec![UP, DOWN],
-            '/' => vec![UP],
-            '\\' => vec![DOWN],
+            b'|' => vec![UP, DOWN],
+            b'/' => vec![UP],
+            b'\\' => vec![DOWN],
             _ => vec![],
         },
         _ => vec![],
@@ -65,17 +80,15 @@ fn get_next_directions(c: &char, direction: &Point) -> Vec<Point> {
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid = Grid::parse(s);
+        let grid = Grid::parse(s)?;
         let mut nodes = HashMap::new();
         // Find every point containing a bouncer or splitter, and create a map of their closest neighbor in each direction
         let mut points: Vec<Point> = grid
-            .elements
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| **e != '.')
-            .map(|(i, _)| grid.get_point(i))
+            .cells()
+            .filter(|(_, e)| *e != b'.')
+            .map(|(p, _)| p)
             .collect();
         // Also insert all starting points one step outside the grid in each direction
         for x in 0..grid.x_max as i32 {
@@ -197,12 +210,8 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // Note: the test input needs to be adjusted to add an escape backslash before every backslash character
-    const TEST_DATA: &str = ".|...\\....
+// Note: the example input needs to be adjusted to add an escape backslash before every backslash character
+const EXAMPLE: &str = ".|...\\....
 |.-.\\.....
 .....|-...
 ........|.
@@ -213,15 +222,13 @@ mod tests {
 .|....-|.\\
 ..//.|....";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 46);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 51);
+    fn examples() {
+        check::<InputData>();
     }
 }
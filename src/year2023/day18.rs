@@ -1,132 +1,131 @@
 //! # 2023 day 18 - Lavaduct Lagoon
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::{self, Point};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("62").part2("952408144115")];
+
+    const SHAPE: Shape = Shape::Lines(&["[UDLR] {int} (#{word})"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Debug)]
 struct Instruction {
     direction: Point,
     steps: usize,
-    color: String,
+    swapped: (Point, usize),
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split_whitespace();
-        let direction = match parts.next().unwrap() {
+        let direction = match parse::next(&mut parts, "direction", s)? {
             "L" => point::LEFT,
             "R" => point::RIGHT,
             "U" => point::UP,
             "D" => point::DOWN,
-            _ => unreachable!(),
+            d => return Err(ParseError::new("expected L, R, U or D", d)),
         };
-        let steps = parts.next().unwrap().parse().unwrap();
-        let color = parts
-            .next()
-            .unwrap()
-            .trim_start_matches("(#")
-            .trim_end_matches(')')
-            .to_string();
+        let steps = parse::number(parse::next(&mut parts, "steps", s)?)?;
+        let color = parse::next(&mut parts, "color", s)?;
+        let color = parse::strip_prefix(color, "(#")?;
+        let hex = color.strip_suffix(')').unwrap_or(color);
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseError::new("expected a color of six hex digits", color));
+        }
         Ok(Self {
             direction,
             steps,
-            color,
+            swapped: Self::get_swapped_instruction(hex)?,
         })
     }
 }
 
 impl Instruction {
-    fn get_swapped_instruction(&self) -> Self {
-        let direction = match self.color.chars().last().unwrap() {
-            '0' => point::RIGHT,
-            '1' => point::DOWN,
-            '2' => point::LEFT,
-            '3' => point::UP,
-            _ => unreachable!(),
+    /// Reads the direction and steps hidden in the hex digits of the color.
+    fn get_swapped_instruction(hex: &str) -> Result<(Point, usize), ParseError> {
+        let direction = match &hex[5..] {
+            "0" => point::RIGHT,
+            "1" => point::DOWN,
+            "2" => point::LEFT,
+            "3" => point::UP,
+            d => return Err(ParseError::new("expected a direction from 0 to 3", d)),
         };
-        let steps = usize::from_str_radix(&self.color[0..5], 16).unwrap();
-        Self {
-            direction,
-            steps,
-            color: self.color.to_string(),
-        }
-    }
-}
-
-impl Point {
-    #[inline]
-    fn get_determinant(&self, other: &Self) -> i64 {
-        // Need to use 64 bit numbers since the coordinates get really large for part 2
-        (self.x as i64 * other.y as i64) - (self.y as i64 * other.x as i64)
+        let steps = usize::from_str_radix(&hex[0..5], 16).expect("the color is hex digits");
+        Ok((direction, steps))
     }
 }
 
-struct InputData {
+pub struct InputData {
     plan: Vec<Instruction>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            plan: s
-                .lines()
-                .map(|line| Instruction::from_str(line).unwrap())
-                .collect(),
+            plan: parse::lines(s, Instruction::from_str)?,
         })
     }
 }
 
 impl InputData {
-    fn get_area_points(&self, swapped: bool) -> usize {
-        let mut digpath = vec![point::ORIGIN];
+    /// Returns `None` if the coordinates or the area overflow.
+    fn get_area_points(&self, swapped: bool) -> Option<usize> {
+        // Need to use 64 bit numbers since the coordinates get really large for part 2
+        let (mut x, mut y) = (0_i64, 0_i64);
+        let mut areasum: i64 = 0;
+        let mut outline_len: i64 = 0;
         for instr in &self.plan {
-            let next = if !swapped {
-                *digpath.last().unwrap() + (instr.direction) * (instr.steps as i32)
+            let (direction, steps) = if !swapped {
+                (instr.direction, instr.steps)
             } else {
-                let swapped = instr.get_swapped_instruction();
-                *digpath.last().unwrap() + (swapped.direction) * (swapped.steps as i32)
+                instr.swapped
             };
-            digpath.push(next);
+            let steps = i64::try_from(steps).ok()?;
+            let next_x = x.checked_add(direction.x as i64 * steps)?;
+            let next_y = y.checked_add(direction.y as i64 * steps)?;
+            let determinant = x.checked_mul(next_y)?.checked_sub(y.checked_mul(next_x)?)?;
+            areasum = areasum.checked_add(determinant)?;
+            outline_len = outline_len.checked_add(steps)?;
+            (x, y) = (next_x, next_y);
         }
-        // Add starting point at the end to close the loop so we can use the windows function
-        digpath.push(point::ORIGIN);
-
-        let (areasum, outline_len) =
-            digpath
-                .windows(2)
-                .fold((0, 0), |(areasum, outline_len), p| {
-                    (
-                        areasum + p[0].get_determinant(&p[1]),
-                        outline_len + p[0].manhattan(&p[1]),
-                    )
-                });
+        // Close the loop back to the starting point
+        outline_len = outline_len.checked_add(x.checked_abs()?.checked_add(y.checked_abs()?)?)?;
+
         let areasum = areasum.unsigned_abs() as usize;
-        (areasum / 2) + 1 + (outline_len / 2)
+        Some((areasum / 2) + 1 + (outline_len as usize / 2))
     }
 
-    fn solve_part1(&self) -> usize {
+    fn solve_part1(&self) -> Option<usize> {
         self.get_area_points(false)
     }
 
-    fn solve_part2(&self) -> usize {
+    fn solve_part2(&self) -> Option<usize> {
         self.get_area_points(true)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "R 6 (#70c710)
+const EXAMPLE: &str = "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
 D 2 (#d2c081)
@@ -141,15 +140,13 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 62);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 952408144115);
+    fn examples() {
+        check::<InputData>();
     }
 }
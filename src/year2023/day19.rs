@@ -1,17 +1,38 @@
 //! # 2023 day 19 - Aplenty
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     cmp::Ordering,
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("19114")
+        .part2("167409079868000")];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{word}{{any}}"]),
+        Shape::Lines(&["{x={int},m={int},a={int},s={int}}"]),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -23,15 +44,17 @@ struct Rating {
 }
 
 impl FromStr for Rating {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.trim_matches(['{', '}']).split(&[',', '=']).collect();
-        Ok(Self {
-            x: parts[1].parse().unwrap(),
-            m: parts[3].parse().unwrap(),
-            a: parts[5].parse().unwrap(),
-            s: parts[7].parse().unwrap(),
-        })
+        let (ratings, _) = parse::split_once(parse::strip_prefix(s, "{")?, "}")?;
+        let values: Vec<usize> = ratings
+            .split(',')
+            .map(|r| parse::number(parse::split_once(r, "=")?.1))
+            .collect::<Result<_, _>>()?;
+        let [x, m, a, s] = values[..] else {
+            return Err(ParseError::new("expected four ratings", ratings));
+        };
+        Ok(Self { x, m, a, s })
     }
 }
 
@@ -72,18 +95,22 @@ struct Rule {
 }
 
 impl FromStr for Rule {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Some((left, right)) = s.split_once(':') {
+            let category = |label| match label {
+                "x" | "m" | "a" | "s" => Ok(label.to_string()),
+                _ => Err(ParseError::new("expected x, m, a or s", label)),
+            };
             if let Some((label, val)) = left.split_once('<') {
                 return Ok(Self {
-                    condition: Some((label.to_string(), Ordering::Less, val.parse().unwrap())),
+                    condition: Some((category(label)?, Ordering::Less, parse::number(val)?)),
                     next: right.to_string(),
                 });
             }
             if let Some((label, val)) = left.split_once('>') {
                 return Ok(Self {
-                    condition: Some((label.to_string(), Ordering::Greater, val.parse().unwrap())),
+                    condition: Some((category(label)?, Ordering::Greater, parse::number(val)?)),
                     next: right.to_string(),
                 });
             }
@@ -144,42 +171,81 @@ impl Rule {
     }
 }
 
-struct InputData {
+pub struct InputData {
     workflows: HashMap<String, Vec<Rule>>,
     ratings: Vec<Rating>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (wf, rt) = s.split_once("\n\n").unwrap();
+        let (wf, rt) = parse::split_once(s, "\n\n")?;
         let mut workflows = HashMap::new();
+        let mut labels = HashMap::new();
         for line in wf.lines() {
-            let (label, flow) = line.trim_end_matches('}').split_once('{').unwrap();
-            workflows.insert(
-                label.to_string(),
-                flow.split(',')
-                    .map(|r| Rule::from_str(r).unwrap())
-                    .collect(),
-            );
+            let (label, flow) = parse::split_once(line.trim_end_matches('}'), "{")?;
+            let rules: Vec<Rule> = flow
+                .split(',')
+                .map(Rule::from_str)
+                .collect::<Result<_, _>>()?;
+            if rules.last().is_some_and(|rule| rule.condition.is_some()) {
+                return Err(ParseError::new(
+                    "expected the last rule to have no condition",
+                    flow.rsplit(',').next().unwrap_or(flow),
+                ));
+            }
+            workflows.insert(label.to_string(), rules);
+            labels.insert(label.to_string(), line);
         }
-        let ratings = rt
-            .lines()
-            .map(|line| Rating::from_str(line).unwrap())
-            .collect();
+        if !workflows.contains_key("in") {
+            return Err(ParseError::missing("missing workflow `in`", wf));
+        }
+        for (label, rules) in &workflows {
+            if let Some(rule) = rules
+                .iter()
+                .find(|r| r.next != "A" && r.next != "R" && !workflows.contains_key(&r.next))
+            {
+                return Err(ParseError::new(
+                    format!("unknown workflow `{}`", rule.next),
+                    labels[label],
+                ));
+            }
+        }
+        // Resolve the workflows from the end back, which fails if a workflow leads to itself
+        let mut resolved: HashSet<&str> = HashSet::from(["A", "R"]);
+        loop {
+            let before = resolved.len();
+            for (label, rules) in &workflows {
+                if rules.iter().all(|r| resolved.contains(r.next.as_str())) {
+                    resolved.insert(label);
+                }
+            }
+            match workflows.keys().find(|l| !resolved.contains(l.as_str())) {
+                None => break,
+                Some(label) if resolved.len() == before => {
+                    return Err(ParseError::new(
+                        "the workflows lead to each other in a loop",
+                        labels[label],
+                    ));
+                }
+                _ => {}
+            }
+        }
+        let ratings = parse::lines(rt, Rating::from_str)?;
         Ok(Self { workflows, ratings })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if the sum of the ratings overflows.
+    fn solve_part1(&self) -> Option<usize> {
         const START: &str = "in";
         self.ratings
             .iter()
             .map(|r| {
                 let mut flow = START.to_string();
                 while flow != "A" && flow != "R" {
-                    for rule in self.workflows.get(&flow).unwrap() {
+                    for rule in &self.workflows[&flow] {
                         if let Some(n) = rule.process_rule(r) {
                             flow = n;
                             break;
@@ -187,12 +253,12 @@ impl InputData {
                     }
                 }
                 if flow == "A" {
-                    r.x + r.m + r.a + r.s
+                    r.x.checked_add(r.m)?.checked_add(r.a)?.checked_add(r.s)
                 } else {
-                    0
+                    Some(0)
                 }
             })
-            .sum()
+            .try_fold(0usize, |total, n| total.checked_add(n?))
     }
 
     fn solve_part2(&self) -> usize {
@@ -219,7 +285,7 @@ impl InputData {
                 }
                 "R" => (),
                 _ => {
-                    for rule in self.workflows.get(&workflow).unwrap() {
+                    for rule in &self.workflows[&workflow] {
                         let mut done = true;
                         for (new_name, new_ranges) in rule.get_range_split(&ranges) {
                             if let Some(n) = new_name {
@@ -243,11 +309,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "px{a<2006:qkq,m>2090:A,rfg}
+const EXAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}
 lnx{m>1548:A,A}
 rfg{s<537:gd,x>2440:R,A}
@@ -265,15 +327,13 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 19114);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 167409079868000);
+    fn examples() {
+        check::<InputData>();
     }
 }
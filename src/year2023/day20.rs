@@ -2,19 +2,39 @@
 //!
 //! It would probably make more sense to model this with a Module enum instead, but I wanted to
 //! take the opportunity to mess around with the trait and dyn box system.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use crate::aoc_util::math::lcm;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
+    cell::RefCell,
     collections::{HashMap, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let mut solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("32000000"),
+        Example::new(EXAMPLE_2).part1("11687500"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["broadcaster -> {any}", "[%&]{word} -> {any}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -131,21 +151,21 @@ impl ProcessSignal for Broadcaster {
     }
 }
 
-struct InputData {
-    network: HashMap<String, Box<dyn ProcessSignal>>,
-    rx_input: String,
+pub struct InputData {
+    network: RefCell<HashMap<String, Box<dyn ProcessSignal>>>,
+    rx_input: Option<String>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut network: HashMap<String, Box<dyn ProcessSignal>> = HashMap::new();
         let mut conjunctions = HashMap::new();
-        let mut rx_input = "".to_string();
+        let mut rx_input = None;
         for line in s.lines() {
-            let (left, right) = line.split_once(" -> ").unwrap();
-            match left.chars().nth(0).unwrap() {
-                '%' => {
+            let (left, right) = parse::split_once(line, " -> ")?;
+            match left.chars().next() {
+                Some('%') => {
                     // Flip Flop
                     network.insert(
                         left[1..].to_string(),
@@ -156,7 +176,7 @@ impl FromStr for InputData {
                         }),
                     );
                 }
-                '&' => {
+                Some('&') => {
                     // Conjunction
                     network.insert(
                         left[1..].to_string(),
@@ -184,7 +204,7 @@ impl FromStr for InputData {
         for (n, m) in &network {
             for o in &m.get_outputs() {
                 if o == "rx" {
-                    rx_input = n.to_string();
+                    rx_input = Some(n.to_string());
                 }
                 if conjunctions.contains_key(o) {
                     conjunctions
@@ -203,12 +223,20 @@ impl FromStr for InputData {
                 });
             }
         }
-        Ok(Self { network, rx_input })
+        Ok(Self {
+            network: RefCell::new(network),
+            rx_input,
+        })
     }
 }
 
 impl InputData {
-    fn solve_part1(&mut self) -> usize {
+    fn solve_part1(&self) -> usize {
+        // The module states are kept between the parts, so start from a clean state
+        let mut network = self.network.borrow_mut();
+        for module in network.values_mut() {
+            module.reset();
+        }
         let mut low_count = 0;
         let mut high_count = 0;
         for _ in 0..1000 {
@@ -224,7 +252,7 @@ impl InputData {
                     low_count += 1;
                 }
                 // Note - "rx" exists as receiver in the input, but not as sender, i.e. get_mut can return None
-                if let Some(module) = self.network.get_mut(&msg.receiver) {
+                if let Some(module) = network.get_mut(&msg.receiver) {
                     for new_msg in module.handle_pulse(&msg) {
                         msg_queue.push_back(new_msg);
                     }
@@ -234,13 +262,16 @@ impl InputData {
         low_count * high_count
     }
 
-    fn solve_part2(&mut self) -> usize {
+    /// Returns `None` if no module sends pulses to `rx`.
+    fn solve_part2(&self) -> Option<usize> {
+        let rx_input = self.rx_input.as_ref()?;
+        let mut network = self.network.borrow_mut();
         // Reset all modules while also finding the modules connecting to the node connecting to "rx"
         let mut rx_input_inputs: HashMap<String, usize> = HashMap::new();
-        for (name, module) in self.network.iter_mut() {
+        for (name, module) in network.iter_mut() {
             module.reset();
             for o in &module.get_outputs() {
-                if *o == self.rx_input {
+                if o == rx_input {
                     rx_input_inputs.insert(name.to_string(), 0);
                 }
             }
@@ -258,7 +289,7 @@ impl InputData {
             push_count += 1;
             while let Some(msg) = msg_queue.pop_front() {
                 // Note - "rx" exists as receiver in the input, but not as sender, i.e. get_mut can return None
-                if let Some(module) = self.network.get_mut(&msg.receiver) {
+                if let Some(module) = network.get_mut(&msg.receiver) {
                     for new_msg in module.handle_pulse(&msg) {
                         if rx_input_inputs.contains_key(&msg.receiver)
                             && *rx_input_inputs.get(&msg.receiver).unwrap() == 0
@@ -274,33 +305,29 @@ impl InputData {
             }
         }
 
-        rx_input_inputs.values().fold(1, |acc, v| lcm(acc, *v))
+        Some(rx_input_inputs.values().fold(1, |acc, v| lcm(acc, *v)))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "broadcaster -> a, b, c
+const EXAMPLE_1: &str = "broadcaster -> a, b, c
 %a -> b
 %b -> c
 %c -> inv
 &inv -> a";
-        let mut solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 32000000);
-    }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "broadcaster -> a
+const EXAMPLE_2: &str = "broadcaster -> a
 %a -> inv, con
 &inv -> b
 %b -> con
 &con -> output";
-        let mut solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 11687500);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
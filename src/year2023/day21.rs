@@ -1,58 +1,65 @@
 //! # 2023 day 21 - Step Counter
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{
     collections::{HashSet, VecDeque},
     error::Error,
-    str::FromStr,
 };
 
-use crate::aoc_util::{
-    grid::Grid,
-    point::{self, Point},
-};
+use crate::aoc_util::{grid::Grid, point::Point};
+
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("steps", "64")];
+
+    // The example doesn't have the same geometric symmetry as the real input, so the assumptions
+    // made in part 2 don't apply to it, and it's only used for part 1.
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("16").params("steps=6")];
+
+    const SHAPE: Shape = Shape::Grid(".#S");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("steps")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.get_reachable(self.steps, false)
+    }
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
     start: Point,
+    steps: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid = Grid::parse(s);
-        let start = grid.find('S').unwrap();
-        Ok(Self { grid, start })
+impl InputData {
+    fn parse_input(input: &str, steps: usize) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input)?;
+        let start = grid
+            .find(b'S')
+            .ok_or_else(|| ParseError::missing("missing start `S`", input))?;
+        Ok(Self { grid, start, steps })
     }
-}
 
-impl InputData {
     fn get_neighbors(&self, p: &Point, expand: bool) -> Vec<Point> {
-        let mut neighbors = Vec::new();
-        for n in point::NEIGHBORS_STRAIGHT.iter().map(|d| *d + *p) {
-            if expand {
-                let n_relative = Point::new(
-                    n.x.rem_euclid(self.grid.x_max as i32),
-                    n.y.rem_euclid(self.grid.y_max as i32),
-                );
-                if let Some(v) = self.grid.get_element(&n_relative)
-                    && v != '#'
-                {
-                    neighbors.push(n);
-                }
-            } else if let Some(v) = self.grid.get_element(&n)
-                && v != '#'
-            {
-                neighbors.push(n);
-            }
+        let open = |(n, v): (Point, u8)| (v != b'#').then_some(n);
+        if expand {
+            self.grid.tiled().neighbors(p).filter_map(open).collect()
+        } else {
+            self.grid.neighbors(p).filter_map(open).collect()
         }
-        neighbors
     }
 
     fn get_reachable(&self, maxsteps: usize, expand: bool) -> usize {
@@ -91,20 +98,12 @@ impl InputData {
         ((a * x.pow(2)) + (b * x) + c) as usize
     }
 
-    fn solve_part1(&self) -> usize {
-        self.get_reachable(64, false)
-    }
-
     fn solve_part2(&self) -> usize {
         self.get_infinite_reachable(26501365)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "...........
+const EXAMPLE: &str = "...........
 .....###.#.
 .###.##..#.
 ..#.#...#..
@@ -116,23 +115,13 @@ mod tests {
 .##..##.##.
 ...........";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
     #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_reachable(6, false), 16);
+    fn examples() {
+        check::<InputData>();
     }
-
-    // Test input doesn't have the same geometric symmetry as the real input, i.e. the assumptions made
-    // in the solution don't apply to the test data
-    /*#[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.get_infinite_reachable(6), 16);
-        assert_eq!(solution_data.get_infinite_reachable(10), 50);
-        assert_eq!(solution_data.get_infinite_reachable(50), 1594);
-        assert_eq!(solution_data.get_infinite_reachable(100), 6536);
-        assert_eq!(solution_data.get_infinite_reachable(500), 167004);
-        assert_eq!(solution_data.get_infinite_reachable(1000), 668697);
-        assert_eq!(solution_data.get_infinite_reachable(5000), 16733044);
-    }*/
 }
@@ -10,30 +10,49 @@
 //! Make use of the already sorted lists, and iterate over the left side.
 //! Keep track of the index of the last element used on the right side
 //! to minimize the amount of looping on the right side.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::Ordering, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("11").part2("31")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int} {int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     left: Vec<usize>,
     right: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut left: Vec<usize> = Vec::new();
         let mut right: Vec<usize> = Vec::new();
         for line in s.lines() {
             let mut nbrs = line.split_whitespace();
-            left.push(nbrs.next().unwrap().parse().unwrap());
-            right.push(nbrs.next().unwrap().parse().unwrap());
+            left.push(parse::number(parse::next(&mut nbrs, "left number", line)?)?);
+            right.push(parse::number(parse::next(
+                &mut nbrs,
+                "right number",
+                line,
+            )?)?);
         }
         left.sort_unstable();
         right.sort_unstable();
@@ -69,26 +88,20 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "3   4
+const EXAMPLE: &str = "3   4
 4   3
 2   5
 1   3
 3   9
 3   3";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 11);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 31);
+    fn examples() {
+        check::<InputData>();
     }
 }
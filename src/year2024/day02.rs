@@ -1,10 +1,27 @@
 //! # 2024 day 2 - Red-Nosed Reports
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::Ordering, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("2").part2("4")];
+
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq)]
@@ -19,13 +36,13 @@ struct Report {
 }
 
 impl FromStr for Report {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             levels: s
                 .split_whitespace()
-                .map(|level| level.parse().unwrap())
-                .collect(),
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -61,49 +78,49 @@ impl Report {
     }
 }
 
-struct InputData {
+pub struct InputData {
     reports: Vec<Report>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            reports: s
-                .lines()
-                .map(|line| Report::from_str(line).unwrap())
-                .collect(),
+            reports: parse::lines(s, Report::from_str)?,
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let safety: Vec<SafetyLevel> = self.reports.iter().map(|r| r.get_safety_level()).collect();
-        let safe: usize = safety.iter().filter(|s| **s == SafetyLevel::Safe).count();
-        let almost_safe: usize = safety
+    fn solve_part1(&self) -> usize {
+        self.reports
             .iter()
-            .filter(|s| **s == SafetyLevel::DampenerSafe)
-            .count();
-        (safe, safe + almost_safe)
+            .filter(|r| r.get_safety_level() == SafetyLevel::Safe)
+            .count()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part2(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|r| r.get_safety_level() != SafetyLevel::Unsafe)
+            .count()
+    }
+}
 
-    #[test]
-    fn parts1_2_example_1() {
-        let testdata = "7 6 4 2 1
+const EXAMPLE: &str = "7 6 4 2 1
 1 2 7 8 9
 9 7 6 2 1
 1 3 2 4 5
 8 6 4 4 1
 1 3 6 7 9";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 2);
-        assert_eq!(p2, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -10,45 +10,57 @@
 //! This time, scan for nodes containing an 'A' instead, form words from the
 //! combination with the diagonal nodes to form the X, and see if the generated
 //! word is eithes MAS or SAM.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("18").part2("9")];
+
+    const SHAPE: Shape = Shape::Grid("XMAS");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            grid: Grid::parse(s),
+            grid: Grid::parse(s)?,
         })
     }
 }
 impl InputData {
     fn solve_part1(&self) -> usize {
         let mut total = 0;
-        for (i, c) in self.grid.elements.iter().enumerate() {
-            let current = self.grid.get_point(i);
-            if *c == 'X' {
+        for (current, c) in self.grid.cells() {
+            if c == b'X' {
                 for dir in &NEIGHBORS_ALL {
-                    let word: String = [
-                        'X',
-                        self.grid.get_element(&(current + *dir)).unwrap_or('.'),
-                        self.grid.get_element(&(current + *dir * 2)).unwrap_or('.'),
-                        self.grid.get_element(&(current + *dir * 3)).unwrap_or('.'),
-                    ]
-                    .iter()
-                    .collect();
-                    if word == "XMAS" {
+                    let word = [
+                        b'X',
+                        self.grid.get_element(&(current + *dir)).unwrap_or(b'.'),
+                        self.grid.get_element(&(current + *dir * 2)).unwrap_or(b'.'),
+                        self.grid.get_element(&(current + *dir * 3)).unwrap_or(b'.'),
+                    ];
+                    if &word == b"XMAS" {
                         total += 1;
                     }
                 }
@@ -59,27 +71,22 @@ impl InputData {
 
     fn solve_part2(&self) -> usize {
         let mut total = 0;
-        for (i, c) in self.grid.elements.iter().enumerate() {
-            let current = self.grid.get_point(i);
-            if *c == 'A' {
+        for (current, c) in self.grid.cells() {
+            if c == b'A' {
                 let dir = Point::new(1, 1);
-                let word1: String = [
-                    self.grid.get_element(&(current - dir)).unwrap_or('.'),
-                    'A',
-                    self.grid.get_element(&(current + dir)).unwrap_or('.'),
-                ]
-                .iter()
-                .collect();
-                if word1 == "MAS" || word1 == "SAM" {
+                let word1 = [
+                    self.grid.get_element(&(current - dir)).unwrap_or(b'.'),
+                    b'A',
+                    self.grid.get_element(&(current + dir)).unwrap_or(b'.'),
+                ];
+                if &word1 == b"MAS" || &word1 == b"SAM" {
                     let dir2 = Point::new(1, -1);
-                    let word2: String = [
-                        self.grid.get_element(&(current - dir2)).unwrap_or('.'),
-                        'A',
-                        self.grid.get_element(&(current + dir2)).unwrap_or('.'),
-                    ]
-                    .iter()
-                    .collect();
-                    if word2 == "MAS" || word2 == "SAM" {
+                    let word2 = [
+                        self.grid.get_element(&(current - dir2)).unwrap_or(b'.'),
+                        b'A',
+                        self.grid.get_element(&(current + dir2)).unwrap_or(b'.'),
+                    ];
+                    if &word2 == b"MAS" || &word2 == b"SAM" {
                         total += 1;
                     }
                 }
@@ -89,11 +96,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "MMMSXXMASM
+const EXAMPLE: &str = "MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -104,15 +107,13 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 18);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 9);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,15 +1,28 @@
 //! # 2024 day 5 - Print Queue
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{cmp::Ordering, collections::HashMap, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
-}
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("143").part2("123")];
+
+    const SHAPE: Shape =
+        Shape::Sections(&[Shape::Lines(&["{int}|{int}"]), Shape::Lines(&["{ints}"])]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
 
-enum UpdateResult {
-    CorrectOrder(usize),
-    CorrectedOrder(usize),
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct PageOrderingRules {
@@ -17,13 +30,13 @@ struct PageOrderingRules {
 }
 
 impl FromStr for PageOrderingRules {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut rules = HashMap::new();
         for line in s.lines() {
-            let (left, right) = line.split_once('|').unwrap();
-            let left: usize = left.parse().unwrap();
-            let right: usize = right.parse().unwrap();
+            let (left, right) = parse::split_once(line, "|")?;
+            let left: usize = parse::number(left)?;
+            let right: usize = parse::number(right)?;
             rules.insert((left, right), Ordering::Less);
         }
         Ok(Self { rules })
@@ -31,66 +44,62 @@ impl FromStr for PageOrderingRules {
 }
 
 impl PageOrderingRules {
-    fn validate_update(&self, update: &[usize]) -> UpdateResult {
+    fn is_ordered(&self, update: &[usize]) -> bool {
+        update.is_sorted_by(|&left, &right| self.rules.get(&(left, right)) == Some(&Ordering::Less))
+    }
+
+    /// Returns the middle page number of `update` once put in the right order.
+    fn corrected_middle(&self, update: &[usize]) -> usize {
         let idx_mid = update.len() / 2;
-        if update
-            .is_sorted_by(|&left, &right| self.rules.get(&(left, right)) == Some(&Ordering::Less))
-        {
-            UpdateResult::CorrectOrder(update[idx_mid])
-        } else {
-            let mut correction = vec![0; update.len()];
-            correction.copy_from_slice(update);
-            correction.select_nth_unstable_by(idx_mid, |&left, &right| {
-                match self.rules.get(&(left, right)) {
-                    Some(r) => *r,
-                    None => Ordering::Greater,
-                }
-            });
-            UpdateResult::CorrectedOrder(correction[idx_mid])
-        }
+        let mut correction = vec![0; update.len()];
+        correction.copy_from_slice(update);
+        correction.select_nth_unstable_by(idx_mid, |&left, &right| {
+            match self.rules.get(&(left, right)) {
+                Some(r) => *r,
+                None => Ordering::Greater,
+            }
+        });
+        correction[idx_mid]
     }
 }
 
-struct InputData {
+pub struct InputData {
     rules: PageOrderingRules,
     updates: Vec<Vec<usize>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (r, u) = s.split_once("\n\n").unwrap();
+        let (r, u) = parse::split_once(s, "\n\n")?;
         Ok(Self {
-            rules: PageOrderingRules::from_str(r).unwrap(),
-            updates: u
-                .lines()
-                .map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
-                .collect(),
+            rules: PageOrderingRules::from_str(r)?,
+            updates: parse::lines(u, |line| {
+                line.split(',').map(parse::number).collect::<Result<_, _>>()
+            })?,
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut p1 = 0;
-        let mut p2 = 0;
-        for update in &self.updates {
-            match self.rules.validate_update(update) {
-                UpdateResult::CorrectOrder(score) => p1 += score,
-                UpdateResult::CorrectedOrder(score) => p2 += score,
-            }
-        }
-        (p1, p2)
+    fn solve_part1(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|update| self.rules.is_ordered(update))
+            .map(|update| update[update.len() / 2])
+            .sum()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part2(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|update| !self.rules.is_ordered(update))
+            .map(|update| self.rules.corrected_middle(update))
+            .sum()
+    }
+}
 
-    #[test]
-    fn parts1_2_example_1() {
-        let testdata = "47|53
+const EXAMPLE: &str = "47|53
 97|13
 97|61
 97|47
@@ -118,9 +127,14 @@ mod tests {
 75,97,47,61,53
 61,13,29
 97,13,75,29,47";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 143);
-        assert_eq!(p2, 123);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
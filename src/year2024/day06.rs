@@ -1,23 +1,42 @@
 //! # 2024 day 6 - Guard Gallivant
 //!
-//! Solves both parts in one function, since the result from part 1 is
-//! used for part 2 to determine possible roadblock positions.
+//! The patrol route from part 1 is reused in part 2 to determine possible
+//! roadblock positions.
 //!
 //! Part 2 solution is brute force and crawls through the simulation one
 //! step at the time and is thus really slow. Although this method is
 //! necessary for part 1 to find all points, for part 2 it would be enough
 //! to pre-calculate a graph and directly jump from one roadblock to the
 //! next. On todo-list!
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("41").part2("6")];
+
+    const SHAPE: Shape = Shape::Grid(".#^");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     obstacles: HashSet<Point>,
     x_max: i32,
     y_max: i32,
@@ -25,7 +44,7 @@ struct InputData {
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut start: Option<Point> = None;
         let mut obstacles = HashSet::new();
@@ -48,17 +67,23 @@ impl FromStr for InputData {
             obstacles,
             x_max,
             y_max,
-            guard_start_pos: start.unwrap(),
+            guard_start_pos: start.ok_or_else(|| ParseError::missing("missing guard `^`", s))?,
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
+    /// Returns all points visited by the guard before leaving the map, or `None` if the guard
+    /// never leaves it.
+    fn patrol(&self) -> Option<HashSet<Point>> {
         let mut visited: HashSet<Point> = HashSet::new();
+        let mut visited_directed: HashSet<(Point, Point)> = HashSet::new();
         let mut guard = self.guard_start_pos;
         let mut guard_dir = UP;
         while (0..self.x_max).contains(&guard.x) && (0..self.y_max).contains(&guard.y) {
+            if !visited_directed.insert((guard, guard_dir)) {
+                return None;
+            }
             visited.insert(guard);
             let next_point = guard + guard_dir;
             if self.obstacles.contains(&next_point) {
@@ -67,14 +92,21 @@ impl InputData {
                 guard = next_point;
             }
         }
+        Some(visited)
+    }
+
+    fn solve_part1(&self) -> Option<usize> {
+        Some(self.patrol()?.len())
+    }
 
+    fn solve_part2(&self) -> Option<usize> {
         let mut p2 = 0;
-        for extra_obstacle in &visited {
+        for extra_obstacle in &self.patrol()? {
             if *extra_obstacle == self.guard_start_pos {
                 continue;
             }
-            guard = self.guard_start_pos;
-            guard_dir = UP;
+            let mut guard = self.guard_start_pos;
+            let mut guard_dir = UP;
             let mut visited_directed: HashSet<(Point, Point)> = HashSet::new();
             while (0..self.x_max).contains(&guard.x) && (0..self.y_max).contains(&guard.y) {
                 if visited_directed.contains(&(guard, guard_dir)) {
@@ -91,17 +123,11 @@ impl InputData {
             }
         }
 
-        (visited.len(), p2)
+        Some(p2)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "....#.....
+const EXAMPLE: &str = "....#.....
 .........#
 ..........
 ..#.......
@@ -111,9 +137,14 @@ mod tests {
 ........#.
 #.........
 ......#...";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 41);
-        assert_eq!(p2, 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -5,12 +5,29 @@
 //! operation if it's the only way to succeed at the validation. The state of
 //! whether or not concatenation has been used combined with the calculated value
 //! is carried up through the recursion chain through the return value.
-use std::{error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("3749").part2("11387")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}: {ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq)]
@@ -26,15 +43,19 @@ struct Equation {
 }
 
 impl FromStr for Equation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").unwrap();
+        let (left, right) = parse::split_once(s, ": ")?;
+        let numbers: Vec<_> = right
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        if numbers.is_empty() {
+            return Err(ParseError::missing("missing numbers", s));
+        }
         Ok(Self {
-            test_value: left.parse().unwrap(),
-            numbers: right
-                .split_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect(),
+            test_value: parse::number(left)?,
+            numbers,
         })
     }
 }
@@ -90,47 +111,51 @@ fn concatinate(right: usize) -> usize {
     multiplier
 }
 
-struct InputData {
+pub struct InputData {
     equations: Vec<Equation>,
+    results: OnceCell<Vec<CalibrationResult>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            equations: s
-                .lines()
-                .map(|line| Equation::from_str(line).unwrap())
-                .collect(),
+            equations: parse::lines(s, Equation::from_str)?,
+            results: OnceCell::new(),
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut p1 = 0;
-        let mut p2 = 0;
-        for eq in &self.equations {
-            match eq.calibrate() {
-                CalibrationResult::Ok(v) => {
-                    p1 += v;
-                    p2 += v;
-                }
-                CalibrationResult::ConcatinatedOk(v) => p2 += v,
-                CalibrationResult::NotOk => (),
-            }
-        }
-        (p1, p2)
+    /// Returns the calibration result of each equation. The search tells whether concatenation
+    /// was needed, so one search per equation answers both parts.
+    fn results(&self) -> &[CalibrationResult] {
+        self.results
+            .get_or_init(|| self.equations.iter().map(Equation::calibrate).collect())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn solve_part1(&self) -> usize {
+        self.results()
+            .iter()
+            .map(|r| match r {
+                CalibrationResult::Ok(v) => *v,
+                _ => 0,
+            })
+            .sum()
+    }
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "190: 10 19
+    fn solve_part2(&self) -> usize {
+        self.results()
+            .iter()
+            .map(|r| match r {
+                CalibrationResult::Ok(v) | CalibrationResult::ConcatinatedOk(v) => *v,
+                CalibrationResult::NotOk => 0,
+            })
+            .sum()
+    }
+}
+
+const EXAMPLE: &str = "190: 10 19
 3267: 81 40 27
 83: 17 5
 156: 15 6
@@ -139,9 +164,14 @@ mod tests {
 192: 17 8 14
 21037: 9 7 18 13
 292: 11 6 16 20";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 3749);
-        assert_eq!(p2, 11387);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,11 +1,15 @@
 //! # 2024 day 8 - Resonant Collinearity
 //!
-//! Solves both part 1 and part 2 in one go. For all points belonging to
-//! the same frequency (grid character), loop through the pair combinations
-//! and generate their antinode-points until both points are outside the grid.
-//! Add those antinode-points to hashsets and determine the answers to the
-//! respective part from their final lengths. Remember that also the original
+//! For all points belonging to the same frequency (grid character), loop
+//! through the pair combinations and generate their antinode-points until both
+//! points are outside the grid. Add those antinode-points to a hashset and
+//! determine the answer from its final length. Remember that also the original
 //! nodes needs to be included for the part2 calculation.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::point::*;
 use std::{
     collections::{HashMap, HashSet},
@@ -13,10 +17,23 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("14").part2("34")];
+
+    const SHAPE: Shape =
+        Shape::Grid(".0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.count_antinodes(false)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.count_antinodes(true)
+    }
 }
 
 impl Point {
@@ -34,14 +51,14 @@ impl Point {
     }
 }
 
-struct InputData {
+pub struct InputData {
     antennas: HashMap<char, Vec<Point>>,
     map_width: usize,
     map_height: usize,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut antennas = HashMap::new();
         let mut map_height = 0;
@@ -69,51 +86,37 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
+    /// Counts the unique antinode locations within the map, either only those at twice the
+    /// distance between two antennas, or `with_harmonics` at every multiple of it.
+    fn count_antinodes(&self, with_harmonics: bool) -> usize {
+        let harmonics = if with_harmonics { 0.. } else { 1.. };
         let mut antinodes = HashSet::new();
-        let mut antinodes_w_harmonics = HashSet::new();
         for points in self.antennas.values() {
             for (i, p1) in points.iter().enumerate() {
                 for p2 in points.iter().skip(i + 1) {
-                    let mut inside = true;
-                    let mut harmonic = 0;
-                    while inside {
-                        inside = false;
+                    for harmonic in harmonics.clone() {
                         let (an1, an2) = p1.get_anti_points(p2, harmonic);
-                        if (0..self.map_width as i32).contains(&an1.x)
-                            && (0..self.map_height as i32).contains(&an1.y)
-                        {
-                            inside = true;
-                            if harmonic == 1 {
-                                antinodes.insert(an1);
+                        let mut inside = false;
+                        for an in [an1, an2] {
+                            if (0..self.map_width as i32).contains(&an.x)
+                                && (0..self.map_height as i32).contains(&an.y)
+                            {
+                                inside = true;
+                                antinodes.insert(an);
                             }
-                            antinodes_w_harmonics.insert(an1);
                         }
-                        if (0..self.map_width as i32).contains(&an2.x)
-                            && (0..self.map_height as i32).contains(&an2.y)
-                        {
-                            inside = true;
-                            if harmonic == 1 {
-                                antinodes.insert(an2);
-                            }
-                            antinodes_w_harmonics.insert(an2);
+                        if !inside || !with_harmonics {
+                            break;
                         }
-                        harmonic += 1;
                     }
                 }
             }
         }
-        (antinodes.len(), antinodes_w_harmonics.len())
+        antinodes.len()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "............
+const EXAMPLE: &str = "............
 ........0...
 .....0......
 .......0....
@@ -125,9 +128,14 @@ mod tests {
 .........A..
 ............
 ............";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 14);
-        assert_eq!(p2, 34);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -7,14 +7,29 @@
 //! ## Part 2
 //!
 //! Some slightly different index juggling...
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::VecDeque, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("1928").part2("2858")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct MemBlock {
@@ -22,18 +37,25 @@ struct MemBlock {
     length: usize,
 }
 
-struct InputData {
+pub struct InputData {
     disk_map: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::missing("missing disk map", s));
+        }
         Ok(Self {
             disk_map: s
-                .chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .collect(),
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as usize)
+                        .ok_or_else(|| ParseError::new("expected a digit", &s[i..]))
+                })
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -68,7 +90,7 @@ impl InputData {
                 left_idx += 1;
                 if left_idx == right_idx {
                     buffer = right_counter;
-                } else {
+                } else if left_idx < right_idx {
                     buffer = self.disk_map[left_idx];
                 }
             }
@@ -122,21 +144,15 @@ impl InputData {
     }
 }
 
+const EXAMPLE: &str = "2333133121414131402";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "2333133121414131402";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1928);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2858);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -7,86 +7,98 @@
 //! to part 2 is given by the number of times the number 9 is found, and the
 //! answer to part 1 by throwing all the points found for each trailhead into
 //! a hashset and then calculating its length.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{
+    cell::OnceCell,
     collections::{HashSet, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("1"),
+        Example::new(EXAMPLE_2).part1("36").part2("81"),
+    ];
+
+    const SHAPE: Shape = Shape::Grid("0123456789");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve().0
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve().1
+    }
 }
 
-struct InputData {
+pub struct InputData {
     map: Grid,
     trailheads: Vec<Point>,
+    totals: OnceCell<(usize, usize)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = Grid::parse(s);
+        let map = Grid::parse_digits(s)?;
         let trailheads = map
-            .elements
-            .iter()
-            .enumerate()
-            .filter(|(_, v)| **v == '0')
-            .map(|(i, _)| Point::new((i % map.x_max) as i32, (i / map.y_max) as i32))
+            .cells()
+            .filter(|(_, v)| *v == 0)
+            .map(|(p, _)| p)
             .collect();
-        Ok(Self { map, trailheads })
+        Ok(Self {
+            map,
+            trailheads,
+            totals: OnceCell::new(),
+        })
     }
 }
 
 impl InputData {
+    /// Returns the total score and rating of the trailheads, which both come from the same search
+    /// from each trailhead.
     fn solve(&self) -> (usize, usize) {
-        let mut totalscore = 0;
-        let mut totalrating = 0;
-        for head in &self.trailheads {
-            let mut peaks = HashSet::new();
-            let mut queue: VecDeque<Point> = VecDeque::new();
-            queue.push_back(*head);
-            while let Some(current) = queue.pop_front() {
-                if self.map.get_uint_element(&current).unwrap() == 9 {
-                    peaks.insert(current);
-                    totalrating += 1;
-                } else {
-                    for neighbor in NEIGHBORS_STRAIGHT.map(|dir| dir + current) {
-                        if let Some(neighbor_val) = self.map.get_uint_element(&neighbor)
-                            && neighbor_val == self.map.get_uint_element(&current).unwrap() + 1
-                        {
-                            queue.push_back(neighbor);
+        *self.totals.get_or_init(|| {
+            let mut totalscore = 0;
+            let mut totalrating = 0;
+            for head in &self.trailheads {
+                let mut peaks = HashSet::new();
+                let mut queue = VecDeque::from([(*head, 0)]);
+                while let Some((current, current_val)) = queue.pop_front() {
+                    if current_val == 9 {
+                        peaks.insert(current);
+                        totalrating += 1;
+                    } else {
+                        for (neighbor, neighbor_val) in self.map.neighbors(&current) {
+                            if neighbor_val == current_val + 1 {
+                                queue.push_back((neighbor, neighbor_val));
+                            }
                         }
                     }
                 }
+                totalscore += peaks.len();
             }
-
-            totalscore += peaks.len();
-        }
-        (totalscore, totalrating)
+            (totalscore, totalrating)
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parts1_2_example_1() {
-        let testdata = "0123
+const EXAMPLE_1: &str = "0123
 1234
 8765
 9876";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 1);
-    }
 
-    #[test]
-    fn parts1_2_example_2() {
-        let testdata = "89010123
+const EXAMPLE_2: &str = "89010123
 78121874
 87430965
 96549874
@@ -94,9 +106,14 @@ mod tests {
 32019012
 01329801
 10456732";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 36);
-        assert_eq!(p2, 81);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
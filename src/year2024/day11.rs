@@ -1,49 +1,70 @@
 //! # 2024 day 11 - Plutonian Pebbles
 //!
-//! Solves both parts in the same loop, since part 2 simply requires way more
-//! iterations of the same method. Since the number of stones will quickly become
-//! enormous but many will have the same value, the stones are stored in a hash-map
-//! rather than a vector, with the stone's number as key and number of stones with
-//! that number as value. Despite the desceptive description, the order of the stones
-//! is actually not important!
-use std::{collections::HashMap, error::Error, str::FromStr};
+//! Part 2 simply requires way more iterations of the same method as part 1. Since
+//! the number of stones will quickly become enormous but many will have the same
+//! value, the stones are stored in a hash-map rather than a vector, with the stone's
+//! number as key and number of stones with that number as value. Despite the
+//! desceptive description, the order of the stones is actually not important!
+//!
+//! The number of blinks of part 1 is the parameter `blinks`, so the example can also be run with
+//! the 6 blinks given in the puzzle description.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{collections::HashMap, error::Error};
+
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("blinks", "25")];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE).part1("55312"),
+        Example::new(EXAMPLE).part1("22").params("blinks=6"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{ints}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve(25, 75);
-    Ok((p1.to_string(), p2.to_string()))
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("blinks")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve(self.blinks)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve(75)
+    }
 }
 
-struct InputData {
+pub struct InputData {
     stones: Vec<usize>,
+    blinks: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl InputData {
+    fn parse_input(input: &str, blinks: usize) -> Result<Self, ParseError> {
         Ok(Self {
-            stones: s.split_whitespace().map(|n| n.parse().unwrap()).collect(),
+            stones: input
+                .split_whitespace()
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+            blinks,
         })
     }
-}
 
-impl InputData {
-    fn solve(&self, blinks1: usize, blinks2: usize) -> (usize, usize) {
-        let mut p1 = None;
-        let mut p2 = None;
-        let mut blink_counter = 0;
+    fn solve(&self, blinks: usize) -> usize {
         let mut stones: HashMap<usize, usize> = self.stones.iter().map(|n| (*n, 1)).collect();
-        while p1.is_none() || p2.is_none() {
-            blink_counter += 1;
+        for _ in 0..blinks {
             stones = blink(&stones);
-            if p1.is_none() && blink_counter == blinks1 {
-                p1 = Some(stones.values().sum());
-            }
-            if p2.is_none() && blink_counter == blinks2 {
-                p2 = Some(stones.values().sum());
-            }
         }
-        (p1.unwrap(), p2.unwrap())
+        stones.values().sum()
     }
 }
 
@@ -78,24 +99,22 @@ fn blink(stones: &HashMap<usize, usize>) -> HashMap<usize, usize> {
     newstones
 }
 
+const EXAMPLE: &str = "125 17";
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part1_example_1() {
-        let testdata = "0 1 10 99 999";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve(1, 1);
-        assert_eq!(p1, 7);
+    fn examples() {
+        check::<InputData>();
     }
 
     #[test]
-    fn part1_example_2() {
-        let testdata = "125 17";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve(6, 25);
-        assert_eq!(p1, 22);
-        assert_eq!(p2, 55312);
+    fn part1_example_1() {
+        let testdata = "0 1 10 99 999";
+        let solution_data = InputData::parse_input(testdata, 1).unwrap();
+        assert_eq!(solution_data.solve(1), 7);
     }
 }
@@ -18,116 +18,157 @@
 //!   both cases it means we've found a corner.)
 //! * If only one of the orthogonal neighbors have the same value, we know
 //!   it's not a corner.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*};
 use std::{
+    cell::OnceCell,
     collections::{HashSet, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("140").part2("80"),
+        Example::new(EXAMPLE_2).part1("772").part2("436"),
+        Example::new(EXAMPLE_3).part1("1930").part2("1206"),
+        Example::new(EXAMPLE_4).part2("236"),
+        Example::new(EXAMPLE_5).part2("368"),
+    ];
+
+    const SHAPE: Shape = Shape::Grid("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.regions()
+            .iter()
+            .map(|r| r.area * r.perimeter)
+            .sum::<usize>()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.regions()
+            .iter()
+            .map(|r| r.area * r.sides)
+            .sum::<usize>()
+    }
 }
 
-struct InputData {
+struct Region {
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+pub struct InputData {
     garden_map: Grid,
+    regions: OnceCell<Vec<Region>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            garden_map: Grid::parse(s),
+            garden_map: Grid::parse(s)?,
+            regions: OnceCell::new(),
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut total_perimeter = 0;
-        let mut total_sides = 0;
-        let mut counted = HashSet::new();
-        for y in 0..self.garden_map.y_max {
-            for x in 0..self.garden_map.x_max {
-                if !counted.contains(&(x, y)) {
-                    let mut area = 0;
-                    let mut perimeter = 0;
-                    let mut sides = 0;
-                    let mut group = HashSet::new();
-                    let mut queue = VecDeque::from([Point::new(x as i32, y as i32)]);
-                    let mut neighborstates = Vec::with_capacity(6);
-                    while let Some(current) = queue.pop_front() {
-                        if counted.contains(&(current.x as usize, current.y as usize)) {
-                            continue;
-                        }
-                        counted.insert((current.x as usize, current.y as usize));
-                        group.insert(current);
-                        area += 1;
-                        let element = self.garden_map.get_element(&current).unwrap();
-                        for dir in NEIGHBORS_STRAIGHT.iter() {
-                            let neighbor = current + *dir;
-                            if let Some(neighbor_val) = self.garden_map.get_element(&neighbor) {
-                                if neighbor_val == element {
-                                    queue.push_back(neighbor);
-                                    neighborstates.push((*dir, 1));
+    /// Maps the regions of the garden, which both parts price.
+    fn regions(&self) -> &[Region] {
+        self.regions.get_or_init(|| {
+            let mut regions = Vec::new();
+            let mut counted = HashSet::new();
+            for y in 0..self.garden_map.y_max {
+                for x in 0..self.garden_map.x_max {
+                    if !counted.contains(&(x, y)) {
+                        let mut area = 0;
+                        let mut perimeter = 0;
+                        let mut sides = 0;
+                        let mut group = HashSet::new();
+                        let mut queue = VecDeque::from([Point::new(x as i32, y as i32)]);
+                        let mut neighborstates = Vec::with_capacity(6);
+                        while let Some(current) = queue.pop_front() {
+                            if counted.contains(&(current.x as usize, current.y as usize)) {
+                                continue;
+                            }
+                            counted.insert((current.x as usize, current.y as usize));
+                            group.insert(current);
+                            area += 1;
+                            let element = self.garden_map.get_element(&current).unwrap();
+                            for dir in NEIGHBORS_STRAIGHT.iter() {
+                                let neighbor = current + *dir;
+                                if let Some(neighbor_val) = self.garden_map.get_element(&neighbor) {
+                                    if neighbor_val == element {
+                                        queue.push_back(neighbor);
+                                        neighborstates.push((*dir, 1));
+                                    } else {
+                                        perimeter += 1;
+                                        neighborstates.push((*dir, 0));
+                                    }
                                 } else {
                                     perimeter += 1;
                                     neighborstates.push((*dir, 0));
                                 }
-                            } else {
-                                perimeter += 1;
-                                neighborstates.push((*dir, 0));
                             }
-                        }
-                        // Check the combinations of straight neighbors to evaluate corners
-                        for (i, (n1, v1)) in neighborstates.iter().enumerate() {
-                            for (n2, v2) in neighborstates.iter().skip(i) {
-                                if (n1.x == 0 && n2.x == 0) || (n1.y == 0 && n2.y == 0) {
-                                    continue;
-                                }
-                                if (*v1 == 0 && *v2 == 0)
-                                    || (*v1 == 1
-                                        && *v2 == 1
-                                        && self
-                                            .garden_map
-                                            .get_element(&Point::new(
-                                                current.x + n1.x + n2.x,
-                                                current.y + n1.y + n2.y,
-                                            ))
-                                            .unwrap()
-                                            != element)
-                                {
-                                    sides += 1;
+                            // Check the combinations of straight neighbors to evaluate corners
+                            for (i, (n1, v1)) in neighborstates.iter().enumerate() {
+                                for (n2, v2) in neighborstates.iter().skip(i) {
+                                    if (n1.x == 0 && n2.x == 0) || (n1.y == 0 && n2.y == 0) {
+                                        continue;
+                                    }
+                                    if (*v1 == 0 && *v2 == 0)
+                                        || (*v1 == 1
+                                            && *v2 == 1
+                                            && self
+                                                .garden_map
+                                                .get_element(&Point::new(
+                                                    current.x + n1.x + n2.x,
+                                                    current.y + n1.y + n2.y,
+                                                ))
+                                                .unwrap()
+                                                != element)
+                                    {
+                                        sides += 1;
+                                    }
                                 }
                             }
+                            neighborstates.clear();
                         }
-                        neighborstates.clear();
+                        regions.push(Region {
+                            area,
+                            perimeter,
+                            sides,
+                        });
                     }
-                    total_perimeter += area * perimeter;
-                    total_sides += area * sides;
                 }
             }
-        }
-        (total_perimeter, total_sides)
+            regions
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "AAAA
+const EXAMPLE_1: &str = "AAAA
 BBCD
 BBCC
 EEEC";
-    const TEST_DATA_2: &str = "OOOOO
+
+const EXAMPLE_2: &str = "OOOOO
 OXOXO
 OOOOO
 OXOXO
 OOOOO";
-    const TEST_DATA_3: &str = "RRRRIICCFF
+
+const EXAMPLE_3: &str = "RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
 VVRCCCJFFF
@@ -137,57 +178,27 @@ VVIIICJJEE
 MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE";
-    const TEST_DATA_4: &str = "EEEEE
+
+const EXAMPLE_4: &str = "EEEEE
 EXXXX
 EEEEE
 EXXXX
 EEEEE";
-    const TEST_DATA_5: &str = "AAAAAA
+
+const EXAMPLE_5: &str = "AAAAAA
 AAABBA
 AAABBA
 ABBAAA
 ABBAAA
 AAAAAA";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 140);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 772);
-    }
-
-    #[test]
-    fn part1_example_3() {
-        let solution_data = InputData::from_str(TEST_DATA_3).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 1930);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 80);
-    }
-
-    #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_4).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 236);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_3() {
-        let solution_data = InputData::from_str(TEST_DATA_5).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 368);
+    fn examples() {
+        check::<InputData>();
     }
 }
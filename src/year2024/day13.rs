@@ -2,14 +2,32 @@
 //!
 //! Calculates the answer basically by solving a matrix. The same function can be
 //! used for both parts, with an argument to input the extra scaling value for part 2.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("480")];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&[
+        "Button {word}: X+{int}, Y+{int}",
+        "Prize: X={int}, Y={int}",
+    ]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug)]
@@ -20,78 +38,70 @@ struct ClawMachine {
 }
 
 impl FromStr for ClawMachine {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
-        let a: Vec<&str> = lines.next().unwrap().split_whitespace().collect();
-        let b: Vec<&str> = lines.next().unwrap().split_whitespace().collect();
-        let p: Vec<&str> = lines.next().unwrap().split_whitespace().collect();
-        Ok(Self {
-            button_a: (
-                a[2].strip_suffix(',')
-                    .unwrap()
-                    .split_once('+')
-                    .unwrap()
-                    .1
-                    .parse()
-                    .unwrap(),
-                a[3].split_once('+').unwrap().1.parse().unwrap(),
-            ),
-            button_b: (
-                b[2].strip_suffix(',')
-                    .unwrap()
-                    .split_once('+')
-                    .unwrap()
-                    .1
-                    .parse()
-                    .unwrap(),
-                b[3].split_once('+').unwrap().1.parse().unwrap(),
-            ),
-            prize: (
-                p[1].strip_suffix(',')
-                    .unwrap()
-                    .split_once('=')
-                    .unwrap()
-                    .1
-                    .parse()
-                    .unwrap(),
-                p[2].split_once('=').unwrap().1.parse().unwrap(),
-            ),
-        })
+        let mut line = |prefix: &str, delimiter: &str| -> Result<(isize, isize), ParseError> {
+            let line = parse::next(&mut lines, prefix.trim_end(), s)?;
+            let (x, y) = parse::split_once(parse::strip_prefix(line, prefix)?, delimiter)?;
+            Ok((parse::number(x)?, parse::number(y)?))
+        };
+        let machine = Self {
+            button_a: line("Button A: X+", ", Y+")?,
+            button_b: line("Button B: X+", ", Y+")?,
+            prize: line("Prize: X=", ", Y=")?,
+        };
+        if machine.determinant() == 0 {
+            return Err(ParseError::new(
+                "the buttons move the claw in the same direction",
+                s,
+            ));
+        }
+        Ok(machine)
     }
 }
 
 impl ClawMachine {
+    /// Calculates the determinant of the button matrix, in 128 bits so that no product
+    /// overflows.
+    fn determinant(&self) -> i128 {
+        let (a_x, a_y) = (self.button_a.0 as i128, self.button_a.1 as i128);
+        let (b_x, b_y) = (self.button_b.0 as i128, self.button_b.1 as i128);
+        a_x * b_y - a_y * b_x
+    }
+
+    /// Returns `None` if the prize can't be won with a whole number of presses of each button.
     fn get_win_tokens(&self, extra: usize) -> Option<usize> {
-        let p_x = self.prize.0 + extra as isize;
-        let p_y = self.prize.1 + extra as isize;
-        let i = (self.button_b.0 * p_y - self.button_b.1 * p_x)
-            / (self.button_a.1 * self.button_b.0 - self.button_a.0 * self.button_b.1);
-        let j = (p_x - i * self.button_a.0) / self.button_b.0;
-        if (p_x - i * self.button_a.0) % self.button_b.0 == 0
-            && (self.button_b.0 * p_y - self.button_b.1 * p_x)
-                % (self.button_a.1 * self.button_b.0 - self.button_a.0 * self.button_b.1)
-                == 0
-        {
-            Some((3 * i + j) as usize)
-        } else {
-            None
+        let (a_x, a_y) = (self.button_a.0 as i128, self.button_a.1 as i128);
+        let (b_x, b_y) = (self.button_b.0 as i128, self.button_b.1 as i128);
+        let p_x = self.prize.0 as i128 + extra as i128;
+        let p_y = self.prize.1 as i128 + extra as i128;
+        let det = self.determinant();
+        let i_numerator = b_y * p_x - b_x * p_y;
+        let j_numerator = a_x * p_y - a_y * p_x;
+        if i_numerator % det != 0 || j_numerator % det != 0 {
+            return None;
         }
+        let (i, j) = (i_numerator / det, j_numerator / det);
+        if i < 0 || j < 0 {
+            return None;
+        }
+        usize::try_from(3 * i + j).ok()
     }
 }
 
-struct InputData {
+pub struct InputData {
     machines: Vec<ClawMachine>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             machines: s
                 .split("\n\n")
-                .map(|m| ClawMachine::from_str(m).unwrap())
-                .collect(),
+                .map(ClawMachine::from_str)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -112,13 +122,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "Button A: X+94, Y+34
+const EXAMPLE: &str = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400
 
@@ -133,7 +137,14 @@ Prize: X=7870, Y=6450
 Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 480);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -9,15 +9,44 @@
 //! ## Part 2
 //!
 //! Run the simulation until all robots are in unique positions with no overlap.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::{collections::HashSet, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::parse_input(input, 101, 103);
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("width", "101"), Param::new("height", "103")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("12")
+        .params("width=11,height=7")];
+
+    const SHAPE: Shape = Shape::Lines(&["p={int},{int} v={int},{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(
+            input,
+            params.get_in("width", 1..=i32::MAX as usize)?,
+            params.get_in("height", 1..=i32::MAX as usize)?,
+        )?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 struct Robot {
@@ -26,40 +55,31 @@ struct Robot {
 }
 
 impl FromStr for Robot {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(' ').unwrap();
-        let (left_x, left_y) = left.split_once(',').unwrap();
-        let (right_x, right_y) = right.split_once(',').unwrap();
+        let (left, right) = parse::split_once(s, " ")?;
+        let (left_x, left_y) = parse::split_once(parse::strip_prefix(left, "p=")?, ",")?;
+        let (right_x, right_y) = parse::split_once(parse::strip_prefix(right, "v=")?, ",")?;
         Ok(Self {
-            pos: Point::new(
-                left_x.strip_prefix("p=").unwrap().parse().unwrap(),
-                left_y.parse().unwrap(),
-            ),
-            vel: Point::new(
-                right_x.strip_prefix("v=").unwrap().parse().unwrap(),
-                right_y.parse().unwrap(),
-            ),
+            pos: Point::new(parse::number(left_x)?, parse::number(left_y)?),
+            vel: Point::new(parse::number(right_x)?, parse::number(right_y)?),
         })
     }
 }
 
-struct InputData {
+pub struct InputData {
     robots: Vec<Robot>,
     x_max: usize,
     y_max: usize,
 }
 
 impl InputData {
-    fn parse_input(input: &str, x_max: usize, y_max: usize) -> Self {
-        Self {
-            robots: input
-                .lines()
-                .map(|line| Robot::from_str(line).unwrap())
-                .collect(),
+    fn parse_input(input: &str, x_max: usize, y_max: usize) -> Result<Self, ParseError> {
+        Ok(Self {
+            robots: parse::lines(input, Robot::from_str)?,
             x_max,
             y_max,
-        }
+        })
     }
 
     fn solve_part1(&self) -> usize {
@@ -76,11 +96,17 @@ impl InputData {
         quadrants.iter().product()
     }
 
-    fn solve_part2(&self) -> usize {
+    /// Returns `None` if the robots never are in unique positions, which is certain once the
+    /// positions start to repeat after `width * height` seconds.
+    fn solve_part2(&self) -> Option<usize> {
         let mut points: HashSet<Point> = HashSet::with_capacity(self.robots.len());
+        let period = i32::try_from(self.x_max.checked_mul(self.y_max)?).ok()?;
         let mut time = 0;
         let mut overlap = true;
         while overlap {
+            if time == period {
+                return None;
+            }
             time += 1;
             overlap = false;
             for r in self.robots.iter() {
@@ -97,17 +123,11 @@ impl InputData {
             }
             points.clear();
         }
-        time as usize
+        Some(time as usize)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "p=0,4 v=3,-3
+const EXAMPLE: &str = "p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
 p=2,0 v=2,-1
@@ -119,7 +139,14 @@ p=9,3 v=2,3
 p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3";
-        let solution_data = InputData::parse_input(testdata, 11, 7);
-        assert_eq!(solution_data.solve_part1(), 12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -14,6 +14,11 @@
 //! is used to swap those nodes in the direction of the move, by going through the elements
 //! and finding the ones with empty space above and swapping. The number of nodes involved
 //! is mostly quite low, so we can get away with such a simplistic approach.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::{grid::*, point::*};
 use std::{
     collections::{HashSet, VecDeque},
@@ -21,12 +26,26 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("2028"),
+        Example::new(EXAMPLE_2).part1("10092").part2("9021"),
+        Example::new(EXAMPLE_3).part2("618"),
+    ];
+
+    const SHAPE: Shape = Shape::Sections(&[Shape::Grid("#.O@"), Shape::Lines(&["[<>^v]"])]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 impl Grid {
@@ -36,13 +55,13 @@ impl Grid {
         let y_max = 2 * self.y_max;
         let mut elements = Vec::with_capacity(2 * x_max * y_max);
         self.elements.iter().for_each(|c| match c {
-            '@' => {
+            b'@' => {
                 elements.push(*c);
-                elements.push('.');
+                elements.push(b'.');
             }
-            'O' => {
-                elements.push('[');
-                elements.push(']');
+            b'O' => {
+                elements.push(b'[');
+                elements.push(b']');
             }
             _ => {
                 elements.push(*c);
@@ -59,11 +78,11 @@ impl Grid {
     fn move_simple(&mut self, pos: &Point, dir: &Point) -> bool {
         let nextpos = *pos + *dir;
         match self.get_element(&nextpos) {
-            Some('#') | None => false,
-            Some('O') | Some('[') | Some(']') => {
+            Some(b'#') | None => false,
+            Some(b'O') | Some(b'[') | Some(b']') => {
                 if self.move_simple(&nextpos, dir) {
-                    let i1 = self.get_index(pos);
-                    let i2 = self.get_index(&nextpos);
+                    let i1 = self.get_index(pos).unwrap();
+                    let i2 = self.get_index(&nextpos).unwrap();
                     self.elements.swap(i1, i2);
                     true
                 } else {
@@ -71,8 +90,8 @@ impl Grid {
                 }
             }
             _ => {
-                let i1 = self.get_index(pos);
-                let i2 = self.get_index(&nextpos);
+                let i1 = self.get_index(pos).unwrap();
+                let i2 = self.get_index(&nextpos).unwrap();
                 self.elements.swap(i1, i2);
                 true
             }
@@ -86,10 +105,10 @@ impl Grid {
             // until one of those nodes are in the first position.
             let from_point = *queue.front().unwrap();
             let to_point = *from_point + *dir;
-            if self.get_element(&to_point).unwrap() == '.' {
+            if self.get_element(&to_point).unwrap() == b'.' {
                 queue.pop_front();
-                let from = self.get_index(from_point);
-                let to = self.get_index(&to_point);
+                let from = self.get_index(from_point).unwrap();
+                let to = self.get_index(&to_point).unwrap();
                 self.elements.swap(from, to);
             } else {
                 queue.rotate_left(1);
@@ -101,13 +120,13 @@ impl Grid {
         checked.insert(*pos);
         let nextpos = *pos + *dir;
         match self.get_element(&nextpos) {
-            Some('#') | None => false,
-            Some('[') => {
+            Some(b'#') | None => false,
+            Some(b'[') => {
                 let rightpos = nextpos + RIGHT;
                 self.check_double(&nextpos, dir, checked)
                     && (checked.contains(&rightpos) || self.check_double(&rightpos, dir, checked))
             }
-            Some(']') => {
+            Some(b']') => {
                 let leftpos = nextpos + LEFT;
                 self.check_double(&nextpos, dir, checked)
                     && (checked.contains(&leftpos) || self.check_double(&leftpos, dir, checked))
@@ -130,18 +149,38 @@ impl Point {
     }
 }
 
-struct InputData {
+pub struct InputData {
     grid: Grid,
     robot: Point,
     moves: Vec<Point>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (boxstr, movestr) = s.split_once("\n\n").unwrap();
-        let grid = Grid::parse(boxstr);
-        let robot = grid.find('@').unwrap();
+        let (boxstr, movestr) = parse::split_once(s, "\n\n")?;
+        let grid = Grid::parse(boxstr)?;
+        if let Some(i) = boxstr.find(|c| !"#.O@\n".contains(c)) {
+            return Err(ParseError::new(
+                "expected a wall, a box, the robot or floor",
+                &boxstr[i..],
+            ));
+        }
+        let on_edge = |p: &Point| {
+            p.x == 0 || p.y == 0 || p.x == grid.x_max as i32 - 1 || p.y == grid.y_max as i32 - 1
+        };
+        if grid.cells().any(|(p, e)| on_edge(&p) && e != b'#') {
+            return Err(ParseError::new(
+                "expected the warehouse to be surrounded by walls",
+                boxstr,
+            ));
+        }
+        if boxstr.matches('@').count() > 1 {
+            return Err(ParseError::new("expected a single robot", boxstr));
+        }
+        let robot = grid
+            .find(b'@')
+            .ok_or_else(|| ParseError::missing("missing robot `@`", boxstr))?;
         Ok(Self {
             grid,
             robot,
@@ -163,7 +202,7 @@ impl InputData {
             .elements
             .iter()
             .enumerate()
-            .filter(|(_, e)| **e == 'O')
+            .filter(|(_, e)| **e == b'O')
             .map(|(i, _)| 100 * (i / bumped_grid.x_max) + i % bumped_grid.x_max)
             .sum()
     }
@@ -190,17 +229,13 @@ impl InputData {
             .elements
             .iter()
             .enumerate()
-            .filter(|(_, e)| **e == '[')
+            .filter(|(_, e)| **e == b'[')
             .map(|(i, _)| 100 * (i / bumped_grid.x_max) + i % bumped_grid.x_max)
             .sum()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "########
+const EXAMPLE_1: &str = "########
 #..O.O.#
 ##@.O..#
 #...O..#
@@ -210,7 +245,8 @@ mod tests {
 ########
 
 <^^>>>vv<v>>v<<";
-    const TEST_DATA_2: &str = "##########
+
+const EXAMPLE_2: &str = "##########
 #..O..O.O#
 #......O.#
 #.OO..O.O#
@@ -231,7 +267,8 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-    const TEST_DATA_3: &str = "#######
+
+const EXAMPLE_3: &str = "#######
 #...#.#
 #.....#
 #..OO@#
@@ -241,27 +278,13 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 
 <vv<<^^<<^^";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2028);
-    }
-
-    #[test]
-    fn part1_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part1(), 10092);
-    }
-
-    #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA_3).unwrap();
-        assert_eq!(solution_data.solve_part2(), 105 + 207 + 306);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_2() {
-        let solution_data = InputData::from_str(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 9021);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,149 +1,92 @@
 //! # 2024 day 16 - Reindeer Maze
-use crate::aoc_util::{grid::*, point::*};
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet, VecDeque};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use crate::aoc_util::{grid::*, point::*, search};
+use std::cell::OnceCell;
+use std::collections::HashSet;
 use std::error::Error;
 use std::str::FromStr;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
-}
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("7036").part2("45"),
+        Example::new(EXAMPLE_2).part1("11048").part2("64"),
+    ];
 
-impl Point {
-    fn dir_to_idx(&self) -> usize {
-        match *self {
-            RIGHT => 0,
-            DOWN => 1,
-            LEFT => 2,
-            UP => 3,
-            _ => unreachable!(),
-        }
-    }
-}
+    const SHAPE: Shape = Shape::Grid(".#SE");
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct State {
-    cost: usize,
-    position: Point,
-    direction: Point,
-}
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
 
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost).then_with(|| Ordering::Less)
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve()
+            .map_or(Answer::NotApplicable, |(score, _)| Answer::from(score))
     }
-}
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve()
+            .map_or(Answer::NotApplicable, |(_, seats)| Answer::from(seats))
     }
 }
 
-struct InputData {
+pub struct InputData {
     maze: Grid,
     start: Point,
     exit: Point,
+    results: OnceCell<Option<(usize, usize)>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let maze = Grid::parse(s);
-        let start = maze.find('S').unwrap();
-        let exit = maze.find('E').unwrap();
-        Ok(Self { maze, start, exit })
+        let maze = Grid::parse(s)?;
+        let start = maze
+            .find(b'S')
+            .ok_or_else(|| ParseError::missing("missing start `S`", s))?;
+        let exit = maze
+            .find(b'E')
+            .ok_or_else(|| ParseError::missing("missing exit `E`", s))?;
+        Ok(Self {
+            maze,
+            start,
+            exit,
+            results: OnceCell::new(),
+        })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut dist: Vec<_> = (0..self.maze.elements.len())
-            .map(|_| [usize::MAX; 4])
-            .collect();
-        let mut queue = BinaryHeap::new();
-
-        let mut fastest = usize::MAX;
-
-        dist[self.maze.get_index(&self.start)][RIGHT.dir_to_idx()] = 0;
-        queue.push(State {
-            cost: 0,
-            position: self.start,
-            direction: RIGHT,
-        });
-
-        while let Some(State {
-            cost,
-            position,
-            direction,
-        }) = queue.pop()
-        {
-            if position == self.exit {
-                fastest = fastest.min(cost);
-                continue;
-            }
-            let pos_idx = self.maze.get_index(&position);
-            if cost > dist[pos_idx][direction.dir_to_idx()] {
-                continue;
-            }
-
-            for (newcost, newpos, newdir) in [
-                (cost + 1, position + direction, direction),
-                (cost + 1000, position, direction.rotate_left()),
-                (cost + 1000, position, direction.rotate_right()),
-            ] {
-                if let Some(e) = self.maze.get_element(&newpos)
-                    && e != '#'
-                    && newcost < dist[self.maze.get_index(&newpos)][newdir.dir_to_idx()]
-                {
-                    queue.push(State {
-                        cost: newcost,
-                        position: newpos,
-                        direction: newdir,
-                    });
-                    dist[self.maze.get_index(&newpos)][newdir.dir_to_idx()] = newcost;
-                }
-            }
-        }
-
-        let mut best_seats: HashSet<Point> = HashSet::new();
-        let mut queue = VecDeque::new();
-        for d in NEIGHBORS_STRAIGHT.iter() {
-            if dist[self.maze.get_index(&self.exit)][d.dir_to_idx()] == fastest {
-                queue.push_back((self.exit, *d, fastest));
-            }
-        }
-        while let Some((p, d, v)) = queue.pop_front() {
-            best_seats.insert(p);
-            if p == self.start {
-                continue;
-            }
-            let next = [
-                (p - d, d, v - 1),
-                (p, d.rotate_left(), v - 1000),
-                (p, d.rotate_right(), v - 1000),
-            ];
-            for (np, nd, nv) in next {
-                if dist[self.maze.get_index(&np)][nd.dir_to_idx()] == nv {
-                    queue.push_back((np, nd, nv));
-                    dist[self.maze.get_index(&np)][nd.dir_to_idx()] = usize::MAX;
-                }
-            }
-        }
-
-        (fastest, best_seats.len())
+    /// Returns the lowest score and the number of tiles on a best path, which are both read from
+    /// the same search of the maze, or `None` if there's no path from the start to the exit.
+    fn solve(&self) -> Option<(usize, usize)> {
+        *self.results.get_or_init(|| {
+            // A state is a position and the direction the reindeer is facing
+            let successors = |&(position, direction): &(Point, Point)| {
+                [
+                    ((position + direction, direction), 1),
+                    ((position, direction.rotate_left()), 1000),
+                    ((position, direction.rotate_right()), 1000),
+                ]
+                .into_iter()
+                .filter(|((p, _), _)| self.maze.get_element(p).is_some_and(|e| e != b'#'))
+            };
+            let search =
+                search::dijkstra([(self.start, RIGHT)], successors, |(p, _)| *p == self.exit);
+            let best_seats: HashSet<_> = search
+                .on_shortest_paths(&search.goals)
+                .into_iter()
+                .map(|(p, _)| p)
+                .collect();
+            Some((search.cost()?, best_seats.len()))
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_2_example_1() {
-        let testdata = "###############
+const EXAMPLE_1: &str = "###############
 #.......#....E#
 #.#.###.#.###.#
 #.....#.#...#.#
@@ -158,15 +101,8 @@ mod tests {
 #.###.#.#.#.#.#
 #S..#.....#...#
 ###############";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 7036);
-        assert_eq!(p2, 45);
-    }
 
-    #[test]
-    fn part1_2_example_2() {
-        let testdata = "#################
+const EXAMPLE_2: &str = "#################
 #...#...#...#..E#
 #.#.#.#.#.#.#.#.#
 #.#.#.#...#...#.#
@@ -183,9 +119,14 @@ mod tests {
 #.#.#.#########.#
 #S#.............#
 #################";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 11048);
-        assert_eq!(p2, 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
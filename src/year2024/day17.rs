@@ -1,14 +1,40 @@
 //! # 2024 day 17 - Chronospatial Computer
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("4,6,3,5,6,3,5,2,1,0"),
+        Example::new(EXAMPLE_2).part2("117440"),
+    ];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["Register {word}: {int}"]),
+        Shape::Lines(&["Program: {ints}"]),
+    ]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
+/// The number of instructions after which a program is considered to run forever.
+const MAX_STEPS: usize = 1_000_000;
+
 enum Opcodes {
     Adv,
     Bxl,
@@ -72,30 +98,45 @@ impl Computer {
         }
     }
 
-    fn run(&mut self) -> Vec<u8> {
+    /// Divides register A by 2 to the power of `v`.
+    fn divide_a(&self, v: usize) -> usize {
+        u32::try_from(v)
+            .ok()
+            .and_then(|v| self.a.checked_shr(v))
+            .unwrap_or(0)
+    }
+
+    /// Returns `None` if the program uses the reserved combo operand 7, or doesn't halt within
+    /// `MAX_STEPS` instructions.
+    fn run(&mut self) -> Option<Vec<u8>> {
         let mut out = Vec::new();
-        while (0..self.program.len()).contains(&self.i_p) {
-            if let Some(op) = Opcodes::from_nbr(self.program[self.i_p]) {
+        for _ in 0..MAX_STEPS {
+            // Reading an opcode or its operand past the end of the program halts it
+            let (Some(&opcode), Some(&operand)) =
+                (self.program.get(self.i_p), self.program.get(self.i_p + 1))
+            else {
+                return Some(out);
+            };
+            if let Some(op) = Opcodes::from_nbr(opcode) {
                 match op {
                     Opcodes::Adv => {
-                        let v = self.get_combo_op(self.program[self.i_p + 1]).unwrap();
-                        self.a /= 2_usize.pow(v as u32);
+                        let v = self.get_combo_op(operand)?;
+                        self.a = self.divide_a(v);
                         self.i_p += 2;
                     }
                     Opcodes::Bxl => {
-                        let v = self.program[self.i_p + 1] as usize;
-                        self.b ^= v;
+                        self.b ^= operand as usize;
                         self.i_p += 2;
                     }
                     Opcodes::Bst => {
-                        self.b = self.get_combo_op(self.program[self.i_p + 1]).unwrap() % 8;
+                        self.b = self.get_combo_op(operand)? % 8;
                         self.i_p += 2;
                     }
                     Opcodes::Jnz => {
                         if self.a == 0 {
                             self.i_p += 2;
                         } else {
-                            self.i_p = self.program[self.i_p + 1] as usize;
+                            self.i_p = operand as usize;
                         }
                     }
                     Opcodes::Bxc => {
@@ -103,28 +144,28 @@ impl Computer {
                         self.i_p += 2;
                     }
                     Opcodes::Out => {
-                        let v = self.get_combo_op(self.program[self.i_p + 1]).unwrap();
+                        let v = self.get_combo_op(operand)?;
                         out.push((v % 8) as u8);
                         self.i_p += 2;
                     }
                     Opcodes::Bdv => {
-                        let v = self.get_combo_op(self.program[self.i_p + 1]).unwrap();
-                        self.b = self.a / 2_usize.pow(v as u32);
+                        let v = self.get_combo_op(operand)?;
+                        self.b = self.divide_a(v);
                         self.i_p += 2;
                     }
                     Opcodes::Cdv => {
-                        let v = self.get_combo_op(self.program[self.i_p + 1]).unwrap();
-                        self.c = self.a / 2_usize.pow(v as u32);
+                        let v = self.get_combo_op(operand)?;
+                        self.c = self.divide_a(v);
                         self.i_p += 2;
                     }
                 }
             }
         }
-        out
+        None
     }
 }
 
-struct InputData {
+pub struct InputData {
     reg_a: usize,
     reg_b: usize,
     reg_c: usize,
@@ -132,55 +173,48 @@ struct InputData {
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (regs, prog) = s.split_once("\n\n").unwrap();
-        let mut regs = regs.lines();
-        let reg_a = regs
-            .next()
-            .unwrap()
-            .strip_prefix("Register A: ")
-            .unwrap()
-            .parse()
-            .unwrap();
-        let reg_b = regs
-            .next()
-            .unwrap()
-            .strip_prefix("Register B: ")
-            .unwrap()
-            .parse()
-            .unwrap();
-        let reg_c = regs
-            .next()
-            .unwrap()
-            .strip_prefix("Register C: ")
-            .unwrap()
-            .parse()
-            .unwrap();
+        let (regs, prog) = parse::split_once(s, "\n\n")?;
+        let mut lines = regs.lines();
+        let mut register = |name: &str| {
+            let line = parse::next(&mut lines, &format!("register {name}"), regs)?;
+            parse::number(parse::strip_prefix(line, &format!("Register {name}: "))?)
+        };
+        let reg_a = register("A")?;
+        let reg_b = register("B")?;
+        let reg_c = register("C")?;
         Ok(Self {
             reg_a,
             reg_b,
             reg_c,
-            program: prog
-                .strip_prefix("Program: ")
-                .unwrap()
+            program: parse::strip_prefix(prog.trim_end(), "Program: ")?
                 .split(',')
-                .map(|c| c.parse().unwrap())
-                .collect(),
+                .map(|n| match parse::number(n)? {
+                    n @ 0..=7 => Ok(n),
+                    _ => Err(ParseError::new("expected a 3-bit number", n)),
+                })
+                .collect::<Result<_, _>>()?,
         })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> String {
+    /// Returns `None` if the program fails to run.
+    fn solve_part1(&self) -> Option<String> {
         let mut computer = Computer::new(self.reg_a, self.reg_b, self.reg_c, &self.program);
-        let out = computer.run();
-        let result: String = out.iter().map(|n| n.to_string() + ",").collect();
-        result.strip_suffix(',').unwrap().to_string()
+        let out = computer.run()?;
+        Some(
+            out.iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
     }
 
-    fn solve_part2(&self) -> usize {
-        let mut solutions = vec![0];
+    /// Returns `None` if no value of register A makes the program output itself.
+    fn solve_part2(&self) -> Option<usize> {
+        let mut solutions: Vec<usize> = vec![0];
         let mut computer = Computer::new(self.reg_a, self.reg_b, self.reg_c, &self.program);
         // Add digit by digit in the program in reverse order
         for p_digit in self.program.iter().rev() {
@@ -189,9 +223,9 @@ impl InputData {
                 // For every possible solution from the previous iteration, shift it 3 bits
                 // to the left and try values 0-7 in the 3 LSB
                 for i in 0..8 {
-                    let test_a = (nbr << 3) | i;
+                    let test_a = nbr.checked_mul(8)? | i;
                     computer.reboot(test_a);
-                    if computer.run()[0] == *p_digit {
+                    if computer.run().and_then(|out| out.first().copied()) == Some(*p_digit) {
                         subprogram.push(test_a);
                     }
                 }
@@ -199,36 +233,29 @@ impl InputData {
             solutions = subprogram;
         }
         // Choose the smallest value found
-        *solutions.iter().min().unwrap()
+        solutions.iter().min().copied()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "Register A: 729
+const EXAMPLE_1: &str = "Register A: 729
 Register B: 0
 Register C: 0
 
 Program: 0,1,5,4,3,0";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(
-            solution_data.solve_part1(),
-            "4,6,3,5,6,3,5,2,1,0".to_string()
-        );
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "Register A: 2024
+const EXAMPLE_2: &str = "Register A: 2024
 Register B: 0
 Register C: 0
 
 Program: 0,3,5,4,3,0";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 117440);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -10,86 +10,103 @@
 //! Re-using the BFS function from part 1, and using bisection to identify the
 //! last timestamp with a possible path. As starting values, for lower limit we
 //! know from part 1 that at 1024 there is still at least one open path, and for
-//! upper limit the length of the input is the largest it can possibly be.
+//! upper limit the length of the input is the largest it can possibly be. If
+//! the exit can still be reached after all bytes have fallen, there is no
+//! answer, and likewise for part 1 if it's already cut off.
 //!
 //! As a possible improvement, one could generate the grid already at parsing,
 //! marking each point with its index in the input, to avoid having to generate
 //! the grid for every attempted time stamp. However the bisection finds the
 //! answer in only a handful of tries, so the time saving would be in fractions
 //! of a ms.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use crate::aoc_util::search::grid_bfs;
 use crate::aoc_util::{grid::*, point::*};
-use std::collections::{HashSet, VecDeque};
 use std::error::Error;
 use std::str::FromStr;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::parse_input(input, 71);
-    Ok((
-        solution_data.solve_part1(1024).to_string(),
-        solution_data.solve_part2(1024),
-    ))
-}
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("dim", "71"), Param::new("bytes", "1024")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("22")
+        .part2("6,1")
+        .params("dim=7,bytes=12")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int},{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(
+            input,
+            params.get_in("dim", 1..=i32::MAX as usize)?,
+            params.get("bytes")?,
+        )?)
+    }
 
-impl Point {
-    fn tostring(&self) -> String {
-        self.x.to_string() + "," + &self.y.to_string()
+    fn part1(&self) -> impl Into<Answer> {
+        self.shortest_path(self.fallen)
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2(self.fallen)
+            .map_or(Answer::NotApplicable, Answer::from)
     }
 }
 
-struct InputData {
+pub struct InputData {
     bytes: Vec<Point>,
     max_x: usize,
     max_y: usize,
+    fallen: usize,
 }
 
 impl InputData {
-    fn parse_input(input: &str, dim: usize) -> Self {
-        Self {
-            bytes: input
-                .lines()
-                .map(|line| Point::from_str(line).unwrap())
-                .collect(),
+    fn parse_input(input: &str, dim: usize, fallen: usize) -> Result<Self, ParseError> {
+        let bytes = parse::lines(input, Point::from_str)?;
+        if fallen > bytes.len() {
+            return Err(ParseError::missing(
+                format!("expected at least {fallen} bytes, found {}", bytes.len()),
+                input,
+            ));
+        }
+        Ok(Self {
+            bytes,
             max_x: dim,
             max_y: dim,
-        }
+            fallen,
+        })
     }
 
     fn shortest_path(&self, bytes: usize) -> Option<usize> {
-        let mut grid = Grid::new(self.max_x, self.max_y, '.');
-        (0..bytes).for_each(|i| grid.set_point(&self.bytes[i], '#'));
-        let mut seen = HashSet::new();
+        let mut corrupted = Grid::new(self.max_x, self.max_y, false);
+        (0..bytes).for_each(|i| corrupted.set_point(&self.bytes[i], true));
         let exit = Point::new(self.max_x as i32 - 1, self.max_y as i32 - 1);
-        let mut queue = VecDeque::new();
-        queue.push_back((ORIGIN, 0, Point::new(-1, -1)));
-
-        while let Some((current, steps, previous)) = queue.pop_front() {
-            if current == exit {
-                return Some(steps);
-            }
-            if seen.contains(&current) {
-                continue;
-            }
-            seen.insert(current);
-            for neighbor in NEIGHBORS_STRAIGHT.iter().map(|n| *n + current) {
-                if let Some(v) = grid.get_element(&neighbor) {
-                    if v == '#' || neighbor == previous {
-                        continue;
-                    }
-                    queue.push_back((neighbor, steps + 1, current));
-                }
-            }
-        }
-        None
-    }
-
-    fn solve_part1(&self, bytes: usize) -> usize {
-        self.shortest_path(bytes).unwrap()
+        grid_bfs(&corrupted, ORIGIN, |_, to| !to, |p| *p == exit).cost()
     }
 
-    fn solve_part2(&self, start: usize) -> String {
-        let mut lowest = start;
+    /// Returns the first byte which cuts off the exit, searching from `start` bytes, or `None` if
+    /// the exit can still be reached after all bytes have fallen.
+    fn solve_part2(&self, start: usize) -> Option<Point> {
         let mut highest = self.bytes.len();
-        while lowest < highest - 1 {
+        if self.shortest_path(highest).is_some() {
+            return None;
+        }
+        let mut lowest = if self.shortest_path(start).is_some() {
+            start
+        } else {
+            0
+        };
+        while lowest + 1 < highest {
             let tryval = (highest + lowest) / 2;
             if self.shortest_path(tryval).is_some() {
                 lowest = tryval;
@@ -97,15 +114,11 @@ impl InputData {
                 highest = tryval;
             }
         }
-        self.bytes[lowest].tostring()
+        Some(self.bytes[lowest])
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "5,4
+const EXAMPLE: &str = "5,4
 4,2
 4,5
 3,0
@@ -131,15 +144,13 @@ mod tests {
 1,6
 2,0";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::parse_input(TEST_DATA, 7);
-        assert_eq!(solution_data.solve_part1(12), 22);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::parse_input(TEST_DATA, 7);
-        assert_eq!(solution_data.solve_part2(12), "6,1");
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,69 +1,84 @@
 //! # 2024 day 19 - Linen Layout
 //!
 //! Recursive solution with memo, solving both parts in one go.
-use std::{collections::HashMap, error::Error};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
+use std::{cell::OnceCell, collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("6").part2("16")];
+
+    const SHAPE: Shape =
+        Shape::Sections(&[Shape::Lines(&["[wubrg, ]"]), Shape::Lines(&["[wubrg]"])]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.arrangements().iter().filter(|n| **n > 0).count()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.arrangements().iter().sum::<usize>()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     patterns: Vec<&'a str>,
     designs: Vec<&'a str>,
+    arrangements: OnceCell<Vec<usize>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let (p, d) = s.split_once("\n\n").unwrap();
+        let (p, d) = parse::split_once(s, "\n\n")?;
         Ok(Self {
-            patterns: p.split(", ").collect(),
+            patterns: p.split(", ").filter(|p| !p.is_empty()).collect(),
             designs: d.lines().collect(),
+            arrangements: OnceCell::new(),
         })
     }
 }
 
 impl InputData<'_> {
-    fn solve(&self) -> (usize, usize) {
-        let seen = &mut HashMap::new();
-        let result: Vec<usize> = self
-            .designs
-            .iter()
-            .map(|design| self.process_designs(seen, design))
-            .collect();
-        (
-            result.iter().filter(|n| **n > 0).count(),
-            result.iter().sum(),
-        )
-    }
-
-    fn process_designs<'b>(&self, seen: &mut HashMap<&'b str, usize>, design: &'b str) -> usize {
-        if design.is_empty() {
-            1
-        } else if let Some(cache) = seen.get(design) {
-            *cache
-        } else {
-            let count: usize = self
-                .patterns
+    /// Returns the number of ways each design can be made, which both parts count.
+    fn arrangements(&self) -> &[usize] {
+        self.arrangements.get_or_init(|| {
+            let seen = &mut HashMap::new();
+            self.designs
                 .iter()
-                .filter_map(|p| design.strip_prefix(p))
-                .map(|s| self.process_designs(seen, s))
-                .sum();
-            seen.insert(design, count);
-            count
-        }
+                .map(|design| process_designs(&self.patterns, seen, design))
+                .collect()
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn process_designs<'b>(
+    patterns: &[&str],
+    seen: &mut HashMap<&'b str, usize>,
+    design: &'b str,
+) -> usize {
+    if design.is_empty() {
+        1
+    } else if let Some(cache) = seen.get(design) {
+        *cache
+    } else {
+        let count: usize = patterns
+            .iter()
+            .filter_map(|p| design.strip_prefix(p))
+            .map(|s| process_designs(patterns, seen, s))
+            .sum();
+        seen.insert(design, count);
+        count
+    }
+}
 
-    #[test]
-    fn part1_example_1() {
-        let testdata = "r, wr, b, g, bwu, rb, gb, br
+const EXAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
 
 brwrr
 bggr
@@ -73,9 +88,14 @@ ubwu
 bwurrg
 brgr
 bbrgwb";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 6);
-        assert_eq!(p2, 16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
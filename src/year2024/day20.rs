@@ -12,56 +12,88 @@
 //! Although this is already decently fast, since the solution is calculated on
 //! data that doesn't change after parsing, using this to as an opportunity
 //! to check out multi-threading.
+//!
+//! The minimum gain of 100 is the `gain` param, since the example track is far too short for it.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use crate::aoc_util::{grid::*, point::*, thread::*};
 use std::{
     error::Error,
-    str::FromStr,
     sync::atomic::{AtomicU32, Ordering},
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("gain", "100")];
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE).part1("44").params("gain=2"),
+        Example::new(EXAMPLE).part2("285").params("gain=50"),
+        Example::new(EXAMPLE)
+            .part1("1")
+            .part2("86")
+            .params("gain=64"),
+    ];
+
+    const SHAPE: Shape = Shape::Grid(".#SE");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("gain")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     path: Vec<Point>,
+    gain: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid = Grid::parse(s);
-        let start = grid.find('S').unwrap();
-        let end = grid.find('E').unwrap();
+impl InputData {
+    fn parse_input(s: &str, gain: usize) -> Result<Self, ParseError> {
+        let grid = Grid::parse(s)?;
+        let start = grid
+            .find(b'S')
+            .ok_or_else(|| ParseError::missing("missing start `S`", s))?;
+        let end = grid
+            .find(b'E')
+            .ok_or_else(|| ParseError::missing("missing end `E`", s))?;
         let mut grid_path = vec![start];
         while *grid_path.last().unwrap() != end {
-            for neighbor in NEIGHBORS_STRAIGHT
+            let current = *grid_path.last().unwrap();
+            let previous = grid_path.len().checked_sub(2).map(|i| grid_path[i]);
+            let next = NEIGHBORS_STRAIGHT
                 .iter()
-                .map(|&n| *grid_path.last().unwrap() + n)
-            {
-                if let Some(e) = grid.get_element(&neighbor) {
-                    if e == '#' {
-                        continue;
-                    }
-                    if let Some(previous) = grid_path.get(grid_path.len().wrapping_sub(2))
-                        && *previous == neighbor
-                    {
-                        continue;
-                    }
-                    grid_path.push(neighbor);
-                    break;
-                }
+                .map(|&n| current + n)
+                .find(|p| grid.get_element(p).is_some_and(|e| e != b'#') && Some(*p) != previous)
+                .ok_or_else(|| ParseError::new("the track dead-ends before the end `E`", s))?;
+            if grid_path.len() > grid.x_max * grid.y_max {
+                return Err(ParseError::new(
+                    "the track loops without reaching the end `E`",
+                    s,
+                ));
             }
+            grid_path.push(next);
         }
-        Ok(Self { path: grid_path })
+        Ok(Self {
+            path: grid_path,
+            gain,
+        })
     }
-}
 
-impl InputData {
     fn find_cheats(&self, cheat_steps: usize, gain: usize) -> usize {
         let total = AtomicU32::new(0);
         let items = Vec::from_iter(0..self.path.len());
@@ -84,19 +116,15 @@ impl InputData {
     }
 
     fn solve_part1(&self) -> usize {
-        self.find_cheats(2, 100)
+        self.find_cheats(2, self.gain)
     }
 
     fn solve_part2(&self) -> usize {
-        self.find_cheats(20, 100)
+        self.find_cheats(20, self.gain)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "###############
+const EXAMPLE: &str = "###############
 #...#...#.....#
 #.#.#.#.#.###.#
 #S#...#.#.#...#
@@ -112,9 +140,19 @@ mod tests {
 #...#...#...###
 ###############";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
+    }
+
     #[test]
     fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
+        let solution_data = InputData::parse_input(EXAMPLE, 100).unwrap();
         assert_eq!(solution_data.find_cheats(2, 2), 44);
         assert_eq!(solution_data.find_cheats(2, 4), 30);
         assert_eq!(solution_data.find_cheats(2, 6), 16);
@@ -130,7 +168,7 @@ mod tests {
 
     #[test]
     fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
+        let solution_data = InputData::parse_input(EXAMPLE, 100).unwrap();
         assert_eq!(solution_data.find_cheats(20, 50), 285);
         assert_eq!(solution_data.find_cheats(20, 52), 253);
         assert_eq!(solution_data.find_cheats(20, 54), 222);
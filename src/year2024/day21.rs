@@ -1,16 +1,33 @@
 //! # 2024 day 21 - Keypad Conundrum
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use crate::aoc_util::point::*;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("126384")
+        .part2("154115708116294")];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789]A"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 const NUMPAD: &str = "789\n456\n123\n.0A";
@@ -22,7 +39,7 @@ struct Keypad {
 }
 
 impl FromStr for Keypad {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut keys = HashMap::new();
         let mut positions = HashMap::new();
@@ -100,15 +117,27 @@ impl Keypad {
     }
 }
 
-struct InputData {
+/// The codes, and their numeric parts.
+pub struct InputData {
     codes: Vec<Vec<char>>,
+    numbers: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = parse::lines(s, |line| match line.strip_suffix('A') {
+            Some(digits) if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+                parse::number(digits)
+            }
+            _ => Err(ParseError::new(
+                "expected a code of digits ending in A",
+                line,
+            )),
+        })?;
         Ok(Self {
             codes: s.lines().map(|line| line.chars().collect()).collect(),
+            numbers,
         })
     }
 }
@@ -127,8 +156,8 @@ impl InputData {
             seqs = seqs.iter().map(|code| dir_pad.get_commands(code)).collect();
         }
         seqs.iter()
-            .zip(&self.codes)
-            .map(|(seq, code)| seq.len() * code_to_num(code))
+            .zip(&self.numbers)
+            .map(|(seq, number)| seq.len() * number)
             .sum()
     }
 
@@ -163,38 +192,25 @@ impl InputData {
         }
         seq_counter
             .iter()
-            .zip(&self.codes)
-            .map(|(seq, code)| {
-                seq.iter().map(|(k, v)| k.len() * v).sum::<usize>() * code_to_num(code)
-            })
+            .zip(&self.numbers)
+            .map(|(seq, number)| seq.iter().map(|(k, v)| k.len() * v).sum::<usize>() * number)
             .sum()
     }
 }
 
-fn code_to_num(input: &[char]) -> usize {
-    let s: String = input[0..input.len() - 1].iter().collect();
-    s.parse().unwrap()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "029A
+const EXAMPLE: &str = "029A
 980A
 179A
 456A
 379A";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 126384);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 154115708116294);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,61 +1,86 @@
 //! # 2024 day 22 - Monkey Market
 //!
 //! Really slow part 2...
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("37327623"),
+        Example::new(EXAMPLE_2).part2("23"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     secret_numbers: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::missing("missing secret numbers", s));
+        }
         Ok(Self {
-            secret_numbers: s.lines().map(|line| line.parse().unwrap()).collect(),
+            secret_numbers: parse::lines(s, parse::number)?,
         })
     }
 }
 
 impl InputData {
-    fn solve(&self) -> (usize, usize) {
-        let mut bananas: HashMap<Vec<isize>, isize> = HashMap::new();
-        let p1 = self
-            .secret_numbers
+    fn solve_part1(&self) -> usize {
+        self.secret_numbers
             .iter()
-            .map(|&n| {
-                let mut seen = HashSet::new();
-                let mut prices = Vec::with_capacity(2001);
-                let mut changes = Vec::new();
-                let mut next = n;
-                prices.push((next % 10) as isize);
-                for _ in 0..2000 {
-                    next = evolve(next);
-                    let next_10th = (next % 10) as isize;
-                    if changes.len() < 4 {
-                        changes.push(next_10th - prices.last().unwrap());
-                    } else {
-                        changes.rotate_left(1);
-                        changes[3] = next_10th - prices.last().unwrap();
-                        if seen.insert(changes.clone()) {
-                            *bananas.entry(changes.clone()).or_default() += next_10th;
-                        }
+            .map(|&n| (0..2000).fold(n, |next, _| evolve(next)))
+            .sum()
+    }
+
+    fn solve_part2(&self) -> usize {
+        let mut bananas: HashMap<Vec<isize>, isize> = HashMap::new();
+        for &n in &self.secret_numbers {
+            let mut seen = HashSet::new();
+            let mut prices = Vec::with_capacity(2001);
+            let mut changes = Vec::new();
+            let mut next = n;
+            prices.push((next % 10) as isize);
+            for _ in 0..2000 {
+                next = evolve(next);
+                let next_10th = (next % 10) as isize;
+                if changes.len() < 4 {
+                    changes.push(next_10th - prices.last().unwrap());
+                } else {
+                    changes.rotate_left(1);
+                    changes[3] = next_10th - prices.last().unwrap();
+                    if seen.insert(changes.clone()) {
+                        *bananas.entry(changes.clone()).or_default() += next_10th;
                     }
-                    prices.push(next_10th);
                 }
-                next
-            })
-            .sum();
-        (p1, *bananas.values().max().unwrap() as usize)
+                prices.push(next_10th);
+            }
+        }
+        *bananas.values().max().unwrap() as usize
     }
 }
 
@@ -65,29 +90,23 @@ fn evolve(mut nbr: usize) -> usize {
     (nbr ^ (nbr << 11)) % 16777216
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "1
+const EXAMPLE_1: &str = "1
 10
 100
 2024";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (p1, _) = solution_data.solve();
-        assert_eq!(p1, 37327623);
-    }
 
-    #[test]
-    fn part2_example_1() {
-        let testdata = "1
+const EXAMPLE_2: &str = "1
 2
 3
 2024";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        let (_, p2) = solution_data.solve();
-        assert_eq!(p2, 23);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -1,28 +1,43 @@
 //! # 2024 day 23 - LAN Party
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("7").part2("co,de,ka,ta")];
+
+    const SHAPE: Shape = Shape::Lines(&["{word}-{word}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     connections: HashMap<String, HashSet<String>>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut connections: HashMap<String, HashSet<String>> = HashMap::new();
-        s.lines().for_each(|line| {
-            let (left, right) = line.split_once('-').unwrap();
+        for line in s.lines() {
+            let (left, right) = parse::split_once(line, "-")?;
             connections
                 .entry(left.to_string())
                 .and_modify(|v| {
@@ -35,7 +50,7 @@ impl FromStr for InputData {
                     v.insert(left.to_string());
                 })
                 .or_insert(HashSet::from([left.to_string()]));
-        });
+        }
         Ok(Self { connections })
     }
 }
@@ -87,11 +102,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "kh-tc
+const EXAMPLE: &str = "kh-tc
 qp-kh
 de-cg
 ka-co
@@ -124,15 +135,13 @@ wh-qp
 tb-vc
 td-yn";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), "co,de,ka,ta");
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -21,6 +21,11 @@
 //! - A correct OR gate can never connect to another OR gate
 //! - Except for the first XOR, a correct XOR will connect to another XOR,
 //!   which in turn connects to output.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 
 use std::{
     collections::{HashMap, HashSet, VecDeque},
@@ -28,12 +33,29 @@ use std::{
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("4"),
+        Example::new(EXAMPLE_2).part1("2024"),
+    ];
+
+    const SHAPE: Shape = Shape::Sections(&[
+        Shape::Lines(&["{word}: {int}"]),
+        Shape::Lines(&["{word} [ANDXOR] {word} -> {word}"]),
+    ]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -44,13 +66,13 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "AND" => Ok(Self::And),
             "OR" => Ok(Self::Or),
             "XOR" => Ok(Self::Xor),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected `AND`, `OR` or `XOR`", s)),
         }
     }
 }
@@ -73,14 +95,14 @@ struct Gate<'b> {
 }
 
 impl<'b> TryFrom<&'b str> for Gate<'b> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'b str) -> Result<Self, Self::Error> {
         let mut tokens = s.split_whitespace();
-        let in_a = tokens.next().unwrap();
-        let op = Operation::from_str(tokens.next().unwrap()).unwrap();
-        let in_b = tokens.next().unwrap();
-        tokens.next();
-        let out = tokens.next().unwrap();
+        let in_a = parse::next(&mut tokens, "input", s)?;
+        let op = Operation::from_str(parse::next(&mut tokens, "operation", s)?)?;
+        let in_b = parse::next(&mut tokens, "input", s)?;
+        parse::strip_prefix(parse::next(&mut tokens, "`->`", s)?, "->")?;
+        let out = parse::next(&mut tokens, "output", s)?;
         Ok(Self {
             in_a,
             in_b,
@@ -90,26 +112,26 @@ impl<'b> TryFrom<&'b str> for Gate<'b> {
     }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     initial_values: Vec<(&'a str, usize)>,
     gates: Vec<Gate<'a>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let (iv, g) = s.split_once("\n\n").unwrap();
+        let (iv, g) = parse::split_once(s, "\n\n")?;
         let initial_values = iv
             .lines()
             .map(|line| {
-                let (left, right) = line.split_once(": ").unwrap();
-                (left, right.parse::<usize>().unwrap())
+                let (left, right) = parse::split_once(line, ": ")?;
+                match parse::number::<usize>(right)? {
+                    value @ (0 | 1) => Ok((left, value)),
+                    _ => Err(ParseError::new("expected 0 or 1", right)),
+                }
             })
-            .collect();
-        let gates = g
-            .lines()
-            .map(|line| Gate::try_from(line).unwrap())
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
+        let gates = g.lines().map(Gate::try_from).collect::<Result<_, _>>()?;
         Ok(Self {
             initial_values,
             gates,
@@ -118,7 +140,9 @@ impl<'a> TryFrom<&'a str> for InputData<'a> {
 }
 
 impl InputData<'_> {
-    fn solve_part1(&self) -> usize {
+    /// Returns `None` if some gate never gets both its inputs, or if an output bit doesn't fit in
+    /// the result.
+    fn solve_part1(&self) -> Option<usize> {
         let mut wires: HashMap<&str, usize> = HashMap::new();
         self.initial_values.iter().for_each(|(iv_name, iv_val)| {
             wires.insert(iv_name, *iv_val);
@@ -127,11 +151,18 @@ impl InputData<'_> {
         self.gates.iter().for_each(|gate| {
             queue.push_back(gate);
         });
+        // The number of gates put back in the queue since a gate last got its inputs
+        let mut waiting = 0;
         while let Some(g) = queue.pop_front() {
             if !wires.contains_key(g.in_a) || !wires.contains_key(g.in_b) {
                 queue.push_back(g);
+                waiting += 1;
+                if waiting > queue.len() {
+                    return None;
+                }
             } else {
                 wires.insert(g.out, g.op.process(wires[g.in_a], wires[g.in_b]));
+                waiting = 0;
             }
         }
         let mut z = 0;
@@ -139,10 +170,10 @@ impl InputData<'_> {
             if let Some(rest) = wire.strip_prefix('z')
                 && let Ok(nbr) = rest.parse::<usize>()
             {
-                z |= val << nbr;
+                z |= val.checked_shl(u32::try_from(nbr).ok()?)?;
             }
         }
-        z
+        Some(z)
     }
 
     fn solve_part2(&self) -> String {
@@ -193,13 +224,7 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "x00: 1
+const EXAMPLE_1: &str = "x00: 1
 x01: 1
 x02: 1
 y00: 0
@@ -209,13 +234,8 @@ y02: 0
 x00 AND y00 -> z00
 x01 XOR y01 -> z01
 x02 OR y02 -> z02";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4);
-    }
 
-    #[test]
-    fn part1_example_2() {
-        let testdata = "x00: 1
+const EXAMPLE_2: &str = "x00: 1
 x01: 0
 x02: 1
 x03: 1
@@ -262,7 +282,14 @@ y03 OR x01 -> nrd
 hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj";
-        let solution_data = InputData::try_from(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 2024);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
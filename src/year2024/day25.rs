@@ -1,4 +1,4 @@
-//! 2024 day 25 - Code Chronicle
+//! # 2024 day 25 - Code Chronicle
 //!
 //! Store the keys/locks as items represented by a number, made up of the depth of each column
 //! bit-shifted into its location. The solution assumes width 5 and max depth 5 (top and bottom
@@ -8,12 +8,30 @@
 //! check each column by making sure the bitmasked sum doesn't exceed 5. The easiest way to do this
 //! is to add 2 and then see if it causes an overflow into the 4th bit (0x8). That way there is no
 //! need to iterate over each individual digit.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
 use crate::aoc_util::grid::*;
+use crate::aoc_util::parse::ParseError;
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((solution_data.solve_part1().to_string(), "-".to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("3")];
+
+    const SHAPE: Shape = Shape::Blocks(&Shape::Grid(".#"));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        Answer::NotApplicable
+    }
 }
 
 enum Item {
@@ -22,18 +40,21 @@ enum Item {
 }
 
 impl FromStr for Item {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines = Grid::parse(s);
+        let lines = Grid::parse(s)?;
         // Naive approach - assume that all input items are correct, i.e. first line all have the same char
         let mut signature: Vec<usize> = vec![0; lines.x_max];
-        let k = lines.elements[0];
+        let k = *lines
+            .elements
+            .first()
+            .ok_or_else(|| ParseError::new("expected a lock or key", s))?;
         for (i, c) in lines.elements.iter().enumerate() {
             if *c == k {
                 signature[i % lines.x_max] += 1;
             }
         }
-        if k == '#' {
+        if k == b'#' {
             Ok(Self::Lock(
                 signature
                     .iter()
@@ -55,18 +76,18 @@ impl FromStr for Item {
     }
 }
 
-struct InputData {
+pub struct InputData {
     locks: Vec<usize>,
     keys: Vec<usize>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut locks = Vec::new();
         let mut keys = Vec::new();
-        for item in s.split("\n\n").map(|i| Item::from_str(i).unwrap()) {
-            match item {
+        for item in s.split("\n\n") {
+            match Item::from_str(item)? {
                 Item::Lock(s) => locks.push(s),
                 Item::Key(s) => keys.push(s),
             }
@@ -90,13 +111,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_example_1() {
-        let testdata = "#####
+const EXAMPLE: &str = "#####
 .####
 .####
 .####
@@ -135,7 +150,14 @@ mod tests {
 #.#..
 #.#.#
 #####";
-        let solution_data = InputData::from_str(testdata).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
+    #[test]
+    fn examples() {
+        check::<InputData>();
     }
 }
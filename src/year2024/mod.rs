@@ -23,3 +23,33 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2024:
+    1 => day01: "Historian Hysteria",
+    2 => day02: "Red-Nosed Reports",
+    3 => day03: "Mull It Over",
+    4 => day04: "Ceres Search",
+    5 => day05: "Print Queue",
+    6 => day06: "Guard Gallivant",
+    7 => day07: "Bridge Repair",
+    8 => day08: "Resonant Collinearity",
+    9 => day09: "Disk Fragmenter",
+    10 => day10: "Hoof It",
+    11 => day11: "Plutonian Pebbles",
+    12 => day12: "Garden Groups",
+    13 => day13: "Claw Contraption",
+    14 => day14: "Restroom Redoubt",
+    15 => day15: "Warehouse Woes",
+    16 => day16: "Reindeer Maze",
+    17 => day17: "Chronospatial Computer",
+    18 => day18: "RAM Run",
+    19 => day19: "Linen Layout",
+    20 => day20: "Race Condition",
+    21 => day21: "Keypad Conundrum",
+    22 => day22: "Monkey Market",
+    23 => day23: "LAN Party",
+    24 => day24: "Crossed Wires",
+    25 => day25: "Code Chronicle",
+}
@@ -22,14 +22,29 @@
 //! working with signed integers, and flip the dial around whenever the direction changes. That way, all
 //! changes to the dial will be in the positive direction, i.e. there would be no need to handle
 //! "left" (negative) values separately.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("3").part2("6")];
+
+    const SHAPE: Shape = Shape::Lines(&["[LR]{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Lock {
@@ -61,26 +76,25 @@ impl Lock {
     }
 }
 
-struct InputData {
+pub struct InputData {
     sequence: Vec<i32>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            sequence: s
-                .lines()
-                .map(|line| {
-                    let (direction, value) = line.split_at(1);
-                    let value: i32 = value.parse().unwrap();
-                    match direction {
-                        "L" => -value,
-                        "R" => value,
-                        _ => panic!(),
-                    }
-                })
-                .collect(),
+            sequence: parse::lines(s, |line| {
+                let (direction, value) = line
+                    .split_at_checked(1)
+                    .ok_or_else(|| ParseError::new("expected a rotation", line))?;
+                let value: i32 = parse::number(value)?;
+                match direction {
+                    "L" => Ok(-value),
+                    "R" => Ok(value),
+                    _ => Err(ParseError::new("expected L or R", direction)),
+                }
+            })?,
         })
     }
 }
@@ -103,11 +117,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "L68
+const EXAMPLE: &str = "L68
 L30
 R48
 L5
@@ -118,15 +128,13 @@ L99
 R14
 L82";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 6);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -17,38 +17,60 @@
 //!   the second value in the range.
 //! * The same number can be found with different part lengths, but should only be counted once. So the identified invalid
 //!   numbers are stored in a set and then summarized at the end to get rid of duplicates.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashSet, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("1227775554")
+        .part2("4174379265")];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789,-]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     ranges: Vec<(&'a str, &'a str)>,
 }
 
+/// Repeats the digits of `part` the given number of times (12 -> 121212), or returns `None` if the
+/// result doesn't fit in 64 bits.
+fn repeat_digits(part: u64, times: usize) -> Option<u64> {
+    let shift = 10_u64.checked_pow(1 + part.checked_ilog10().unwrap_or(0))?;
+    (1..times).try_fold(part, |id, _| id.checked_mul(shift)?.checked_add(part))
+}
+
 fn get_invalid(v1: &str, v2: &str) -> u64 {
-    let start_id: u64 = v1.parse().unwrap();
-    let stop_id: u64 = v2.parse().unwrap();
+    let start_id: u64 = v1.parse().expect("the range was validated");
+    let stop_id: u64 = v2.parse().expect("the range was validated");
     let nbrof_parts = 2;
 
     let mut invalid = 0;
     let mut next_part: u64 = if v1.len().is_multiple_of(nbrof_parts) {
-        v1[..v1.len() / nbrof_parts].parse().unwrap()
+        v1[..v1.len() / nbrof_parts]
+            .parse()
+            .expect("the range was validated")
     } else {
         10_u64.pow((v1.len() / nbrof_parts) as u32)
     };
 
-    loop {
-        let nbrof_part_digits = 1 + next_part.checked_ilog10().unwrap_or(0);
-        let candidate_id = next_part + next_part * 10_u64.pow(nbrof_part_digits);
-        if candidate_id > stop_id {
-            break;
-        }
+    while let Some(candidate_id) = repeat_digits(next_part, nbrof_parts).filter(|id| *id <= stop_id)
+    {
         if candidate_id >= start_id {
             invalid += candidate_id;
         }
@@ -59,26 +81,23 @@ fn get_invalid(v1: &str, v2: &str) -> u64 {
 }
 
 fn get_multiple_invalid(v1: &str, v2: &str) -> u64 {
-    let start_id: u64 = v1.parse().unwrap();
-    let stop_id: u64 = v2.parse().unwrap();
+    let start_id: u64 = v1.parse().expect("the range was validated");
+    let stop_id: u64 = v2.parse().expect("the range was validated");
     let mut nbrof_parts = 2;
     let mut invalid = HashSet::new();
 
     while nbrof_parts <= v2.len() {
         let mut next_part: u64 = if v1.len().is_multiple_of(nbrof_parts) {
-            v1[..v1.len() / nbrof_parts].parse().unwrap()
+            v1[..v1.len() / nbrof_parts]
+                .parse()
+                .expect("the range was validated")
         } else {
             10_u64.pow((v1.len() / nbrof_parts) as u32)
         };
 
-        loop {
-            let nbrof_part_digits = 1 + next_part.checked_ilog10().unwrap_or(0);
-            let candidate_id = (0..nbrof_parts)
-                .map(|p| next_part * 10_u64.pow(p as u32 * nbrof_part_digits))
-                .sum();
-            if candidate_id > stop_id {
-                break;
-            }
+        while let Some(candidate_id) =
+            repeat_digits(next_part, nbrof_parts).filter(|id| *id <= stop_id)
+        {
             if candidate_id >= start_id {
                 invalid.insert(candidate_id);
             }
@@ -91,10 +110,24 @@ fn get_multiple_invalid(v1: &str, v2: &str) -> u64 {
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let id = |s: &'a str| {
+            let max_digits = u64::MAX.ilog10() as usize + 1;
+            if s.is_empty() || s.len() > max_digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::new("expected an ID", s));
+            }
+            parse::number::<u64>(s)?;
+            Ok(s)
+        };
         Ok(Self {
-            ranges: s.split(',').map(|r| r.split_once('-').unwrap()).collect(),
+            ranges: s
+                .split(',')
+                .map(|r| {
+                    let (v1, v2) = parse::split_once(r, "-")?;
+                    Ok((id(v1)?, id(v2)?))
+                })
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -112,21 +145,15 @@ impl InputData<'_> {
     }
 }
 
+const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const TEST_DATA: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 1227775554);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 4174379265);
+    fn examples() {
+        check::<InputData>();
     }
 }
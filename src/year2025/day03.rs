@@ -12,40 +12,65 @@
 //! ## Part 2
 //!
 //! Same as part 1, except repeat the search for digits 12 times instead of just 2.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::error::Error;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] =
+        &[Example::new(EXAMPLE).part1("357").part2("3121910778619")];
+
+    const SHAPE: Shape = Shape::Grid("123456789");
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     banks: Vec<&'a str>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         Ok(Self {
-            banks: s.lines().collect(),
+            banks: parse::lines(s, |line| match line.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => Err(ParseError::new("expected a digit", &line[i..])),
+                None => Ok(line),
+            })?,
         })
     }
 }
 
 impl InputData<'_> {
-    fn get_jolt(&self, nbrof_digits: usize) -> u64 {
+    /// Returns `None` if a bank has fewer batteries than `nbrof_digits`.
+    fn get_jolt(&self, nbrof_digits: usize) -> Option<u64> {
         let mut total = 0;
         for bank in self.banks.iter() {
+            if bank.len() < nbrof_digits {
+                return None;
+            }
             let mut new_number = 0;
             let mut start_idx = 0;
             for digit_idx in (0..nbrof_digits).rev() {
                 let mut next_digit = 0;
                 let i = start_idx;
                 for x in i..bank.len() - digit_idx {
-                    let new_digit: u64 = bank.get(x..x + 1).unwrap().parse().unwrap();
+                    let new_digit = u64::from(bank.as_bytes()[x] - b'0');
                     if next_digit < new_digit {
                         next_digit = new_digit;
                         start_idx = x + 1;
@@ -58,36 +83,30 @@ impl InputData<'_> {
             }
             total += new_number;
         }
-        total
+        Some(total)
     }
 
-    fn solve_part1(&self) -> u64 {
+    fn solve_part1(&self) -> Option<u64> {
         self.get_jolt(2)
     }
 
-    fn solve_part2(&self) -> u64 {
+    fn solve_part2(&self) -> Option<u64> {
         self.get_jolt(12)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "987654321111111
+const EXAMPLE: &str = "987654321111111
 811111111111119
 234234234234278
 818181911112111";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 357);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 3121910778619);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -19,55 +19,65 @@
 //! * When a point is identified to be cleared, update that point in the map and push all its
 //!   neighbors containing paper to the back of the queue to be re-evaluated.
 //! * Once the queue is empty, count the number of nodes in the map that still contain paper.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{collections::VecDeque, error::Error, str::FromStr};
 
-use crate::aoc_util::{grid::Grid, point::*};
+use crate::aoc_util::grid::Grid;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("13").part2("43")];
+
+    const SHAPE: Shape = Shape::Grid(".@");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     paper_map: Grid,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            paper_map: Grid::parse(s),
+            paper_map: Grid::parse(s)?,
         })
     }
 }
 
-const PAPER: char = '@';
-const NOT_PAPER: char = '.';
+const PAPER: u8 = b'@';
+const NOT_PAPER: u8 = b'.';
 
 impl InputData {
     fn solve_part1(&self) -> u32 {
         let mut total = 0;
         let paper: Vec<_> = self
             .paper_map
-            .elements
-            .iter()
-            .enumerate()
-            .filter(|(_, c)| **c == PAPER)
-            .map(|(i, _)| self.paper_map.get_point(i))
+            .cells()
+            .filter(|(_, c)| *c == PAPER)
+            .map(|(p, _)| p)
             .collect();
         let mut queue = VecDeque::from(paper);
         while let Some(p) = queue.pop_front() {
-            let mut paper_neighbors = 0;
-            for neighbor in NEIGHBORS_ALL.iter().map(|n| *n + p) {
-                if let Some(c) = self.paper_map.get_element(&neighbor)
-                    && c == PAPER
-                {
-                    paper_neighbors += 1;
-                }
-            }
+            let paper_neighbors = self
+                .paper_map
+                .neighbors_all(&p)
+                .filter(|(_, c)| *c == PAPER)
+                .count();
             if paper_neighbors < 4 {
                 total += 1;
             }
@@ -80,11 +90,9 @@ impl InputData {
         let mut paper_map = self.paper_map.clone();
         let paper: Vec<_> = self
             .paper_map
-            .elements
-            .iter()
-            .enumerate()
-            .filter(|(_, c)| **c == PAPER)
-            .map(|(i, _)| self.paper_map.get_point(i))
+            .cells()
+            .filter(|(_, c)| *c == PAPER)
+            .map(|(p, _)| p)
             .collect();
         let mut queue = VecDeque::from(paper);
         while let Some(p) = queue.pop_front() {
@@ -92,14 +100,11 @@ impl InputData {
                 // The point has already been cleared after this entry got added to the queue
                 continue;
             }
-            let mut paper_neighbors = Vec::with_capacity(8);
-            for neighbor in NEIGHBORS_ALL.iter().map(|n| *n + p) {
-                if let Some(c) = paper_map.get_element(&neighbor)
-                    && c == PAPER
-                {
-                    paper_neighbors.push(neighbor);
-                }
-            }
+            let paper_neighbors: Vec<_> = paper_map
+                .neighbors_all(&p)
+                .filter(|(_, c)| *c == PAPER)
+                .map(|(n, _)| n)
+                .collect();
             if paper_neighbors.len() < 4 {
                 total += 1;
                 paper_map.set_point(&p, NOT_PAPER);
@@ -112,11 +117,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "..@@.@@@@.
+const EXAMPLE: &str = "..@@.@@@@.
 @@@.@.@.@@
 @@@@@.@.@@
 @.@@@@..@.
@@ -127,15 +128,13 @@ mod tests {
 .@@@@@@@@.
 @.@.@@@.@.";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 13);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 43);
+    fn examples() {
+        check::<InputData>();
     }
 }
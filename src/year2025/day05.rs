@@ -17,35 +17,48 @@
 //! Once the new list of combined ranges is completed, take the total sum of ID:s contained
 //! by each range. Note that the ranges are inclusive, so the number of ID:s for a range
 //! is 1 + upper_range - lower range.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::VecDeque, error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("3").part2("14")];
+
+    const SHAPE: Shape =
+        Shape::Sections(&[Shape::Lines(&["{int}-{int}"]), Shape::Lines(&["{int}"])]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData {
+pub struct InputData {
     fresh_id_ranges: Vec<(u64, u64)>,
     available_ids: Vec<u64>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (r, a) = s.split_once("\n\n").unwrap();
-        let ranges: Vec<(u64, u64)> = r
-            .lines()
-            .map(|line| {
-                let (x, y) = line.split_once('-').unwrap();
-                (x.parse().unwrap(), y.parse().unwrap())
-            })
-            .collect();
+        let (r, a) = parse::split_once(s, "\n\n")?;
+        let ranges: Vec<(u64, u64)> = parse::lines(r, |line| {
+            let (x, y) = parse::split_once(line, "-")?;
+            Ok((parse::number(x)?, parse::number(y)?))
+        })?;
         Ok(Self {
             fresh_id_ranges: ranges,
-            available_ids: a.lines().map(|line| line.parse().unwrap()).collect(),
+            available_ids: parse::lines(a, parse::number)?,
         })
     }
 }
@@ -87,11 +100,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "3-5
+const EXAMPLE: &str = "3-5
 10-14
 16-20
 12-18
@@ -103,15 +112,13 @@ mod tests {
 17
 32";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 3);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 14);
+    fn examples() {
+        check::<InputData>();
     }
 }
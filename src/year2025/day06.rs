@@ -8,14 +8,31 @@
 //! vertically. Parsing horizontally is done with trivial whitespace splitting. The vertical
 //! numbers are derived by first transposing the input and then treating the empty lines as
 //! separators for the column groups.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("4277556").part2("3263827")];
+
+    const SHAPE: Shape = Shape::Lines(&["[0123456789 ]", "[*+ ]"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -25,12 +42,12 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "+" => Ok(Self::Add),
             "*" => Ok(Self::Mul),
-            _ => Err(()),
+            _ => Err(ParseError::new("expected `+` or `*`", s)),
         }
     }
 }
@@ -43,49 +60,65 @@ struct Column {
 }
 
 impl Column {
-    fn simple_eval(&self) -> u64 {
-        match self.operation {
-            Operation::Add => self.numbers.iter().sum(),
-            Operation::Mul => self.numbers.iter().product(),
-        }
+    fn simple_eval(&self) -> Option<u64> {
+        self.operation.eval(&self.numbers)
+    }
+
+    fn cephalopod_eval(&self) -> Option<u64> {
+        self.operation.eval(&self.number_cols)
     }
+}
 
-    fn cephalopod_eval(&self) -> u64 {
-        match self.operation {
-            Operation::Add => self.number_cols.iter().sum(),
-            Operation::Mul => self.number_cols.iter().product(),
+impl Operation {
+    /// Returns `None` if the result overflows.
+    fn eval(&self, numbers: &[u64]) -> Option<u64> {
+        match self {
+            Self::Add => numbers.iter().try_fold(0u64, |acc, n| acc.checked_add(*n)),
+            Self::Mul => numbers.iter().try_fold(1u64, |acc, n| acc.checked_mul(*n)),
         }
     }
 }
 
-struct InputData {
+pub struct InputData {
     columns: Vec<Column>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (nbrs, op) = s.rsplit_once('\n').unwrap();
+        let (nbrs, op) = s
+            .trim_end_matches('\n')
+            .rsplit_once('\n')
+            .ok_or_else(|| ParseError::new("expected a line break", s))?;
         let operations: Vec<Operation> = op
             .split_whitespace()
-            .map(|s| Operation::from_str(s).unwrap())
-            .collect();
-
-        let value_lines: Vec<Vec<u64>> = nbrs
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|s| s.parse().unwrap())
-                    .collect()
-            })
-            .collect();
+            .map(Operation::from_str)
+            .collect::<Result<_, _>>()?;
+
+        if operations.is_empty() {
+            return Err(ParseError::missing("missing operations", op));
+        }
+
+        let value_lines: Vec<Vec<u64>> = parse::lines(nbrs, |line| {
+            let values = line
+                .split_whitespace()
+                .map(parse::number)
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.len() != operations.len() {
+                return Err(ParseError::new(
+                    format!("expected {} numbers", operations.len()),
+                    line,
+                ));
+            }
+            Ok(values)
+        })?;
         let mut columns = vec![
             Column {
                 numbers: vec![0; value_lines.len()],
                 number_cols: Vec::new(),
                 operation: Operation::Add,
             };
-            value_lines[0].len()
+            operations.len()
         ];
 
         // Find the horizontal numbers
@@ -100,17 +133,24 @@ impl FromStr for InputData {
 
         // Find the vertical numbers by first transposing the input lines
         let lines: Vec<Vec<char>> = nbrs.lines().map(|line| line.chars().collect()).collect();
-        let mut lines_transposed = vec![vec![' '; lines.len()]; lines[0].len()];
-        for row in 0..lines.len() {
-            for col in 0..lines[0].len() {
-                lines_transposed[col][row] = lines[row][col];
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let mut lines_transposed = vec![vec![' '; lines.len()]; width];
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.iter().enumerate() {
+                lines_transposed[col][row] = *c;
             }
         }
 
         let mut col_idx = 0;
         for line in lines_transposed.iter() {
             if let Ok(val) = line.iter().collect::<String>().trim().parse::<u64>() {
-                columns[col_idx].number_cols.push(val);
+                columns
+                    .get_mut(col_idx)
+                    .ok_or_else(|| {
+                        ParseError::new("expected the problems to be separated by one column", nbrs)
+                    })?
+                    .number_cols
+                    .push(val);
             } else {
                 col_idx += 1;
             }
@@ -121,32 +161,32 @@ impl FromStr for InputData {
 }
 
 impl InputData {
-    fn solve_part1(&self) -> u64 {
-        self.columns.iter().map(|c| c.simple_eval()).sum()
+    /// Returns `None` if the grand total overflows.
+    fn solve_part1(&self) -> Option<u64> {
+        self.columns
+            .iter()
+            .try_fold(0u64, |total, c| total.checked_add(c.simple_eval()?))
     }
 
-    fn solve_part2(&self) -> u64 {
-        self.columns.iter().map(|c| c.cephalopod_eval()).sum()
+    /// Returns `None` if the grand total overflows.
+    fn solve_part2(&self) -> Option<u64> {
+        self.columns
+            .iter()
+            .try_fold(0u64, |total, c| total.checked_add(c.cephalopod_eval()?))
     }
 }
 
+// Note: need to format the input this way, since rust formatter otherwise shaves off whitespaces
+// at the end of lines, which are essential for parsing lines correctly.
+const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    // Note: need to format the input this way, since rust formatter otherwise shaves off whitespaces
-    // at the end of lines, which are essential for parsing lines correctly.
-    const TEST_DATA: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 4277556);
-    }
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 3263827);
+    fn examples() {
+        check::<InputData>();
     }
 }
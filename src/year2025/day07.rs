@@ -11,67 +11,93 @@
 //!   this, also count how many times a split is performed.
 //! * After processing the last row, the answer for part 1 is the counter for number of splits performed,
 //!   and the answer to part 2 is the sum of the counter vector.
-use std::{error::Error, str::FromStr};
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
+use std::{cell::OnceCell, error::Error, str::FromStr};
 
 use crate::aoc_util::{grid::Grid, point::Point};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    let (p1, p2) = solution_data.solve();
-    Ok((p1.to_string(), p2.to_string()))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("21").part2("40")];
+
+    const SHAPE: Shape = Shape::Grid(".S^");
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve().0
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve().1
+    }
 }
 
-const START: char = 'S';
-const SPLITTER: char = '^';
+const START: u8 = b'S';
+const SPLITTER: u8 = b'^';
 
-struct InputData {
+pub struct InputData {
     manifold: Grid,
+    counts: OnceCell<(u64, u64)>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let manifold = Grid::parse(s);
-        Ok(Self { manifold })
+        let manifold = Grid::parse(s)?;
+        if let Some(line) = s.lines().find(|line| {
+            line.starts_with(char::from(SPLITTER)) || line.ends_with(char::from(SPLITTER))
+        }) {
+            return Err(ParseError::new("splitter at the edge", line));
+        }
+        Ok(Self {
+            manifold,
+            counts: OnceCell::new(),
+        })
     }
 }
 
 impl InputData {
+    /// Returns the number of splits and the number of timelines, which come out of the same pass
+    /// over the manifold.
     fn solve(&self) -> (u64, u64) {
-        let mut split_count = 0;
-        let mut x_counts: Vec<u64> = (0..self.manifold.x_max)
-            .map(|x| {
-                if self.manifold.elements[x] == START {
-                    1
-                } else {
-                    0
-                }
-            })
-            .collect();
-        for y in 1..self.manifold.y_max {
-            let mut new_x_counts = vec![0; self.manifold.x_max];
-            for x in (0..self.manifold.x_max).filter(|i| x_counts[*i] > 0) {
-                if let Some(c) = self.manifold.get_element(&Point::new(x as i32, y as i32)) {
-                    if c == SPLITTER {
-                        split_count += 1;
-                        new_x_counts[x - 1] += x_counts[x];
-                        new_x_counts[x + 1] += x_counts[x];
+        *self.counts.get_or_init(|| {
+            let mut split_count = 0;
+            let mut x_counts: Vec<u64> = (0..self.manifold.x_max)
+                .map(|x| {
+                    if self.manifold.elements[x] == START {
+                        1
                     } else {
-                        new_x_counts[x] += x_counts[x];
+                        0
+                    }
+                })
+                .collect();
+            for y in 1..self.manifold.y_max {
+                let mut new_x_counts = vec![0; self.manifold.x_max];
+                for x in (0..self.manifold.x_max).filter(|i| x_counts[*i] > 0) {
+                    if let Some(c) = self.manifold.get_element(&Point::new(x as i32, y as i32)) {
+                        if c == SPLITTER {
+                            split_count += 1;
+                            new_x_counts[x - 1] += x_counts[x];
+                            new_x_counts[x + 1] += x_counts[x];
+                        } else {
+                            new_x_counts[x] += x_counts[x];
+                        }
                     }
                 }
+                x_counts = new_x_counts;
             }
-            x_counts = new_x_counts;
-        }
-        (split_count, x_counts.iter().sum())
+            (split_count, x_counts.iter().sum())
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = ".......S.......
+const EXAMPLE: &str = ".......S.......
 ...............
 .......^.......
 ...............
@@ -88,11 +114,13 @@ mod tests {
 .^.^.^.^.^...^.
 ...............";
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
+
     #[test]
-    fn parts1_2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        let (p1, p2) = solution_data.solve();
-        assert_eq!(p1, 21);
-        assert_eq!(p2, 40);
+    fn examples() {
+        check::<InputData>();
     }
 }
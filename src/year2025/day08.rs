@@ -3,14 +3,42 @@
 //! Solving both parts with Disjoint-Set/Union-Find algorithm, where the main difference is that
 //! for part 1, the stop condition is a certain number of connections, and for part 2 that a circuit
 //! contains all elements in the input list.
+//!
+//! The number of connections made in part 1 is the `pairs` param, since the example uses 10
+//! instead of 1000.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::params::{Param, Params};
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1(1000).to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const PARAMS: &'static [Param] = &[Param::new("pairs", "1000")];
+
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE)
+        .part1("40")
+        .part2("25272")
+        .params("pairs=10")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int},{int},{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_input(input, params.get("pairs")?)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 struct Point3D {
@@ -20,14 +48,13 @@ struct Point3D {
 }
 
 impl FromStr for Point3D {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nbrs: Vec<_> = s.split(',').map(|n| n.parse().unwrap()).collect();
-        Ok(Self {
-            x: nbrs[0],
-            y: nbrs[1],
-            z: nbrs[2],
-        })
+        let nbrs: Vec<_> = s.split(',').map(parse::number).collect::<Result<_, _>>()?;
+        let [x, y, z] = nbrs[..] else {
+            return Err(ParseError::new("expected a position `x,y,z`", s));
+        };
+        Ok(Self { x, y, z })
     }
 }
 
@@ -67,18 +94,21 @@ fn union(junctions: &mut [JunctionBox], mut a: usize, mut b: usize) -> u64 {
     junctions[a].count
 }
 
-struct InputData {
+pub struct InputData {
     junction_boxes: Vec<Point3D>,
     connections: Vec<(usize, usize)>,
+    pairs: usize,
 }
 
-impl FromStr for InputData {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let junction_boxes: Vec<Point3D> = s
-            .lines()
-            .map(|line| Point3D::from_str(line).unwrap())
-            .collect();
+impl InputData {
+    fn parse_input(input: &str, pairs: usize) -> Result<Self, ParseError> {
+        let junction_boxes: Vec<Point3D> = parse::lines(input, Point3D::from_str)?;
+        if junction_boxes.len() < 3 {
+            return Err(ParseError::missing(
+                "expected at least 3 junction boxes",
+                input,
+            ));
+        }
         let mut connections = Vec::new();
         for i in 0..(junction_boxes.len() - 1) {
             for j in (i + 1)..junction_boxes.len() {
@@ -93,12 +123,11 @@ impl FromStr for InputData {
         Ok(Self {
             junction_boxes,
             connections,
+            pairs,
         })
     }
-}
 
-impl InputData {
-    fn solve_part1(&self, max_connections: usize) -> u64 {
+    fn solve_part1(&self) -> u64 {
         let mut junctions: Vec<_> = (0..self.junction_boxes.len())
             .map(|i| JunctionBox {
                 parent: i,
@@ -106,7 +135,7 @@ impl InputData {
             })
             .collect();
 
-        for (i, j) in self.connections.iter().take(max_connections) {
+        for (i, j) in self.connections.iter().take(self.pairs) {
             union(&mut junctions, *i, *j);
         }
 
@@ -131,11 +160,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "162,817,812
+const EXAMPLE: &str = "162,817,812
 57,618,57
 906,360,560
 592,479,940
@@ -156,15 +181,13 @@ mod tests {
 984,92,344
 425,690,689";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(10), 40);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 25272);
+    fn examples() {
+        check::<InputData>();
     }
 }
@@ -18,16 +18,31 @@
 //!
 //! Not a slow solution by any means, but I might want to look into "sweep line algorithm" in the future
 //! for possible improvements.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::ParseError;
 use std::{error::Error, str::FromStr};
 
 use crate::aoc_util::point::Point;
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("50").part2("24")];
+
+    const SHAPE: Shape = Shape::Lines(&["{int},{int}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
@@ -95,18 +110,22 @@ impl Line {
     }
 }
 
-struct InputData {
+pub struct InputData {
     rectangles: Vec<Rectangle>,
     lines: Vec<Line>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let red_tiles: Vec<_> = s
-            .lines()
-            .map(|line| Point::from_str(line).unwrap())
-            .collect();
+        let rows: Vec<_> = s.lines().collect();
+        let red_tiles: Vec<_> = rows
+            .iter()
+            .map(|row| Point::from_str(row))
+            .collect::<Result<_, _>>()?;
+        if red_tiles.len() < 4 {
+            return Err(ParseError::missing("expected at least four red tiles", s));
+        }
         let mut rectangles = Vec::new();
         for i in 0..red_tiles.len() - 2 {
             // Note: skip the first following entry, since that will effectively only make a line rather than a rectangle
@@ -115,11 +134,18 @@ impl FromStr for InputData {
             }
         }
         rectangles.sort_unstable();
-        let mut lines: Vec<_> = red_tiles
-            .windows(2)
-            .map(|p| Line::new(&p[0], &p[1]).unwrap())
-            .collect();
-        lines.push(Line::new(red_tiles.last().unwrap(), red_tiles.first().unwrap()).unwrap());
+        // Each red tile is connected to the next, and the last one back to the first.
+        let lines = (0..red_tiles.len())
+            .map(|i| {
+                let next = (i + 1) % red_tiles.len();
+                Line::new(&red_tiles[i], &red_tiles[next]).ok_or_else(|| {
+                    ParseError::new(
+                        "not in a row or column with the previous red tile",
+                        rows[next],
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Self { rectangles, lines })
     }
 }
@@ -143,11 +169,7 @@ impl InputData {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "7,1
+const EXAMPLE: &str = "7,1
 11,1
 11,7
 9,7
@@ -156,15 +178,13 @@ mod tests {
 2,3
 7,3";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 50);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 24);
+    fn examples() {
+        check::<InputData>();
     }
 }
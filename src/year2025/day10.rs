@@ -14,20 +14,44 @@
 //! Uses a recursive approach, inspired by
 //! [this idea](https://www.reddit.com/r/adventofcode/comments/1pk87hl/2025_day_10_part_2_bifurcate_your_way_to_victory/).
 //! The solution map is used again here to avoid recalculating the same solutions over and over.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{
     collections::{HashMap, VecDeque},
     error::Error,
     str::FromStr,
 };
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl Solution<'_> for InputData {
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE).part1("7").part2("33")];
+
+    const SHAPE: Shape = Shape::Lines(&["{any}] {any} {{ints}}"]);
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+            .map_or(Answer::NotApplicable, Answer::from)
+    }
 }
 
+/// The largest number of lights, which keeps every mask within a `u32`.
+const MAX_LIGHTS: usize = 32;
+
+/// The largest number of buttons, which keeps the solution map of every combination of button
+/// presses at a reasonable size.
+const MAX_BUTTONS: usize = 16;
+
 struct Machine {
     light_diagram: u32,
     buttons: Vec<u32>,
@@ -36,12 +60,23 @@ struct Machine {
 }
 
 impl FromStr for Machine {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (ld, s) = s.split_once(' ').unwrap();
-        let (b, j) = s.rsplit_once(' ').unwrap();
-        let light_diagram = ld
-            .trim_matches(['[', ']'])
+        let (ld, s) = parse::split_once(s, " ")?;
+        let (b, j) = s
+            .rsplit_once(' ')
+            .ok_or_else(|| ParseError::new("expected a space", s))?;
+        let lights = ld.trim_matches(['[', ']']);
+        if lights.is_empty()
+            || lights.len() > MAX_LIGHTS
+            || !lights.chars().all(|c| c == '.' || c == '#')
+        {
+            return Err(ParseError::new(
+                format!("expected a diagram of 1 to {MAX_LIGHTS} lights"),
+                ld,
+            ));
+        }
+        let light_diagram = lights
             .chars()
             .enumerate()
             .filter(|(_, c)| *c == '#')
@@ -52,15 +87,29 @@ impl FromStr for Machine {
             .map(|part| {
                 part.trim_matches(['(', ')'])
                     .split(',')
-                    .map(|n| 1 << n.parse::<u32>().unwrap())
-                    .sum()
+                    .try_fold(0, |button, n| match parse::number::<usize>(n)? {
+                        i if i < lights.len() => Ok(button | 1 << i),
+                        _ => Err(ParseError::new("unknown light", n)),
+                    })
             })
-            .collect();
-        let joltage = j
+            .collect::<Result<_, ParseError>>()?;
+        if buttons.len() > MAX_BUTTONS {
+            return Err(ParseError::new(
+                format!("expected at most {MAX_BUTTONS} buttons"),
+                b,
+            ));
+        }
+        let joltage: Vec<u32> = j
             .trim_matches(['{', '}'])
             .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        if joltage.len() != lights.len() {
+            return Err(ParseError::new(
+                "expected a joltage requirement for each light",
+                j,
+            ));
+        }
 
         // Build a map of button press solutions for every possible target mask
         let mut solution_map: HashMap<u32, Vec<u32>> = HashMap::new();
@@ -148,60 +197,50 @@ impl Machine {
     }
 }
 
-struct InputData {
+pub struct InputData {
     machines: Vec<Machine>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            machines: s
-                .lines()
-                .map(|line| Machine::from_str(line).unwrap())
-                .collect(),
+            machines: parse::lines(s, Machine::from_str)?,
         })
     }
 }
 
 impl InputData {
-    fn solve_part1(&self) -> u32 {
+    /// Returns `None` if the lights of a machine can't be configured.
+    fn solve_part1(&self) -> Option<u32> {
         self.machines
             .iter()
-            .map(|machine| {
-                machine
-                    .get_indicator_light_presses_least_cost(machine.light_diagram)
-                    .unwrap()
-            })
+            .map(|machine| machine.get_indicator_light_presses_least_cost(machine.light_diagram))
             .sum()
     }
 
-    fn solve_part2(&self) -> u32 {
+    /// Returns `None` if the joltage requirements of a machine can't be met.
+    fn solve_part2(&self) -> Option<u32> {
         self.machines
             .iter()
-            .map(|machine| machine.get_joltage_presses_total().unwrap())
+            .map(|machine| machine.get_joltage_presses_total())
             .sum()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+const EXAMPLE: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
 [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
 [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
 ";
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part1(), 7);
-    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::from_str(TEST_DATA).unwrap();
-        assert_eq!(solution_data.solve_part2(), 33);
+    fn examples() {
+        check::<InputData>();
     }
 
     // Some extra test of problematic inputs
@@ -209,27 +248,27 @@ mod tests {
     fn part2_extras_1() {
         let inputdata = "[##..##..] (0,1,3,4,5,6) (2,4,6) (1,5,6,7) (0,4,5) (3,6) (1,2,3,5,6) (0,2,4,5,6,7) {31,34,131,26,148,48,168,25}";
         let solution_data = InputData::from_str(inputdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 171);
+        assert_eq!(solution_data.solve_part2(), Some(171));
     }
 
     #[test]
     fn part2_extras_2() {
         let inputdata = "[.##...#.] (4,5) (0,1,2,3,7) (0,1,2,3,6) (0,1,2,4,6,7) (3,4,7) (0,3,6,7) {64,50,50,174,163,15,50,176}";
         let solution_data = InputData::from_str(inputdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 208);
+        assert_eq!(solution_data.solve_part2(), Some(208));
     }
 
     #[test]
     fn part2_extras_3() {
         let inputdata = "[.##..] (1,2) (0,2,3,4) (0,1,3,4) {17,20,19,17,17}";
         let solution_data = InputData::from_str(inputdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 28);
+        assert_eq!(solution_data.solve_part2(), Some(28));
     }
 
     #[test]
     fn part2_extras_4() {
         let inputdata = "[###] (0,1) (0,2) (1,2) {2,2,2}";
         let solution_data = InputData::from_str(inputdata).unwrap();
-        assert_eq!(solution_data.solve_part2(), 3);
+        assert_eq!(solution_data.solve_part2(), Some(3));
     }
 }
@@ -8,30 +8,48 @@
 //!
 //! Similar solution as in part 1, but now also passing along whether or not "fft" and "dac"
 //! have been found in the recursion.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::examples::Example;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{collections::HashMap, error::Error};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::try_from(input).unwrap();
-    Ok((
-        solution_data.solve_part1().to_string(),
-        solution_data.solve_part2().to_string(),
-    ))
+impl<'a> Solution<'a> for InputData<'a> {
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE_1).part1("5"),
+        Example::new(EXAMPLE_2).part2("2"),
+    ];
+
+    const SHAPE: Shape = Shape::Lines(&["{word}: [abcdefghijklmnopqrstuvwxyz ]"]);
+
+    fn parse(input: &'a str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::try_from(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        self.solve_part2()
+    }
 }
 
-struct InputData<'a> {
+pub struct InputData<'a> {
     tree: HashMap<&'a str, Vec<&'a str>>,
 }
 
 impl<'a> TryFrom<&'a str> for InputData<'a> {
-    type Error = ();
+    type Error = ParseError;
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         let tree = s
             .lines()
             .map(|line| {
-                let (left, right) = line.split_once(": ").unwrap();
-                (left, right.split_whitespace().collect())
+                let (left, right) = parse::split_once(line, ": ")?;
+                Ok((left, right.split_whitespace().collect()))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
         Ok(Self { tree })
     }
 }
@@ -99,11 +117,7 @@ impl InputData<'_> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_DATA_1: &str = "aaa: you hhh
+const EXAMPLE_1: &str = "aaa: you hhh
 you: bbb ccc
 bbb: ddd eee
 ccc: ddd eee fff
@@ -114,7 +128,7 @@ ggg: out
 hhh: ccc fff iii
 iii: out";
 
-    const TEST_DATA_2: &str = "svr: aaa bbb
+const EXAMPLE_2: &str = "svr: aaa bbb
 aaa: fft
 fft: ccc
 bbb: tty
@@ -128,15 +142,13 @@ fff: ggg hhh
 ggg: out
 hhh: out";
 
-    #[test]
-    fn part1_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_1).unwrap();
-        assert_eq!(solution_data.solve_part1(), 5);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_runner::examples::check;
 
     #[test]
-    fn part2_example_1() {
-        let solution_data = InputData::try_from(TEST_DATA_2).unwrap();
-        assert_eq!(solution_data.solve_part2(), 2);
+    fn examples() {
+        check::<InputData>();
     }
 }
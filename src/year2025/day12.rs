@@ -9,11 +9,26 @@
 //! Making a generic solution that could handle all cases would be much more complex.
 //!
 //! Note that the test input does not follow these assumptions, and can't be used for this solution.
+use crate::aoc_runner::answer::Answer;
+use crate::aoc_runner::shape::Shape;
+use crate::aoc_runner::solver::Solution;
+use crate::aoc_util::parse::{self, ParseError};
 use std::{error::Error, str::FromStr};
 
-pub fn solve(input: &str) -> Result<(String, String), Box<dyn Error>> {
-    let solution_data = InputData::from_str(input).unwrap();
-    Ok((solution_data.solve_part1().to_string(), "-".to_string()))
+impl Solution<'_> for InputData {
+    const SHAPE: Shape = Shape::Blocks(&Shape::Lines(&["{int}:", "[#.]", "{int}x{int}: {ints}"]));
+
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::from_str(input)?)
+    }
+
+    fn part1(&self) -> impl Into<Answer> {
+        self.solve_part1()
+    }
+
+    fn part2(&self) -> impl Into<Answer> {
+        Answer::NotApplicable
+    }
 }
 
 #[derive(Debug)]
@@ -24,16 +39,16 @@ struct Region {
 }
 
 impl FromStr for Region {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").unwrap();
-        let (width, height) = left.split_once('x').unwrap();
-        let width = width.parse().unwrap();
-        let height = height.parse().unwrap();
+        let (left, right) = parse::split_once(s, ": ")?;
+        let (width, height) = parse::split_once(left, "x")?;
+        let width = parse::number(width)?;
+        let height = parse::number(height)?;
         let quantities = right
             .split_whitespace()
-            .map(|n| n.parse().unwrap())
-            .collect();
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             width,
             height,
@@ -42,19 +57,18 @@ impl FromStr for Region {
     }
 }
 
-struct InputData {
+pub struct InputData {
     regions: Vec<Region>,
 }
 
 impl FromStr for InputData {
-    type Err = ();
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, r) = s.rsplit_once("\n\n").unwrap();
+        let (_, r) = s
+            .rsplit_once("\n\n")
+            .ok_or_else(|| ParseError::new("expected a blank line", s))?;
         Ok(Self {
-            regions: r
-                .lines()
-                .map(|line| Region::from_str(line).unwrap())
-                .collect(),
+            regions: parse::lines(r, Region::from_str)?,
         })
     }
 }
@@ -10,3 +10,20 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 pub mod day12;
+
+use crate::aoc_runner::solver::register_days;
+
+register_days! { 2025:
+    1 => day01: "Secret Entrance",
+    2 => day02: "Gift Shop",
+    3 => day03: "Lobby",
+    4 => day04: "Printing Department",
+    5 => day05: "Cafeteria",
+    6 => day06: "Trash Compactor",
+    7 => day07: "Laboratories",
+    8 => day08: "Playground",
+    9 => day09: "Movie Theater",
+    10 => day10: "Factory",
+    11 => day11: "Reactor",
+    12 => day12: "Christmas Tree Farm",
+}